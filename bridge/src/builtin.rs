@@ -11,14 +11,18 @@ pub(crate) struct Builtins<'a> {
     pub rust_str: bool,
     pub rust_slice: bool,
     pub rust_box: bool,
+    pub rust_arc: bool,
     pub rust_vec: bool,
     pub rust_fn: bool,
+    pub rust_iter: bool,
+    pub range_iter: bool,
     pub rust_isize: bool,
     pub opaque: bool,
     pub layout: bool,
     pub unsafe_bitcopy: bool,
     pub unsafe_bitcopy_t: bool,
     pub rust_error: bool,
+    pub rust_panic: bool,
     pub manually_drop: bool,
     pub maybe_uninit: bool,
     pub trycatch: bool,
@@ -35,7 +39,9 @@ pub(crate) struct Builtins<'a> {
     pub destroy: bool,
     pub deleter_if: bool,
     pub shared_ptr: bool,
+    pub shared_from_this: bool,
     pub vector: bool,
+    pub set: bool,
     pub alignmax: bool,
     pub content: Content<'a>,
 }
@@ -107,15 +113,33 @@ pub(super) fn write(out: &mut OutFile) {
         include.utility = true;
     }
 
+    if builtin.rust_arc {
+        include.type_traits = true;
+        include.utility = true;
+    }
+
     if builtin.rust_fn {
         include.utility = true;
     }
 
-    if builtin.rust_error {
+    if builtin.rust_iter {
+        include.cstddef = true;
+        include.iterator = true;
+        include.new = true;
+        include.utility = true;
+    }
+
+    if builtin.rust_error || builtin.rust_panic {
         include.exception = true;
         builtin.friend_impl = true;
     }
 
+    if builtin.rust_error {
+        // For rust::Error::chain().
+        include.string = true;
+        include.vector = true;
+    }
+
     if builtin.rust_isize {
         include.basetsd = true;
         include.sys_types = true;
@@ -142,6 +166,11 @@ pub(super) fn write(out: &mut OutFile) {
         builtin.is_complete = true;
     }
 
+    if builtin.shared_from_this {
+        include.memory = true;
+        include.utility = true;
+    }
+
     if builtin.is_complete {
         include.cstddef = true;
         include.type_traits = true;
@@ -196,11 +225,14 @@ pub(super) fn write(out: &mut OutFile) {
         ifndef::write(out, builtin.rust_str, "CXXBRIDGE1_RUST_STR");
         ifndef::write(out, builtin.rust_slice, "CXXBRIDGE1_RUST_SLICE");
         ifndef::write(out, builtin.rust_box, "CXXBRIDGE1_RUST_BOX");
+        ifndef::write(out, builtin.rust_arc, "CXXBRIDGE1_RUST_ARC");
         ifndef::write(out, builtin.unsafe_bitcopy_t, "CXXBRIDGE1_RUST_BITCOPY_T");
         ifndef::write(out, builtin.unsafe_bitcopy, "CXXBRIDGE1_RUST_BITCOPY");
         ifndef::write(out, builtin.rust_vec, "CXXBRIDGE1_RUST_VEC");
         ifndef::write(out, builtin.rust_fn, "CXXBRIDGE1_RUST_FN");
+        ifndef::write(out, builtin.rust_iter, "CXXBRIDGE1_RUST_ITER");
         ifndef::write(out, builtin.rust_error, "CXXBRIDGE1_RUST_ERROR");
+        ifndef::write(out, builtin.rust_panic, "CXXBRIDGE1_RUST_PANIC");
         ifndef::write(out, builtin.rust_isize, "CXXBRIDGE1_RUST_ISIZE");
         ifndef::write(out, builtin.opaque, "CXXBRIDGE1_RUST_OPAQUE");
         ifndef::write(out, builtin.is_complete, "CXXBRIDGE1_IS_COMPLETE");
@@ -318,6 +350,10 @@ pub(super) fn write(out: &mut OutFile) {
         write_builtin!("builtin/rust_error.h");
     }
 
+    if builtin.rust_panic {
+        write_builtin!("builtin/rust_panic.h");
+    }
+
     if builtin.destroy {
         write_builtin!("builtin/destroy.h");
     }
@@ -330,10 +366,22 @@ pub(super) fn write(out: &mut OutFile) {
         write_builtin!("builtin/shared_ptr.h");
     }
 
+    if builtin.shared_from_this {
+        write_builtin!("builtin/shared_from_this.h");
+    }
+
     if builtin.vector {
         write_builtin!("builtin/vector.h");
     }
 
+    if builtin.set {
+        write_builtin!("builtin/set.h");
+    }
+
+    if builtin.range_iter {
+        write_builtin!("builtin/range_iter.h");
+    }
+
     if builtin.relocatable_or_array {
         write_builtin!("builtin/relocatable_or_array.h");
     }
@@ -366,14 +414,18 @@ fn write_builtin<'a>(
                 cstddef,
                 cstdint,
                 cstring,
+                deque,
                 exception,
+                format,
                 functional,
                 initializer_list,
                 iterator,
                 limits,
+                list,
                 memory,
                 new,
                 ranges,
+                set,
                 stdexcept,
                 string,
                 string_view,
@@ -391,14 +443,18 @@ fn write_builtin<'a>(
                 "cstddef" => *cstddef = true,
                 "cstdint" => *cstdint = true,
                 "cstring" => *cstring = true,
+                "deque" => *deque = true,
                 "exception" => *exception = true,
+                "format" => *format = true,
                 "functional" => *functional = true,
                 "initializer_list" => *initializer_list = true,
                 "iterator" => *iterator = true,
                 "limits" => *limits = true,
+                "list" => *list = true,
                 "memory" => *memory = true,
                 "new" => *new = true,
                 "ranges" => *ranges = true,
+                "set" => *set = true,
                 "stdexcept" => *stdexcept = true,
                 "string" => *string = true,
                 "string_view" => *string_view = true,
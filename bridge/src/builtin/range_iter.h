@@ -0,0 +1,36 @@
+#pragma once
+#include "../../../include/cxx.h"
+#include <iterator>
+#include <memory>
+#include <utility>
+
+namespace rust {
+inline namespace cxxbridge1 {
+namespace {
+template <typename Range>
+class range_iter final {
+  using iterator = decltype(::std::begin(::std::declval<Range &>()));
+  using sentinel = decltype(::std::end(::std::declval<Range &>()));
+
+public:
+  using pointer = decltype(::std::addressof(*::std::declval<iterator &>()));
+
+  explicit range_iter(Range &range)
+      : it(::std::begin(range)), end(::std::end(range)) {}
+
+  pointer next() {
+    if (this->it == this->end) {
+      return nullptr;
+    }
+    pointer ptr = ::std::addressof(*this->it);
+    ++this->it;
+    return ptr;
+  }
+
+private:
+  iterator it;
+  sentinel end;
+};
+} // namespace
+} // namespace cxxbridge1
+} // namespace rust
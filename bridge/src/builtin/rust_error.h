@@ -13,6 +13,15 @@ public:
     Error error;
     error.msg = static_cast<char const *>(repr.ptr);
     error.len = repr.len;
+    error.details = nullptr;
+    return error;
+  }
+
+  static Error error_details(repr::PtrLen repr) noexcept {
+    Error error;
+    error.msg = nullptr;
+    error.len = 0;
+    error.details = static_cast<detail::ErrorDetails *>(repr.ptr);
     return error;
   }
 };
@@ -0,0 +1,21 @@
+#pragma once
+#include "../../../include/cxx.h"
+#include "./friend_impl.h"
+#include "./ptr_len.h"
+
+namespace rust {
+inline namespace cxxbridge1 {
+namespace {
+template <>
+class impl<Panic> final {
+public:
+  static Panic panic(repr::PtrLen repr) noexcept {
+    Panic panic;
+    panic.msg = static_cast<char const *>(repr.ptr);
+    panic.len = repr.len;
+    return panic;
+  }
+};
+} // namespace
+} // namespace cxxbridge1
+} // namespace rust
@@ -0,0 +1,26 @@
+#pragma once
+#include "../../../include/cxx.h"
+#include <set>
+#include <type_traits>
+#include <utility>
+
+namespace rust {
+inline namespace cxxbridge1 {
+namespace {
+template <typename T, bool = ::std::is_copy_constructible<T>::value>
+struct if_copy_constructible {
+  static bool insert(::std::set<T> &, const T &, bool *) noexcept {
+    return false;
+  }
+};
+//
+template <typename T>
+struct if_copy_constructible<T, true> {
+  static bool insert(::std::set<T> &set, const T &value, bool *inserted) {
+    *inserted = set.insert(value).second;
+    return true;
+  }
+};
+} // namespace
+} // namespace cxxbridge1
+} // namespace rust
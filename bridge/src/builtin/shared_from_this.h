@@ -0,0 +1,28 @@
+#pragma once
+#include <memory>
+
+namespace rust {
+inline namespace cxxbridge1 {
+namespace {
+template <typename T>
+::std::shared_ptr<T> shared_from_this(T const &self) {
+#if defined(__cpp_lib_enable_shared_from_this)
+  auto shared = self.weak_from_this().lock();
+#elif defined(__cpp_exceptions) || defined(_CPPUNWIND)
+  // Before C++17 there is no weak_from_this to check for an owner first.
+  // Standard libraries implement shared_from_this on an object without an
+  // owner by throwing bad_weak_ptr, which must not escape a noexcept shim.
+  ::std::shared_ptr<T const> shared;
+  try {
+    shared = self.shared_from_this();
+  } catch (::std::bad_weak_ptr const &) {
+  }
+#else
+  auto shared = self.shared_from_this();
+#endif
+  return ::std::const_pointer_cast<T>(
+      ::std::static_pointer_cast<T const>(::std::move(shared)));
+}
+} // namespace
+} // namespace cxxbridge1
+} // namespace rust
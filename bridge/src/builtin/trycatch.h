@@ -16,7 +16,7 @@ static typename ::std::enable_if<::std::is_same<
 trycatch(Try &&func, Fail &&fail) noexcept try {
   func();
 } catch (::std::exception const &e) {
-  fail(e.what());
+  fail(e);
 }
 } // namespace behavior
 } // namespace rust
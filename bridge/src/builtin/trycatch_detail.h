@@ -1,5 +1,6 @@
 #pragma once
 #include "./ptr_len.h"
+#include <exception>
 #include <string>
 
 #pragma GCC diagnostic ignored "-Wshadow"
@@ -15,6 +16,7 @@ public:
   Fail(::rust::repr::PtrLen &throw$) noexcept : throw$(throw$) {}
   void operator()(char const *) noexcept;
   void operator()(std::string const &) noexcept;
+  void operator()(std::exception const &) noexcept;
 };
 } // namespace detail
 } // namespace cxxbridge1
@@ -20,6 +20,16 @@ struct if_move_constructible<T, true> {
     return true;
   }
 };
+
+template <typename T, bool = ::std::is_default_constructible<T>::value>
+struct if_default_constructible_array {
+  static T *new_array(::std::size_t) noexcept { return nullptr; }
+};
+//
+template <typename T>
+struct if_default_constructible_array<T, true> {
+  static T *new_array(::std::size_t len) { return new T[len](); }
+};
 } // namespace
 } // namespace cxxbridge1
 } // namespace rust
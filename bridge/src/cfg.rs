@@ -37,6 +37,9 @@ pub(super) fn strip(
             Api::CxxFunction(efn) | Api::RustFunction(efn) => &mut efn.cfg,
             Api::TypeAlias(alias) => &mut alias.cfg,
             Api::Impl(imp) => &mut imp.cfg,
+            Api::Const(konst) => &mut konst.cfg,
+            Api::CxxStatic(stc) | Api::RustStatic(stc) => &mut stc.cfg,
+            Api::CxxIterator(iter) | Api::RustIterator(iter) => &mut iter.cfg,
         })
     });
     for api in apis {
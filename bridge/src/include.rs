@@ -27,14 +27,18 @@ pub(crate) struct Includes<'a> {
     pub cstddef: bool,
     pub cstdint: bool,
     pub cstring: bool,
+    pub deque: bool,
     pub exception: bool,
+    pub format: bool,
     pub functional: bool,
     pub initializer_list: bool,
     pub iterator: bool,
     pub limits: bool,
+    pub list: bool,
     pub memory: bool,
     pub new: bool,
     pub ranges: bool,
+    pub set: bool,
     pub stdexcept: bool,
     pub string: bool,
     pub string_view: bool,
@@ -91,14 +95,18 @@ pub(super) fn write(out: &mut OutFile) {
         cstddef,
         cstdint,
         cstring,
+        deque,
         exception,
+        format,
         functional,
         initializer_list,
         iterator,
         limits,
+        list,
         memory,
         new,
         ranges,
+        set,
         stdexcept,
         string,
         string_view,
@@ -128,6 +136,9 @@ pub(super) fn write(out: &mut OutFile) {
     if cstring {
         writeln!(out, "#include <cstring>");
     }
+    if deque {
+        writeln!(out, "#include <deque>");
+    }
     if exception && !cxx_header {
         writeln!(out, "#include <exception>");
     }
@@ -143,12 +154,18 @@ pub(super) fn write(out: &mut OutFile) {
     if limits {
         writeln!(out, "#include <limits>");
     }
+    if list {
+        writeln!(out, "#include <list>");
+    }
     if memory {
         writeln!(out, "#include <memory>");
     }
     if new && !cxx_header {
         writeln!(out, "#include <new>");
     }
+    if set {
+        writeln!(out, "#include <set>");
+    }
     if stdexcept && !cxx_header {
         writeln!(out, "#include <stdexcept>");
     }
@@ -191,6 +208,14 @@ pub(super) fn write(out: &mut OutFile) {
         writeln!(out, "#include <ranges>");
         writeln!(out, "#endif");
     }
+    if format && !cxx_header {
+        writeln!(out, "#if __cplusplus >= 202002L");
+        writeln!(out, "#include <version>");
+        writeln!(out, "#endif");
+        writeln!(out, "#ifdef __cpp_lib_format");
+        writeln!(out, "#include <format>");
+        writeln!(out, "#endif");
+    }
 }
 
 impl<'i, 'a> Extend<&'i Include> for Includes<'a> {
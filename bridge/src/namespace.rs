@@ -8,7 +8,13 @@ impl Api {
             Api::CxxType(ety) | Api::RustType(ety) => &ety.name.namespace,
             Api::Enum(enm) => &enm.name.namespace,
             Api::Struct(strct) => &strct.name.namespace,
-            Api::Impl(_) | Api::Include(_) | Api::TypeAlias(_) => Default::default(),
+            Api::Const(konst) => &konst.name.namespace,
+            Api::CxxStatic(stc) | Api::RustStatic(stc) => &stc.name.namespace,
+            Api::Impl(_)
+            | Api::Include(_)
+            | Api::TypeAlias(_)
+            | Api::CxxIterator(_)
+            | Api::RustIterator(_) => Default::default(),
         }
     }
 }
@@ -151,6 +151,8 @@ mod tests {
             bounds: Vec::new(),
             semi_token: Token![;](Span::call_site()),
             trusted: false,
+            cxx_deleter: None,
+            shared_from_this: None,
         })
     }
 }
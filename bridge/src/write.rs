@@ -13,9 +13,11 @@ use crate::syntax::set::UnorderedSet;
 use crate::syntax::symbol::Symbol;
 use crate::syntax::trivial::{self, TrivialReason};
 use crate::syntax::{
-    Api, Doc, Enum, ExternFn, ExternType, FnKind, Lang, Pair, Signature, Struct, Trait, Type,
-    TypeAlias, Types, Var, derive, mangle,
+    Api, Const, Doc, Enum, ExternFn, ExternStatic, ExternType, FnKind, IterImpl, Lang, NamedType,
+    Pair, Signature, Struct, Trait, Type, TypeAlias, Types, Var, derive, mangle,
 };
+use proc_macro2::Ident;
+use syn::{Expr, Lit};
 
 pub(super) fn generate(apis: &[Api], types: &Types, opt: &Opt, header: bool) -> Vec<u8> {
     let mut out_file = OutFile::new(header, opt, types);
@@ -107,6 +109,25 @@ fn write_data_structures<'a>(out: &mut OutFile<'a>, apis: &'a [Api]) {
         }
     }
 
+    let mut consts_for_type = Map::new();
+    for api in apis {
+        if let Api::Const(konst) = api {
+            if let Some(self_type) = &konst.self_type {
+                consts_for_type
+                    .entry(self_type)
+                    .or_insert_with(Vec::new)
+                    .push(konst);
+            }
+        }
+    }
+
+    let mut iterator_for_type = Map::new();
+    for api in apis {
+        if let Api::RustIterator(iter) = api {
+            iterator_for_type.insert(&iter.self_ty.rust, &iter.item);
+        }
+    }
+
     let mut structs_written = UnorderedSet::new();
     let mut toposorted_structs = out.types.toposorted_structs.iter();
     for api in apis {
@@ -119,7 +140,11 @@ fn write_data_structures<'a>(out: &mut OutFile<'a>, apis: &'a [Api]) {
                             .get(&next.name.rust)
                             .map(Vec::as_slice)
                             .unwrap_or_default();
-                        write_struct(out, next, methods);
+                        let consts = consts_for_type
+                            .get(&next.name.rust)
+                            .map(Vec::as_slice)
+                            .unwrap_or_default();
+                        write_struct(out, next, methods, consts);
                     }
                     structs_written.insert(&next.name.rust);
                     if next.name.rust == strct.name.rust {
@@ -141,7 +166,12 @@ fn write_data_structures<'a>(out: &mut OutFile<'a>, apis: &'a [Api]) {
                     .get(&ety.name.rust)
                     .map(Vec::as_slice)
                     .unwrap_or_default();
-                write_opaque_type(out, ety, methods);
+                let iterator = iterator_for_type.get(&ety.name.rust).copied();
+                write_opaque_type(out, ety, methods, iterator);
+            }
+            Api::Const(konst) if konst.self_type.is_none() => {
+                out.next_section();
+                write_const(out, konst);
             }
             _ => {}
         }
@@ -168,9 +198,14 @@ fn write_functions<'a>(out: &mut OutFile<'a>, apis: &'a [Api]) {
         for api in apis {
             match api {
                 Api::Struct(strct) => write_struct_operator_decls(out, strct),
+                Api::Enum(enm) => write_enum_operator_decls(out, enm),
+                Api::CxxType(ety) => write_shared_from_this_shims(out, ety),
                 Api::RustType(ety) => write_opaque_type_layout_decls(out, ety),
                 Api::CxxFunction(efn) => write_cxx_function_shim(out, efn),
                 Api::RustFunction(efn) => write_rust_function_decl(out, efn),
+                Api::CxxStatic(stc) => write_cxx_static_shim(out, stc),
+                Api::CxxIterator(iter) => write_cxx_iterator_shim(out, iter),
+                Api::RustIterator(iter) => write_rust_iterator_decl(out, iter),
                 _ => {}
             }
         }
@@ -178,14 +213,24 @@ fn write_functions<'a>(out: &mut OutFile<'a>, apis: &'a [Api]) {
         write_std_specializations(out, apis);
     }
 
+    write_std_formatters(out, apis);
+
     for api in apis {
         match api {
             Api::Struct(strct) => write_struct_operators(out, strct),
+            Api::Const(konst) if konst.self_type.is_some() => {
+                write_struct_const_definition(out, konst);
+            }
             Api::RustType(ety) => write_opaque_type_layout(out, ety),
             Api::RustFunction(efn) => {
                 out.next_section();
                 write_rust_function_shim(out, efn);
             }
+            Api::RustStatic(stc) => {
+                out.next_section();
+                write_rust_static(out, stc);
+            }
+            Api::RustIterator(iter) => write_rust_iterator(out, iter),
             _ => {}
         }
     }
@@ -224,6 +269,71 @@ fn write_std_specializations(out: &mut OutFile, apis: &[Api]) {
     out.end_block(Block::Namespace("std"));
 }
 
+// Formatters for shared types that derive Debug, backed by the Rust Debug impl.
+// The header declares them and the implementation file defines them.
+fn write_std_formatters(out: &mut OutFile, apis: &[Api]) {
+    let mut debug_types = apis
+        .iter()
+        .filter_map(|api| match api {
+            Api::Struct(strct) if derive::contains(&strct.derives, Trait::Debug) => {
+                Some(&strct.name)
+            }
+            Api::Enum(enm) if derive::contains(&enm.derives, Trait::Debug) => Some(&enm.name),
+            _ => None,
+        })
+        .peekable();
+    if debug_types.peek().is_none() {
+        return;
+    }
+
+    out.set_namespace(Default::default());
+    out.begin_block(Block::Namespace("std"));
+
+    for name in debug_types {
+        out.next_section();
+        out.include.format = true;
+        let qualified = name.to_fully_qualified();
+        writeln!(out, "#ifdef __cpp_lib_format");
+        writeln!(out, "template <>");
+        writeln!(
+            out,
+            "struct formatter<{}, char> : formatter<::std::string_view, char> {{",
+            qualified,
+        );
+        writeln!(
+            out,
+            "  ::std::format_context::iterator format({} const &self, ::std::format_context &ctx) const;",
+            qualified,
+        );
+        writeln!(out, "}};");
+        if !out.header {
+            out.include.string = true;
+            out.pragma.dollar_in_identifier = true;
+            writeln!(out);
+            writeln!(
+                out,
+                "::std::format_context::iterator formatter<{}, char>::format({} const &self, ::std::format_context &ctx) const {{",
+                qualified, qualified,
+            );
+            writeln!(out, "  ::std::string debug;");
+            let link_name = mangle::operator(name, "debug");
+            write!(out, "  ::");
+            for name in &name.namespace {
+                write!(out, "{}::", name);
+            }
+            writeln!(out, "{}(self, debug);", link_name);
+            writeln!(
+                out,
+                "  return formatter<::std::string_view, char>::format(debug, ctx);",
+            );
+            writeln!(out, "}}");
+        }
+        writeln!(out, "#endif // __cpp_lib_format");
+    }
+
+    out.end_block(Block::Namespace("std"));
+}
+
 fn pick_includes_and_builtins(out: &mut OutFile, apis: &[Api]) {
     for api in apis {
         if let Api::Include(include) = api {
@@ -237,16 +347,20 @@ fn pick_includes_and_builtins(out: &mut OutFile, apis: &[Api]) {
                 Some(U8 | U16 | U32 | U64 | I8 | I16 | I32 | I64) => out.include.cstdint = true,
                 Some(Usize) => out.include.cstddef = true,
                 Some(Isize) => out.builtin.rust_isize = true,
-                Some(CxxString) => out.include.string = true,
+                Some(CxxString | CxxU16String | CxxU32String) => out.include.string = true,
                 Some(RustString) => out.builtin.rust_string = true,
                 Some(Bool | Char | F32 | F64) | None => {}
             },
             Type::RustBox(_) => out.builtin.rust_box = true,
+            Type::RustArc(_) => out.builtin.rust_arc = true,
             Type::RustVec(_) => out.builtin.rust_vec = true,
             Type::UniquePtr(_) => out.include.memory = true,
             Type::SharedPtr(_) | Type::WeakPtr(_) => out.include.memory = true,
             Type::Str(_) => out.builtin.rust_str = true,
             Type::CxxVector(_) => out.include.vector = true,
+            Type::CxxDeque(_) => out.include.deque = true,
+            Type::CxxList(_) => out.include.list = true,
+            Type::CxxSet(_) => out.include.set = true,
             Type::Fn(_) => out.builtin.rust_fn = true,
             Type::SliceRef(_) => out.builtin.rust_slice = true,
             Type::Array(_) => out.include.array = true,
@@ -274,7 +388,12 @@ fn write_doc(out: &mut OutFile, indent: &str, doc: &Doc) {
     }
 }
 
-fn write_struct<'a>(out: &mut OutFile<'a>, strct: &'a Struct, methods: &[&ExternFn]) {
+fn write_struct<'a>(
+    out: &mut OutFile<'a>,
+    strct: &'a Struct,
+    methods: &[&ExternFn],
+    consts: &[&Const],
+) {
     let operator_eq = derive::contains(&strct.derives, Trait::PartialEq);
     let operator_ord = derive::contains(&strct.derives, Trait::PartialOrd);
 
@@ -319,6 +438,18 @@ fn write_struct<'a>(out: &mut OutFile<'a>, strct: &'a Struct, methods: &[&Extern
 
     out.next_section();
 
+    for konst in consts {
+        write_doc(out, "  ", &konst.doc);
+        write!(out, "  static constexpr ");
+        write_type_space(out, &konst.ty);
+        write!(out, "{} = ", konst.name.cxx);
+        write_const_value(out, konst);
+        writeln!(out, ";");
+    }
+    if !consts.is_empty() {
+        out.next_section();
+    }
+
     for method in methods {
         if !method.doc.is_empty() {
             out.next_section();
@@ -394,7 +525,12 @@ fn write_struct_using(out: &mut OutFile, ident: &Pair) {
     writeln!(out, "using {} = {};", ident.cxx, ident.to_fully_qualified());
 }
 
-fn write_opaque_type<'a>(out: &mut OutFile<'a>, ety: &'a ExternType, methods: &[&ExternFn]) {
+fn write_opaque_type<'a>(
+    out: &mut OutFile<'a>,
+    ety: &'a ExternType,
+    methods: &[&ExternFn],
+    iterator: Option<&Type>,
+) {
     out.set_namespace(&ety.name.namespace);
     let guard = Guard::new(out, "CXXBRIDGE1_STRUCT", &ety.name);
     writeln!(out, "#ifndef {}", guard);
@@ -426,6 +562,15 @@ fn write_opaque_type<'a>(out: &mut OutFile<'a>, ety: &'a ExternType, methods: &[
         }
     }
 
+    if let Some(item) = iterator {
+        out.builtin.rust_iter = true;
+        for method in ["begin", "end"] {
+            write!(out, "  ::rust::Iter<");
+            write_type(out, item);
+            writeln!(out, "> {}() noexcept;", method);
+        }
+    }
+
     writeln!(out, "  ~{}() = delete;", ety.name.cxx);
     writeln!(out);
 
@@ -500,6 +645,69 @@ fn check_enum<'a>(out: &mut OutFile<'a>, enm: &'a Enum) {
     }
 }
 
+fn write_const<'a>(out: &mut OutFile<'a>, konst: &'a Const) {
+    out.set_namespace(&konst.name.namespace);
+    let guard = Guard::new(out, "CXXBRIDGE1_CONST", &konst.name);
+    writeln!(out, "#ifndef {}", guard);
+    writeln!(out, "#define {}", guard);
+    write_doc(out, "", &konst.doc);
+    write!(out, "constexpr ");
+    write_type_space(out, &konst.ty);
+    write!(out, "{} = ", konst.name.cxx);
+    write_const_value(out, konst);
+    writeln!(out, ";");
+    writeln!(out, "#endif // {}", guard);
+}
+
+fn write_struct_const_definition<'a>(out: &mut OutFile<'a>, konst: &'a Const) {
+    let self_type = konst.self_type.as_ref().unwrap();
+    if out.header || out.types.cxx.contains(self_type) {
+        return;
+    }
+
+    // Before C++17 a static constexpr data member is not implicitly inline, so
+    // an ODR-use needs a namespace-scope definition in exactly one translation
+    // unit.
+    let strct = out.types.structs[self_type];
+    out.set_namespace(&strct.name.namespace);
+    out.next_section();
+    writeln!(out, "#if __cplusplus < 201703L");
+    write!(out, "constexpr ");
+    write_type_space(out, &konst.ty);
+    writeln!(out, "{}::{};", strct.name.cxx, konst.name.cxx);
+    writeln!(out, "#endif");
+}
+
+fn write_const_value(out: &mut OutFile, konst: &Const) {
+    let (negative, lit) = match &konst.expr {
+        Expr::Lit(expr) => (false, &expr.lit),
+        Expr::Unary(expr) => match &*expr.expr {
+            Expr::Lit(expr) => (true, &expr.lit),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    let sign = if negative { "-" } else { "" };
+    match lit {
+        Lit::Bool(lit) => write!(out, "{}", lit.value),
+        Lit::Int(lit) => {
+            let magnitude = lit.base10_parse::<u64>().unwrap_or(u64::MAX);
+            if negative && magnitude == 1 << 63 {
+                // The literal 9223372036854775808 does not fit in any signed
+                // C++ integer type, so it cannot be negated.
+                out.include.limits = true;
+                write!(out, "::std::numeric_limits<::std::int64_t>::min()");
+            } else if magnitude > i64::MAX as u64 {
+                write!(out, "{}ull", lit.base10_digits());
+            } else {
+                write!(out, "{}{}", sign, lit.base10_digits());
+            }
+        }
+        Lit::Float(lit) => write!(out, "{}{}", sign, lit.base10_digits()),
+        _ => unreachable!(),
+    }
+}
+
 fn write_discriminant(out: &mut OutFile, repr: Atom, discriminant: Discriminant) {
     let limits = Limits::of(repr).unwrap();
     if discriminant == limits.min && limits.min < Discriminant::zero() {
@@ -703,9 +911,36 @@ fn write_struct_operator_decls<'a>(out: &mut OutFile<'a>, strct: &'a Struct) {
         );
     }
 
+    if derive::contains(&strct.derives, Trait::Debug) {
+        write_debug_operator_decl(out, &strct.name);
+    }
+
     out.end_block(Block::ExternC);
 }
 
+fn write_enum_operator_decls<'a>(out: &mut OutFile<'a>, enm: &'a Enum) {
+    if !derive::contains(&enm.derives, Trait::Debug) {
+        return;
+    }
+
+    out.set_namespace(&enm.name.namespace);
+    out.begin_block(Block::ExternC);
+    write_debug_operator_decl(out, &enm.name);
+    out.end_block(Block::ExternC);
+}
+
+fn write_debug_operator_decl(out: &mut OutFile, name: &Pair) {
+    out.include.string = true;
+    out.pragma.dollar_in_identifier = true;
+    out.pragma.missing_declarations = true;
+    let link_name = mangle::operator(name, "debug");
+    writeln!(
+        out,
+        "void {}({} const &, ::std::string &) noexcept;",
+        link_name, name.cxx,
+    );
+}
+
 fn write_struct_operators<'a>(out: &mut OutFile<'a>, strct: &'a Struct) {
     if out.header {
         return;
@@ -838,6 +1073,101 @@ fn write_opaque_type_layout<'a>(out: &mut OutFile<'a>, ety: &'a ExternType) {
     writeln!(out, "}}");
 }
 
+fn write_cxx_iterator_shim<'a>(out: &mut OutFile<'a>, iter: &'a IterImpl) {
+    let resolve = out.types.resolve(&iter.self_ty);
+    let Type::Ref(item) = &iter.item else {
+        unreachable!();
+    };
+    out.builtin.range_iter = true;
+    out.pragma.dollar_in_identifier = true;
+    out.pragma.missing_declarations = true;
+    out.next_section();
+    out.set_namespace(&resolve.name.namespace);
+    out.begin_block(Block::ExternC);
+
+    let range_iter = format!(
+        "::rust::range_iter<{} const>",
+        resolve.name.to_fully_qualified(),
+    );
+
+    begin_function_definition(out);
+    let link_name = mangle::iterator(resolve.name, "begin");
+    writeln!(
+        out,
+        "void *{}({} const &self) noexcept {{",
+        link_name, resolve.name.cxx,
+    );
+    writeln!(out, "  return new {}(self);", range_iter);
+    writeln!(out, "}}");
+
+    out.next_section();
+    begin_function_definition(out);
+    write_type(out, &item.inner);
+    let link_name = mangle::iterator(resolve.name, "next");
+    writeln!(out, " const *{}(void *iter) noexcept {{", link_name);
+    writeln!(out, "  return static_cast<{} *>(iter)->next();", range_iter);
+    writeln!(out, "}}");
+
+    out.next_section();
+    begin_function_definition(out);
+    let link_name = mangle::iterator(resolve.name, "drop");
+    writeln!(out, "void {}(void *iter) noexcept {{", link_name);
+    writeln!(out, "  delete static_cast<{} *>(iter);", range_iter);
+    writeln!(out, "}}");
+
+    out.end_block(Block::ExternC);
+}
+
+fn write_rust_iterator_decl<'a>(out: &mut OutFile<'a>, iter: &'a IterImpl) {
+    let resolve = out.types.resolve(&iter.self_ty);
+    out.set_namespace(&resolve.name.namespace);
+    out.begin_block(Block::ExternC);
+    out.pragma.dollar_in_identifier = true;
+    out.pragma.missing_declarations = true;
+
+    let link_name = mangle::iterator(resolve.name, "next");
+    write!(out, "bool {}({} &self, ", link_name, resolve.name.cxx);
+    write_type_space(out, &iter.item);
+    writeln!(out, "*item) noexcept;");
+
+    out.end_block(Block::ExternC);
+}
+
+fn write_rust_iterator<'a>(out: &mut OutFile<'a>, iter: &'a IterImpl) {
+    if out.header {
+        return;
+    }
+
+    let resolve = out.types.resolve(&iter.self_ty);
+    out.set_namespace(&resolve.name.namespace);
+    out.pragma.dollar_in_identifier = true;
+
+    out.next_section();
+    let link_name = mangle::iterator(resolve.name, "next");
+    write!(out, "::rust::Iter<");
+    write_type(out, &iter.item);
+    writeln!(out, "> {}::begin() noexcept {{", resolve.name.cxx);
+    write!(out, "  return ::rust::Iter<");
+    write_type(out, &iter.item);
+    write!(out, ">(this, [](void *self, ");
+    write_type_space(out, &iter.item);
+    writeln!(out, "*item) noexcept {{");
+    writeln!(
+        out,
+        "    return {}(*static_cast<{} *>(self), item);",
+        link_name, resolve.name.cxx,
+    );
+    writeln!(out, "  }});");
+    writeln!(out, "}}");
+
+    out.next_section();
+    write!(out, "::rust::Iter<");
+    write_type(out, &iter.item);
+    writeln!(out, "> {}::end() noexcept {{", resolve.name.cxx);
+    writeln!(out, "  return {{}};");
+    writeln!(out, "}}");
+}
+
 fn begin_function_definition(out: &mut OutFile) {
     if let Some(annotation) = &out.opt.cxx_impl_annotations {
         write!(out, "{} ", annotation);
@@ -914,7 +1244,7 @@ fn write_cxx_function_shim<'a>(out: &mut OutFile<'a>, efn: &'a ExternFn) {
         if i > 0 {
             write!(out, ", ");
         }
-        write_type(out, &arg.ty);
+        write_arg_type(out, arg);
     }
     write!(out, ")");
     if let Some(receiver) = efn.receiver() {
@@ -932,6 +1262,16 @@ fn write_cxx_function_shim<'a>(out: &mut OutFile<'a>, efn: &'a ExternFn) {
             efn.name.cxx,
         ),
     }
+    if let Some(template_args) = &efn.cxx_template {
+        write!(out, "<");
+        for (i, template_arg) in template_args.iter().enumerate() {
+            if i > 0 {
+                write!(out, ", ");
+            }
+            write_type(out, template_arg);
+        }
+        write!(out, ">");
+    }
     writeln!(out, ";");
     write!(out, "  ");
     if efn.throws {
@@ -972,7 +1312,7 @@ fn write_cxx_function_shim<'a>(out: &mut OutFile<'a>, efn: &'a ExternFn) {
         if i > 0 {
             write!(out, ", ");
         }
-        if let Type::RustBox(_) = &arg.ty {
+        if let Type::RustBox(_) | Type::RustArc(_) = &arg.ty {
             write_type(out, &arg.ty);
             write!(out, "::from_raw({})", arg.name.cxx);
         } else if let Type::UniquePtr(_) = &arg.ty {
@@ -998,7 +1338,7 @@ fn write_cxx_function_shim<'a>(out: &mut OutFile<'a>, efn: &'a ExternFn) {
     }
     write!(out, ")");
     match &efn.ret {
-        Some(Type::RustBox(_)) => write!(out, ".into_raw()"),
+        Some(Type::RustBox(_) | Type::RustArc(_)) => write!(out, ".into_raw()"),
         Some(Type::UniquePtr(_)) => write!(out, ".release()"),
         Some(Type::Str(_) | Type::SliceRef(_)) if !indirect_return => write!(out, ")"),
         _ => {}
@@ -1054,6 +1394,92 @@ fn write_rust_function_decl<'a>(out: &mut OutFile<'a>, efn: &'a ExternFn) {
     out.end_block(Block::ExternC);
 }
 
+fn write_cxx_static_shim<'a>(out: &mut OutFile<'a>, stc: &'a ExternStatic) {
+    out.pragma.dollar_in_identifier = true;
+    out.pragma.missing_declarations = true;
+    out.next_section();
+    out.set_namespace(&stc.name.namespace);
+    out.begin_block(Block::ExternC);
+    begin_function_definition(out);
+    write_type(out, &stc.ty);
+    let link_name = mangle::extern_static(stc);
+    writeln!(out, " const *{}() noexcept {{", link_name);
+    writeln!(out, "  return &{};", stc.name.to_fully_qualified());
+    writeln!(out, "}}");
+    out.end_block(Block::ExternC);
+}
+
+fn write_shared_from_this_shims<'a>(out: &mut OutFile<'a>, ety: &'a ExternType) {
+    if ety.shared_from_this.is_none() {
+        return;
+    }
+
+    out.builtin.shared_from_this = true;
+    out.pragma.dollar_in_identifier = true;
+    out.pragma.missing_declarations = true;
+    out.next_section();
+    out.set_namespace(&ety.name.namespace);
+    out.begin_block(Block::ExternC);
+    let qualified = ety.name.to_fully_qualified();
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void {}({} const &self, ::std::shared_ptr<{}> *ptr) noexcept {{",
+        mangle::enable_shared_from_this(&ety.name, "shared"),
+        qualified,
+        qualified,
+    );
+    writeln!(
+        out,
+        "  ::new (ptr) ::std::shared_ptr<{}>(::rust::shared_from_this(self));",
+        qualified,
+    );
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void {}({} const &self, ::std::weak_ptr<{}> *ptr) noexcept {{",
+        mangle::enable_shared_from_this(&ety.name, "weak"),
+        qualified,
+        qualified,
+    );
+    writeln!(
+        out,
+        "  ::new (ptr) ::std::weak_ptr<{}>(::rust::shared_from_this(self));",
+        qualified,
+    );
+    writeln!(out, "}}");
+
+    out.end_block(Block::ExternC);
+}
+
+fn write_rust_static<'a>(out: &mut OutFile<'a>, stc: &'a ExternStatic) {
+    // Exposed through an inline accessor rather than a global reference, so
+    // that it is usable from other C++ static initializers regardless of
+    // initialization order. The pointer it reads is constant-initialized data
+    // emitted by the Rust side.
+    if !out.header {
+        return;
+    }
+
+    out.pragma.dollar_in_identifier = true;
+    out.set_namespace(&stc.name.namespace);
+    let link_name = mangle::extern_static(stc);
+    out.begin_block(Block::ExternC);
+    write!(out, "extern ");
+    write_type(out, &stc.ty);
+    writeln!(out, " const *const {};", link_name);
+    out.end_block(Block::ExternC);
+    write_doc(out, "", &stc.doc);
+    write!(out, "inline ");
+    write_type(out, &stc.ty);
+    writeln!(out, " const &{}() noexcept {{", stc.name.cxx);
+    writeln!(out, "  return *{};", link_name);
+    writeln!(out, "}}");
+}
+
 fn write_rust_function_decl_impl(
     out: &mut OutFile,
     link_name: &Symbol,
@@ -1062,7 +1488,7 @@ fn write_rust_function_decl_impl(
 ) {
     out.next_section();
     out.pragma.dollar_in_identifier = true;
-    if sig.throws {
+    if sig.fallible() {
         out.builtin.ptr_len = true;
         write!(out, "::rust::repr::PtrLen ");
     } else {
@@ -1106,6 +1532,13 @@ fn write_rust_function_decl_impl(
         write!(out, "*return$");
         needs_comma = true;
     }
+    if sig.catch_unwind {
+        if needs_comma {
+            write!(out, ", ");
+        }
+        write!(out, "bool *panic$");
+        needs_comma = true;
+    }
     if indirect_call {
         if needs_comma {
             write!(out, ", ");
@@ -1117,7 +1550,7 @@ fn write_rust_function_decl_impl(
 
 fn write_rust_function_shim<'a>(out: &mut OutFile<'a>, efn: &'a ExternFn) {
     out.set_namespace(&efn.name.namespace);
-    let local_name = match efn.self_type() {
+    let mut local_name = match efn.self_type() {
         None => efn.name.cxx.to_string(),
         Some(self_type) => format!(
             "{}::{}",
@@ -1125,7 +1558,18 @@ fn write_rust_function_shim<'a>(out: &mut OutFile<'a>, efn: &'a ExternFn) {
             efn.name.cxx,
         ),
     };
-    let doc = &efn.doc;
+    let mut doc = &efn.doc;
+    let no_doc = Doc::new();
+    if let Some(instance) = &efn.instance {
+        if let Some(template) = &instance.template {
+            write_rust_function_template(out, efn, &instance.param, template);
+        }
+        // The explicit specialization is documented by its primary template.
+        doc = &no_doc;
+        let arg = Type::Ident(NamedType::new(instance.arg.rust.clone()));
+        local_name = format!("{}<{}>", local_name, stringify_type(&arg, out.types));
+        writeln!(out, "template <>");
+    }
     let invoke = mangle::extern_fn(efn, out.types);
     let indirect_call = false;
     let main = efn.name.cxx == *"main"
@@ -1134,10 +1578,21 @@ fn write_rust_function_shim<'a>(out: &mut OutFile<'a>, efn: &'a ExternFn) {
         && matches!(efn.kind, FnKind::Free)
         && efn.sig.args.is_empty()
         && efn.sig.ret.is_none()
-        && !efn.sig.throws;
+        && !efn.sig.fallible();
     write_rust_function_shim_impl(out, &local_name, efn, doc, &invoke, indirect_call, main);
 }
 
+fn write_rust_function_template(out: &mut OutFile, efn: &ExternFn, param: &Ident, sig: &Signature) {
+    write_doc(out, "", &efn.doc);
+    writeln!(out, "template <typename {}>", param);
+    let local_name = efn.name.cxx.to_string();
+    let in_class = false;
+    let indirect_call = false;
+    let main = false;
+    write_rust_function_shim_decl(out, &local_name, sig, in_class, indirect_call, main);
+    writeln!(out, ";");
+}
+
 fn write_rust_function_shim_decl(
     out: &mut OutFile,
     local_name: &str,
@@ -1160,8 +1615,14 @@ fn write_rust_function_shim_decl(
         if i > 0 {
             write!(out, ", ");
         }
-        write_type_space(out, &arg.ty);
+        write_arg_type_space(out, arg);
         write!(out, "{}", arg.name.cxx);
+        if let Some(cxx_default) = &arg.cxx_default {
+            // Default arguments belong on the first declaration only.
+            if in_class || out.header {
+                write!(out, " = {}", cxx_default.value());
+            }
+        }
     }
     if indirect_call {
         if !sig.args.is_empty() {
@@ -1175,7 +1636,7 @@ fn write_rust_function_shim_decl(
             write!(out, " const");
         }
     }
-    if !sig.throws {
+    if !sig.fallible() {
         write!(out, " noexcept");
     }
 }
@@ -1237,7 +1698,7 @@ fn write_rust_function_shim_impl(
     } else if let Some(ret) = &sig.ret {
         write!(out, "return ");
         match ret {
-            Type::RustBox(_) => {
+            Type::RustBox(_) | Type::RustArc(_) => {
                 write_type(out, ret);
                 write!(out, "::from_raw(");
             }
@@ -1259,7 +1720,11 @@ fn write_rust_function_shim_impl(
             _ => {}
         }
     }
-    if sig.throws {
+    if sig.catch_unwind {
+        writeln!(out, "bool panic$ = false;");
+        write!(out, "  ");
+    }
+    if sig.fallible() {
         out.builtin.ptr_len = true;
         write!(out, "::rust::repr::PtrLen error$ = ");
     }
@@ -1278,7 +1743,7 @@ fn write_rust_function_shim_impl(
         }
         write!(out, "{}", arg.name.cxx);
         match &arg.ty {
-            Type::RustBox(_) => write!(out, ".into_raw()"),
+            Type::RustBox(_) | Type::RustArc(_) => write!(out, ".into_raw()"),
             Type::UniquePtr(_) => write!(out, ".release()"),
             ty if ty != RustString && out.types.needs_indirect_abi(ty) => write!(out, "$.value"),
             _ => {}
@@ -1292,6 +1757,13 @@ fn write_rust_function_shim_impl(
         write!(out, "&return$.value");
         needs_comma = true;
     }
+    if sig.catch_unwind {
+        if needs_comma {
+            write!(out, ", ");
+        }
+        write!(out, "&panic$");
+        needs_comma = true;
+    }
     if indirect_call {
         if needs_comma {
             write!(out, ", ");
@@ -1300,17 +1772,37 @@ fn write_rust_function_shim_impl(
     }
     write!(out, ")");
     if !indirect_return {
-        if let Some(Type::RustBox(_) | Type::UniquePtr(_) | Type::Str(_) | Type::SliceRef(_)) =
-            &sig.ret
+        if let Some(
+            Type::RustBox(_)
+            | Type::RustArc(_)
+            | Type::UniquePtr(_)
+            | Type::Str(_)
+            | Type::SliceRef(_),
+        ) = &sig.ret
         {
             write!(out, ")");
         }
     }
     writeln!(out, ";");
+    if sig.catch_unwind {
+        out.builtin.rust_panic = true;
+        writeln!(out, "  if (panic$) {{");
+        writeln!(out, "    throw ::rust::impl<::rust::Panic>::panic(error$);");
+        writeln!(out, "  }}");
+    }
     if sig.throws {
         out.builtin.rust_error = true;
+        let constructor = if sig.error_details {
+            "error_details"
+        } else {
+            "error"
+        };
         writeln!(out, "  if (error$.ptr) {{");
-        writeln!(out, "    throw ::rust::impl<::rust::Error>::error(error$);");
+        writeln!(
+            out,
+            "    throw ::rust::impl<::rust::Error>::{}(error$);",
+            constructor,
+        );
         writeln!(out, "  }}");
     }
     if indirect_return {
@@ -1336,7 +1828,7 @@ fn write_return_type(out: &mut OutFile, ty: &Option<Type>) {
 
 fn indirect_return(sig: &Signature, types: &Types, lang: Lang) -> bool {
     sig.ret.as_ref().is_some_and(|ret| {
-        sig.throws
+        sig.fallible()
             || types.needs_indirect_abi(ret)
             || match lang {
                 Lang::Cxx | Lang::CxxUnwind => types.contains_elided_lifetime(ret),
@@ -1351,6 +1843,10 @@ fn write_indirect_return_type(out: &mut OutFile, ty: &Type) {
             write_type_space(out, &ty.inner);
             write!(out, "*");
         }
+        Type::RustArc(ty) => {
+            write_type_space(out, &ty.inner);
+            write!(out, "const *");
+        }
         Type::Ref(ty) => {
             write_type_space(out, &ty.inner);
             if !ty.mutable {
@@ -1365,7 +1861,7 @@ fn write_indirect_return_type(out: &mut OutFile, ty: &Type) {
 fn write_indirect_return_type_space(out: &mut OutFile, ty: &Type) {
     write_indirect_return_type(out, ty);
     match ty {
-        Type::RustBox(_) | Type::UniquePtr(_) | Type::Ref(_) => {}
+        Type::RustBox(_) | Type::RustArc(_) | Type::UniquePtr(_) | Type::Ref(_) => {}
         Type::Str(_) | Type::SliceRef(_) => write!(out, " "),
         _ => write_space_after_type(out, ty),
     }
@@ -1378,6 +1874,10 @@ fn write_extern_return_type_space(out: &mut OutFile, sig: &Signature, lang: Lang
             write_type_space(out, &ty.inner);
             write!(out, "*");
         }
+        Some(Type::RustArc(ty)) => {
+            write_type_space(out, &ty.inner);
+            write!(out, "const *");
+        }
         Some(Type::Ref(ty)) => {
             write_type_space(out, &ty.inner);
             if !ty.mutable {
@@ -1399,7 +1899,11 @@ fn write_extern_arg(out: &mut OutFile, arg: &Var) {
             write_type_space(out, &ty.inner);
             write!(out, "*");
         }
-        _ => write_type_space(out, &arg.ty),
+        Type::RustArc(ty) => {
+            write_type_space(out, &ty.inner);
+            write!(out, "const *");
+        }
+        _ => write_arg_type_space(out, arg),
     }
     if out.types.needs_indirect_abi(&arg.ty) {
         write!(out, "*");
@@ -1411,6 +1915,26 @@ fn write_type(out: &mut OutFile, ty: &Type) {
     write_type_to_generic_writer(out, ty, out.types);
 }
 
+// An argument marked #[cxx_char16] is a &[u16] that C++ sees as a slice of
+// char16_t, which has the same size and alignment as uint16_t.
+fn write_arg_type(out: &mut OutFile, arg: &Var) {
+    match &arg.ty {
+        Type::SliceRef(slice) if arg.cxx_char16 => {
+            write!(out, "::rust::Slice<char16_t");
+            if slice.mutability.is_none() {
+                write!(out, " const");
+            }
+            write!(out, ">");
+        }
+        ty => write_type(out, ty),
+    }
+}
+
+fn write_arg_type_space(out: &mut OutFile, arg: &Var) {
+    write_arg_type(out, arg);
+    write_space_after_type(out, &arg.ty);
+}
+
 fn stringify_type(ty: &Type, types: &Types) -> String {
     let mut s = String::new();
     write_type_to_generic_writer(&mut s, ty, types);
@@ -1421,6 +1945,7 @@ fn write_type_to_generic_writer(out: &mut impl InfallibleWrite, ty: &Type, types
     match ty {
         Type::Ident(ident) => match Atom::from(&ident.rust) {
             Some(atom) => write_atom(out, atom),
+            None if types.template_params.contains(&ident.rust) => write!(out, "{}", ident.rust),
             None => write!(out, "{}", types.resolve(ident).name.to_fully_qualified()),
         },
         Type::RustBox(ty) => {
@@ -1428,6 +1953,11 @@ fn write_type_to_generic_writer(out: &mut impl InfallibleWrite, ty: &Type, types
             write_type_to_generic_writer(out, &ty.inner, types);
             write!(out, ">");
         }
+        Type::RustArc(ty) => {
+            write!(out, "::rust::Arc<");
+            write_type_to_generic_writer(out, &ty.inner, types);
+            write!(out, ">");
+        }
         Type::RustVec(ty) => {
             write!(out, "::rust::Vec<");
             write_type_to_generic_writer(out, &ty.inner, types);
@@ -1436,6 +1966,9 @@ fn write_type_to_generic_writer(out: &mut impl InfallibleWrite, ty: &Type, types
         Type::UniquePtr(ptr) => {
             write!(out, "::std::unique_ptr<");
             write_type_to_generic_writer(out, &ptr.inner, types);
+            if let Some(cxx_deleter) = types.cxx_deleter(&ptr.inner) {
+                write!(out, ", {}", cxx_deleter.value());
+            }
             write!(out, ">");
         }
         Type::SharedPtr(ptr) => {
@@ -1453,6 +1986,21 @@ fn write_type_to_generic_writer(out: &mut impl InfallibleWrite, ty: &Type, types
             write_type_to_generic_writer(out, &ty.inner, types);
             write!(out, ">");
         }
+        Type::CxxDeque(ty) => {
+            write!(out, "::std::deque<");
+            write_type_to_generic_writer(out, &ty.inner, types);
+            write!(out, ">");
+        }
+        Type::CxxList(ty) => {
+            write!(out, "::std::list<");
+            write_type_to_generic_writer(out, &ty.inner, types);
+            write!(out, ">");
+        }
+        Type::CxxSet(ty) => {
+            write!(out, "::std::set<");
+            write_type_to_generic_writer(out, &ty.inner, types);
+            write!(out, ">");
+        }
         Type::Ref(r) => {
             write_type_space_to_generic_writer(out, &r.inner, types);
             if !r.mutable {
@@ -1519,6 +2067,8 @@ fn write_atom(out: &mut impl InfallibleWrite, atom: Atom) {
         F32 => write!(out, "float"),
         F64 => write!(out, "double"),
         CxxString => write!(out, "::std::string"),
+        CxxU16String => write!(out, "::std::u16string"),
+        CxxU32String => write!(out, "::std::u32string"),
         RustString => write!(out, "::rust::String"),
     }
 }
@@ -1536,11 +2086,15 @@ fn write_space_after_type(out: &mut impl InfallibleWrite, ty: &Type) {
     match ty {
         Type::Ident(_)
         | Type::RustBox(_)
+        | Type::RustArc(_)
         | Type::UniquePtr(_)
         | Type::SharedPtr(_)
         | Type::WeakPtr(_)
         | Type::Str(_)
         | Type::CxxVector(_)
+        | Type::CxxDeque(_)
+        | Type::CxxList(_)
+        | Type::CxxSet(_)
         | Type::RustVec(_)
         | Type::SliceRef(_)
         | Type::Fn(_)
@@ -1562,11 +2116,15 @@ fn write_generic_instantiations(out: &mut OutFile) {
         out.next_section();
         match impl_key {
             ImplKey::RustBox(ident) => write_rust_box_extern(out, ident),
+            ImplKey::RustArc(ident) => write_rust_arc_extern(out, ident),
             ImplKey::RustVec(ident) => write_rust_vec_extern(out, ident),
             ImplKey::UniquePtr(ident) => write_unique_ptr(out, ident),
             ImplKey::SharedPtr(ident) => write_shared_ptr(out, ident),
             ImplKey::WeakPtr(ident) => write_weak_ptr(out, ident),
             ImplKey::CxxVector(ident) => write_cxx_vector(out, ident),
+            ImplKey::CxxDeque(ident) => write_cxx_deque(out, ident),
+            ImplKey::CxxList(ident) => write_cxx_list(out, ident),
+            ImplKey::CxxSet(ident) => write_cxx_set(out, ident),
         }
     }
     out.end_block(Block::ExternC);
@@ -1576,6 +2134,7 @@ fn write_generic_instantiations(out: &mut OutFile) {
     for impl_key in out.types.impls.keys() {
         match impl_key {
             ImplKey::RustBox(ident) => write_rust_box_impl(out, ident),
+            ImplKey::RustArc(ident) => write_rust_arc_impl(out, ident),
             ImplKey::RustVec(ident) => write_rust_vec_impl(out, ident),
             _ => {}
         }
@@ -1607,7 +2166,25 @@ fn write_rust_box_extern(out: &mut OutFile, key: &NamedImplKey) {
     );
 }
 
-fn write_rust_vec_extern(out: &mut OutFile, key: &NamedImplKey) {
+fn write_rust_arc_extern(out: &mut OutFile, key: &NamedImplKey) {
+    let inner = stringify_type(key.inner, out.types);
+    let instance = &key.symbol;
+
+    out.pragma.dollar_in_identifier = true;
+
+    writeln!(
+        out,
+        "void cxxbridge1$arc${}$increment({} const *ptr) noexcept;",
+        instance, inner,
+    );
+    writeln!(
+        out,
+        "void cxxbridge1$arc${}$drop({} const *ptr) noexcept;",
+        instance, inner,
+    );
+}
+
+fn write_rust_vec_extern(out: &mut OutFile, key: &NamedImplKey) {
     let inner = stringify_type(key.inner, out.types);
     let instance = &key.symbol;
 
@@ -1689,6 +2266,25 @@ fn write_rust_box_impl(out: &mut OutFile, key: &NamedImplKey) {
     writeln!(out, "}}");
 }
 
+fn write_rust_arc_impl(out: &mut OutFile, key: &NamedImplKey) {
+    let inner = stringify_type(key.inner, out.types);
+    let instance = &key.symbol;
+
+    out.pragma.dollar_in_identifier = true;
+
+    writeln!(out, "template <>");
+    begin_function_definition(out);
+    writeln!(out, "void Arc<{}>::increment() noexcept {{", inner);
+    writeln!(out, "  cxxbridge1$arc${}$increment(this->ptr);", instance);
+    writeln!(out, "}}");
+
+    writeln!(out, "template <>");
+    begin_function_definition(out);
+    writeln!(out, "void Arc<{}>::drop() noexcept {{", inner);
+    writeln!(out, "  cxxbridge1$arc${}$drop(this->ptr);", instance);
+    writeln!(out, "}}");
+}
+
 fn write_rust_vec_impl(out: &mut OutFile, key: &NamedImplKey) {
     let inner = stringify_type(key.inner, out.types);
     let instance = &key.symbol;
@@ -1781,7 +2377,8 @@ fn write_unique_ptr(out: &mut OutFile, key: &NamedImplKey) {
     write_unique_ptr_common(out, key.inner);
 }
 
-// Shared by UniquePtr<T> and UniquePtr<CxxVector<T>>.
+// Shared by UniquePtr<T> and UniquePtr<C> for each C++ container type C, like
+// UniquePtr<CxxVector<T>>.
 fn write_unique_ptr_common(out: &mut OutFile, ty: &Type) {
     out.include.new = true;
     out.include.utility = true;
@@ -1791,12 +2388,19 @@ fn write_unique_ptr_common(out: &mut OutFile, ty: &Type) {
     let inner = stringify_type(ty, out.types);
     let instance = mangle::typename(ty, &out.types.resolutions)
         .expect("unexpected UniquePtr generic parameter allowed through by syntax/check.rs");
+    let cxx_deleter = out.types.cxx_deleter(ty);
+    let unique_ptr = match cxx_deleter {
+        Some(cxx_deleter) => format!("::std::unique_ptr<{}, {}>", inner, cxx_deleter.value()),
+        None => format!("::std::unique_ptr<{}>", inner),
+    };
 
     // Some aliases are to opaque types; some are to trivial types. We can't
     // know at code generation time, so we generate both C++ and Rust side
     // bindings for a "new" method anyway. But the Rust code can't be called for
-    // Opaque types because the 'new' method is not implemented.
-    let can_construct_from_value = out.types.is_maybe_trivial(ty);
+    // Opaque types because the 'new' method is not implemented. A custom
+    // deleter would not know how to free memory allocated by operator new, so
+    // no "new" method is available in that case.
+    let can_construct_from_value = out.types.is_maybe_trivial(ty) && cxx_deleter.is_none();
 
     out.builtin.is_complete = true;
     writeln!(
@@ -1804,24 +2408,28 @@ fn write_unique_ptr_common(out: &mut OutFile, ty: &Type) {
         "static_assert(::rust::detail::is_complete<::std::remove_extent<{}>::type>::value, \"definition of `{}` is required\");",
         inner, inner,
     );
+    let size_msg = match cxx_deleter {
+        Some(cxx_deleter) => format!("deleter `{}` must be stateless", cxx_deleter.value()),
+        None => String::new(),
+    };
     writeln!(
         out,
-        "static_assert(sizeof(::std::unique_ptr<{}>) == sizeof(void *), \"\");",
-        inner,
+        "static_assert(sizeof({}) == sizeof(void *), \"{}\");",
+        unique_ptr, size_msg,
     );
     writeln!(
         out,
-        "static_assert(alignof(::std::unique_ptr<{}>) == alignof(void *), \"\");",
-        inner,
+        "static_assert(alignof({}) == alignof(void *), \"{}\");",
+        unique_ptr, size_msg,
     );
 
     begin_function_definition(out);
     writeln!(
         out,
-        "void cxxbridge1$unique_ptr${}$null(::std::unique_ptr<{}> *ptr) noexcept {{",
-        instance, inner,
+        "void cxxbridge1$unique_ptr${}$null({} *ptr) noexcept {{",
+        instance, unique_ptr,
     );
-    writeln!(out, "  ::new (ptr) ::std::unique_ptr<{}>();", inner);
+    writeln!(out, "  ::new (ptr) {}();", unique_ptr);
     writeln!(out, "}}");
 
     if can_construct_from_value {
@@ -1830,15 +2438,15 @@ fn write_unique_ptr_common(out: &mut OutFile, ty: &Type) {
         begin_function_definition(out);
         writeln!(
             out,
-            "{} *cxxbridge1$unique_ptr${}$uninit(::std::unique_ptr<{}> *ptr) noexcept {{",
-            inner, instance, inner,
+            "{} *cxxbridge1$unique_ptr${}$uninit({} *ptr) noexcept {{",
+            inner, instance, unique_ptr,
         );
         writeln!(
             out,
             "  {} *uninit = reinterpret_cast<{} *>(new ::rust::MaybeUninit<{}>);",
             inner, inner, inner,
         );
-        writeln!(out, "  ::new (ptr) ::std::unique_ptr<{}>(uninit);", inner);
+        writeln!(out, "  ::new (ptr) {}(uninit);", unique_ptr);
         writeln!(out, "  return uninit;");
         writeln!(out, "}}");
     }
@@ -1846,17 +2454,17 @@ fn write_unique_ptr_common(out: &mut OutFile, ty: &Type) {
     begin_function_definition(out);
     writeln!(
         out,
-        "void cxxbridge1$unique_ptr${}$raw(::std::unique_ptr<{}> *ptr, ::std::unique_ptr<{}>::pointer raw) noexcept {{",
-        instance, inner, inner,
+        "void cxxbridge1$unique_ptr${}$raw({} *ptr, {}::pointer raw) noexcept {{",
+        instance, unique_ptr, unique_ptr,
     );
-    writeln!(out, "  ::new (ptr) ::std::unique_ptr<{}>(raw);", inner);
+    writeln!(out, "  ::new (ptr) {}(raw);", unique_ptr);
     writeln!(out, "}}");
 
     begin_function_definition(out);
     writeln!(
         out,
-        "::std::unique_ptr<{}>::element_type const *cxxbridge1$unique_ptr${}$get(::std::unique_ptr<{}> const &ptr) noexcept {{",
-        inner, instance, inner,
+        "{}::element_type const *cxxbridge1$unique_ptr${}$get({} const &ptr) noexcept {{",
+        unique_ptr, instance, unique_ptr,
     );
     writeln!(out, "  return ptr.get();");
     writeln!(out, "}}");
@@ -1864,8 +2472,8 @@ fn write_unique_ptr_common(out: &mut OutFile, ty: &Type) {
     begin_function_definition(out);
     writeln!(
         out,
-        "::std::unique_ptr<{}>::pointer cxxbridge1$unique_ptr${}$release(::std::unique_ptr<{}> &ptr) noexcept {{",
-        inner, instance, inner,
+        "{}::pointer cxxbridge1$unique_ptr${}$release({} &ptr) noexcept {{",
+        unique_ptr, instance, unique_ptr,
     );
     writeln!(out, "  return ptr.release();");
     writeln!(out, "}}");
@@ -1873,8 +2481,8 @@ fn write_unique_ptr_common(out: &mut OutFile, ty: &Type) {
     begin_function_definition(out);
     writeln!(
         out,
-        "void cxxbridge1$unique_ptr${}$drop(::std::unique_ptr<{}> *ptr) noexcept {{",
-        instance, inner,
+        "void cxxbridge1$unique_ptr${}$drop({} *ptr) noexcept {{",
+        instance, unique_ptr,
     );
     out.builtin.deleter_if = true;
     writeln!(
@@ -1946,12 +2554,24 @@ fn write_shared_ptr(out: &mut OutFile, key: &NamedImplKey) {
         "bool cxxbridge1$shared_ptr${}$raw(::std::shared_ptr<{}> *ptr, ::std::shared_ptr<{}>::element_type *raw) noexcept {{",
         instance, inner, inner,
     );
-    writeln!(
-        out,
-        "  ::new (ptr) ::rust::shared_ptr_if_destructible<{}>(raw);",
-        inner,
-    );
-    writeln!(out, "  return ::rust::is_destructible<{}>::value;", inner);
+    if let Some(cxx_deleter) = out.types.cxx_deleter(key.inner) {
+        // Ownership arriving from UniquePtr<T> must be released through the
+        // same deleter that std::unique_ptr<T, D> would have used.
+        writeln!(
+            out,
+            "  ::new (ptr) ::std::shared_ptr<{}>(raw, {}{{}});",
+            inner,
+            cxx_deleter.value(),
+        );
+        writeln!(out, "  return true;");
+    } else {
+        writeln!(
+            out,
+            "  ::new (ptr) ::rust::shared_ptr_if_destructible<{}>(raw);",
+            inner,
+        );
+        writeln!(out, "  return ::rust::is_destructible<{}>::value;", inner);
+    }
     writeln!(out, "}}");
 
     begin_function_definition(out);
@@ -1980,6 +2600,41 @@ fn write_shared_ptr(out: &mut OutFile, key: &NamedImplKey) {
     );
     writeln!(out, "  self->~shared_ptr();");
     writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "::std::size_t cxxbridge1$shared_ptr${}$use_count(::std::shared_ptr<{}> const &self) noexcept {{",
+        instance, inner,
+    );
+    writeln!(
+        out,
+        "  return static_cast<::std::size_t>(self.use_count());",
+    );
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void cxxbridge1$shared_ptr${}$erase(::std::shared_ptr<{}> const &self, ::std::shared_ptr<void> *erased) noexcept {{",
+        instance, inner,
+    );
+    writeln!(out, "  ::new (erased) ::std::shared_ptr<void>(self);");
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void cxxbridge1$shared_ptr${}$alias(::std::shared_ptr<void> *erased, ::std::shared_ptr<{}>::element_type *ptr, ::std::shared_ptr<{}> *alias) noexcept {{",
+        instance, inner, inner,
+    );
+    writeln!(
+        out,
+        "  ::new (alias) ::std::shared_ptr<{}>(*erased, ptr);",
+        inner,
+    );
+    writeln!(out, "  erased->~shared_ptr();");
+    writeln!(out, "}}");
 }
 
 fn write_weak_ptr(out: &mut OutFile, key: &NamedImplKey) {
@@ -2050,6 +2705,18 @@ fn write_weak_ptr(out: &mut OutFile, key: &NamedImplKey) {
     );
     writeln!(out, "  self->~weak_ptr();");
     writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "::std::size_t cxxbridge1$weak_ptr${}$use_count(::std::weak_ptr<{}> const &self) noexcept {{",
+        instance, inner,
+    );
+    writeln!(
+        out,
+        "  return static_cast<::std::size_t>(self.use_count());",
+    );
+    writeln!(out, "}}");
 }
 
 fn write_cxx_vector(out: &mut OutFile, key: &NamedImplKey) {
@@ -2112,6 +2779,26 @@ fn write_cxx_vector(out: &mut OutFile, key: &NamedImplKey) {
     );
     writeln!(out, "}}");
 
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void cxxbridge1$std$vector${}$truncate(::std::vector<{}> *s, ::std::size_t len) noexcept {{",
+        instance, inner,
+    );
+    writeln!(out, "  while (s->size() > len) {{");
+    writeln!(out, "    s->pop_back();");
+    writeln!(out, "  }}");
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void cxxbridge1$std$vector${}$swap(::std::vector<{}> *s, ::std::vector<{}> *other) noexcept {{",
+        instance, inner, inner,
+    );
+    writeln!(out, "  s->swap(*other);");
+    writeln!(out, "}}");
+
     if out.types.is_maybe_trivial(key.inner) {
         begin_function_definition(out);
         writeln!(
@@ -2132,8 +2819,235 @@ fn write_cxx_vector(out: &mut OutFile, key: &NamedImplKey) {
         writeln!(out, "  ::new (out) {}(::std::move(v->back()));", inner);
         writeln!(out, "  v->pop_back();");
         writeln!(out, "}}");
+
+        begin_function_definition(out);
+        writeln!(
+            out,
+            "{} *cxxbridge1$unique_ptr$array${}$new(::std::size_t len) noexcept {{",
+            inner, instance,
+        );
+        writeln!(
+            out,
+            "  return ::rust::if_default_constructible_array<{}>::new_array(len);",
+            inner,
+        );
+        writeln!(out, "}}");
+
+        begin_function_definition(out);
+        writeln!(
+            out,
+            "void cxxbridge1$unique_ptr$array${}$delete({} *ptr) noexcept {{",
+            instance, inner,
+        );
+        writeln!(out, "  delete[] ptr;");
+        writeln!(out, "}}");
     }
 
     out.include.memory = true;
     write_unique_ptr_common(out, key.outer);
 }
+
+// Shared by CxxDeque<T>, CxxList<T> and CxxSet<T>.
+fn write_cxx_container_common(out: &mut OutFile, key: &NamedImplKey, container: &str) {
+    let inner = stringify_type(key.inner, out.types);
+    let instance = &key.symbol;
+
+    out.include.cstddef = true;
+    out.builtin.range_iter = true;
+    out.pragma.dollar_in_identifier = true;
+    out.pragma.missing_declarations = true;
+
+    let range_iter = format!("::rust::range_iter<::std::{}<{}> const>", container, inner);
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "::std::{}<{}> *cxxbridge1$std${}${}$new() noexcept {{",
+        container, inner, container, instance,
+    );
+    writeln!(out, "  return new ::std::{}<{}>();", container, inner);
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "::std::size_t cxxbridge1$std${}${}$size(::std::{}<{}> const &s) noexcept {{",
+        container, instance, container, inner,
+    );
+    writeln!(out, "  return s.size();");
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void cxxbridge1$std${}${}$clear(::std::{}<{}> *s) noexcept {{",
+        container, instance, container, inner,
+    );
+    writeln!(out, "  s->clear();");
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void *cxxbridge1$std${}${}$iter(::std::{}<{}> const &s) noexcept {{",
+        container, instance, container, inner,
+    );
+    writeln!(out, "  return new {}(s);", range_iter);
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "{} const *cxxbridge1$std${}${}$iter_next(void *iter) noexcept {{",
+        inner, container, instance,
+    );
+    writeln!(out, "  return static_cast<{} *>(iter)->next();", range_iter);
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void cxxbridge1$std${}${}$iter_drop(void *iter) noexcept {{",
+        container, instance,
+    );
+    writeln!(out, "  delete static_cast<{} *>(iter);", range_iter);
+    writeln!(out, "}}");
+}
+
+// Shared by CxxDeque<T> and CxxList<T>, which both have push and pop at either
+// end, as well as insert and remove at a position, for elements that can be
+// passed by value.
+fn write_cxx_container_push_pop(out: &mut OutFile, key: &NamedImplKey, container: &str) {
+    if !out.types.is_maybe_trivial(key.inner) {
+        return;
+    }
+
+    let inner = stringify_type(key.inner, out.types);
+    let instance = &key.symbol;
+
+    out.include.utility = true;
+    out.builtin.destroy = true;
+
+    for end in ["back", "front"] {
+        begin_function_definition(out);
+        writeln!(
+            out,
+            "void cxxbridge1$std${}${}$push_{}(::std::{}<{}> *s, {} *value) noexcept {{",
+            container, instance, end, container, inner, inner,
+        );
+        writeln!(out, "  s->push_{}(::std::move(*value));", end);
+        writeln!(out, "  ::rust::destroy(value);");
+        writeln!(out, "}}");
+
+        begin_function_definition(out);
+        writeln!(
+            out,
+            "void cxxbridge1$std${}${}$pop_{}(::std::{}<{}> *s, {} *out) noexcept {{",
+            container, instance, end, container, inner, inner,
+        );
+        writeln!(out, "  ::new (out) {}(::std::move(s->{}()));", inner, end);
+        writeln!(out, "  s->pop_{}();", end);
+        writeln!(out, "}}");
+    }
+
+    out.include.cstddef = true;
+    out.include.iterator = true;
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void cxxbridge1$std${}${}$insert(::std::{}<{}> *s, ::std::size_t pos, {} *value) noexcept {{",
+        container, instance, container, inner, inner,
+    );
+    writeln!(
+        out,
+        "  s->insert(::std::next(s->begin(), static_cast<::std::ptrdiff_t>(pos)), ::std::move(*value));",
+    );
+    writeln!(out, "  ::rust::destroy(value);");
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "void cxxbridge1$std${}${}$remove(::std::{}<{}> *s, ::std::size_t pos, {} *out) noexcept {{",
+        container, instance, container, inner, inner,
+    );
+    writeln!(
+        out,
+        "  auto it = ::std::next(s->begin(), static_cast<::std::ptrdiff_t>(pos));",
+    );
+    writeln!(out, "  ::new (out) {}(::std::move(*it));", inner);
+    writeln!(out, "  s->erase(it);");
+    writeln!(out, "}}");
+}
+
+fn write_cxx_deque(out: &mut OutFile, key: &NamedImplKey) {
+    let inner = stringify_type(key.inner, out.types);
+    let instance = &key.symbol;
+
+    write_cxx_container_common(out, key, "deque");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "{} *cxxbridge1$std$deque${}$get_unchecked(::std::deque<{}> *s, ::std::size_t pos) noexcept {{",
+        inner, instance, inner,
+    );
+    writeln!(out, "  return &(*s)[pos];");
+    writeln!(out, "}}");
+
+    write_cxx_container_push_pop(out, key, "deque");
+
+    out.include.memory = true;
+    write_unique_ptr_common(out, key.outer);
+}
+
+fn write_cxx_list(out: &mut OutFile, key: &NamedImplKey) {
+    write_cxx_container_common(out, key, "list");
+    write_cxx_container_push_pop(out, key, "list");
+
+    out.include.memory = true;
+    write_unique_ptr_common(out, key.outer);
+}
+
+fn write_cxx_set(out: &mut OutFile, key: &NamedImplKey) {
+    let inner = stringify_type(key.inner, out.types);
+    let instance = &key.symbol;
+
+    write_cxx_container_common(out, key, "set");
+    out.builtin.set = true;
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "bool cxxbridge1$std$set${}$contains(::std::set<{}> const &s, {} const &value) noexcept {{",
+        instance, inner, inner,
+    );
+    writeln!(out, "  return s.find(value) != s.end();");
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "bool cxxbridge1$std$set${}$insert(::std::set<{}> *s, {} const &value, bool *inserted) noexcept {{",
+        instance, inner, inner,
+    );
+    writeln!(
+        out,
+        "  return ::rust::if_copy_constructible<{}>::insert(*s, value, inserted);",
+        inner,
+    );
+    writeln!(out, "}}");
+
+    begin_function_definition(out);
+    writeln!(
+        out,
+        "bool cxxbridge1$std$set${}$remove(::std::set<{}> *s, {} const &value) noexcept {{",
+        instance, inner, inner,
+    );
+    writeln!(out, "  return s->erase(value) != 0;");
+    writeln!(out, "}}");
+
+    out.include.memory = true;
+    write_unique_ptr_common(out, key.outer);
+}
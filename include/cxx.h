@@ -26,6 +26,11 @@
 
 #if __cplusplus >= 202002L
 #include <ranges>
+#include <version>
+#endif
+
+#ifdef __cpp_lib_format
+#include <format>
 #endif
 
 namespace rust {
@@ -70,6 +75,9 @@ public:
 
   explicit operator std::string() const;
 
+  // Transcode to UTF-16, for example for interop with ICU.
+  std::u16string to_utf16() const;
+
   // Note: no null terminator.
   const char *data() const noexcept;
   std::size_t size() const noexcept;
@@ -132,6 +140,9 @@ public:
   explicit operator std::string_view() const;
 #endif
 
+  // Transcode to UTF-16, for example for interop with ICU.
+  std::u16string to_utf16() const;
+
   // Note: no null terminator.
   const char *data() const noexcept;
   std::size_t size() const noexcept;
@@ -179,6 +190,10 @@ struct copy_assignable_if<false> {
   copy_assignable_if &operator=(const copy_assignable_if &) & noexcept = delete;
   copy_assignable_if &operator=(copy_assignable_if &&) & noexcept = default;
 };
+
+template <typename T>
+struct is_char16
+    : std::is_same<typename std::remove_const<T>::type, char16_t> {};
 } // namespace detail
 
 // https://cxx.rs/binding/slice.html
@@ -194,6 +209,22 @@ public:
   template <typename C>
   explicit Slice(C &c) : Slice(c.data(), c.size()) {}
 
+#if __cplusplus >= 201703L
+  // Zero-copy views of UTF-16 text, for slices of char16_t. A slice of
+  // std::uint16_t, which is what &[u16] maps to, has to be copied instead.
+  template <typename U = T,
+            typename = typename std::enable_if<
+                std::is_const<U>::value && detail::is_char16<U>::value>::type>
+  explicit Slice(std::u16string_view s) noexcept
+      : Slice(s.data(), s.size()) {}
+
+  template <typename U = T, typename = typename std::enable_if<
+                                detail::is_char16<U>::value>::type>
+  explicit operator std::u16string_view() const noexcept {
+    return std::u16string_view(this->data(), this->size());
+  }
+#endif
+
   Slice &operator=(const Slice<T> &) & noexcept = default;
   Slice &operator=(Slice<T> &&) & noexcept = default;
 
@@ -334,6 +365,46 @@ private:
 };
 #endif // CXXBRIDGE1_RUST_BOX
 
+#ifndef CXXBRIDGE1_RUST_ARC
+// https://cxx.rs/binding/arc.html
+template <typename T>
+class Arc final {
+public:
+  using element_type = T;
+  using const_pointer =
+      typename std::add_pointer<typename std::add_const<T>::type>::type;
+
+  Arc() = delete;
+  Arc(const Arc &) noexcept;
+  Arc(Arc &&) noexcept;
+  ~Arc() noexcept;
+
+  Arc &operator=(const Arc &) & noexcept;
+  Arc &operator=(Arc &&) & noexcept;
+
+  const T *operator->() const noexcept;
+  const T &operator*() const noexcept;
+
+  void swap(Arc &) noexcept;
+
+  // Important: requires that `raw` came from an into_raw call. Do not pass a
+  // pointer from `new` or any other source.
+  static Arc from_raw(const T *) noexcept;
+
+  const T *into_raw() noexcept;
+
+private:
+  class uninit;
+  Arc(uninit) noexcept;
+  void increment() noexcept;
+  void drop() noexcept;
+
+  friend void swap(Arc &lhs, Arc &rhs) noexcept { lhs.swap(rhs); }
+
+  const T *ptr;
+};
+#endif // CXXBRIDGE1_RUST_ARC
+
 #ifndef CXXBRIDGE1_RUST_VEC
 // https://cxx.rs/binding/vec.html
 template <typename T>
@@ -418,8 +489,109 @@ private:
 };
 #endif // CXXBRIDGE1_RUST_FN
 
+#ifndef CXXBRIDGE1_RUST_ITER
+#define CXXBRIDGE1_RUST_ITER
+// https://cxx.rs/extern-rust.html#iterators
+template <typename T>
+class Iter final {
+public:
+  using iterator_category = std::input_iterator_tag;
+  using value_type = T;
+  using difference_type = std::ptrdiff_t;
+  using pointer = T *;
+  using reference = T &;
+
+  Iter() noexcept : state(nullptr) {}
+  Iter(void *self, bool (*next)(void *, T *)) noexcept
+      : state(new State(self, next)) {
+    this->state->advance();
+  }
+  Iter(const Iter &other) noexcept : state(other.state) {
+    if (this->state != nullptr) {
+      this->state->refs++;
+    }
+  }
+  Iter(Iter &&other) noexcept : state(other.state) { other.state = nullptr; }
+  ~Iter() noexcept {
+    if (this->state != nullptr && --this->state->refs == 0) {
+      delete this->state;
+    }
+  }
+
+  Iter &operator=(const Iter &other) & noexcept {
+    if (this != &other) {
+      this->~Iter();
+      ::new (this) Iter(other);
+    }
+    return *this;
+  }
+  Iter &operator=(Iter &&other) & noexcept {
+    if (this != &other) {
+      this->~Iter();
+      ::new (this) Iter(std::move(other));
+    }
+    return *this;
+  }
+
+  T &operator*() const noexcept { return this->state->value; }
+  T *operator->() const noexcept { return &this->state->value; }
+
+  Iter &operator++() noexcept {
+    this->state->advance();
+    return *this;
+  }
+  void operator++(int) noexcept { ++*this; }
+
+  // Only equality with an exhausted iterator, such as the one returned by
+  // end(), is meaningful. All copies of an iterator share one position.
+  bool operator==(const Iter &other) const noexcept {
+    return this->done() == other.done() &&
+           (this->done() || this->state == other.state);
+  }
+  bool operator!=(const Iter &other) const noexcept {
+    return !(*this == other);
+  }
+
+private:
+  struct State final {
+    State(void *self, bool (*next)(void *, T *)) noexcept
+        : self(self), next(next), refs(1), valid(false) {}
+    ~State() noexcept {
+      if (this->valid) {
+        this->value.~T();
+      }
+    }
+
+    void advance() noexcept {
+      if (this->valid) {
+        this->value.~T();
+      }
+      this->valid = this->next(this->self, &this->value);
+    }
+
+    void *self;
+    bool (*next)(void *, T *);
+    std::size_t refs;
+    bool valid;
+    union {
+      T value;
+    };
+  };
+
+  bool done() const noexcept {
+    return this->state == nullptr || !this->state->valid;
+  }
+
+  State *state;
+};
+#endif // CXXBRIDGE1_RUST_ITER
+
 #ifndef CXXBRIDGE1_RUST_ERROR
 #define CXXBRIDGE1_RUST_ERROR
+namespace detail {
+struct ErrorDetails;
+} // namespace detail
+
 // https://cxx.rs/binding/result.html
 class Error final : public std::exception {
 public:
@@ -432,14 +604,41 @@ public:
 
   const char *what() const noexcept override;
 
+  // Populated only for errors returned by #[error_details] functions;
+  // otherwise empty.
+  const char *debug() const noexcept;
+  const std::vector<std::string> &chain() const noexcept;
+
 private:
   Error() noexcept = default;
   friend impl<Error>;
   const char *msg;
   std::size_t len;
+  detail::ErrorDetails *details;
 };
 #endif // CXXBRIDGE1_RUST_ERROR
 
+#ifndef CXXBRIDGE1_RUST_PANIC
+#define CXXBRIDGE1_RUST_PANIC
+class Panic final : public std::exception {
+public:
+  Panic(const Panic &);
+  Panic(Panic &&) noexcept;
+  ~Panic() noexcept override;
+
+  Panic &operator=(const Panic &) &;
+  Panic &operator=(Panic &&) & noexcept;
+
+  const char *what() const noexcept override;
+
+private:
+  Panic() noexcept = default;
+  friend impl<Panic>;
+  const char *msg;
+  std::size_t len;
+};
+#endif // CXXBRIDGE1_RUST_PANIC
+
 #ifndef CXXBRIDGE1_RUST_ISIZE
 #define CXXBRIDGE1_RUST_ISIZE
 #if defined(_WIN32)
@@ -509,11 +708,15 @@ using slice = Slice<T>;
 template <typename T>
 using box = Box<T>;
 template <typename T>
+using arc = Arc<T>;
+template <typename T>
 using vec = Vec<T>;
 using error = Error;
 template <typename Signature>
 using fn = Fn<Signature>;
 template <typename T>
+using iter = Iter<T>;
+template <typename T>
 using is_relocatable = IsRelocatable<T>;
 
 
@@ -863,6 +1066,83 @@ template <typename T>
 Box<T>::Box(uninit) noexcept {}
 #endif // CXXBRIDGE1_RUST_BOX
 
+#ifndef CXXBRIDGE1_RUST_ARC
+#define CXXBRIDGE1_RUST_ARC
+template <typename T>
+class Arc<T>::uninit {};
+
+template <typename T>
+Arc<T>::Arc(const Arc &other) noexcept : ptr(other.ptr) {
+  if (this->ptr) {
+    this->increment();
+  }
+}
+
+template <typename T>
+Arc<T>::Arc(Arc &&other) noexcept : ptr(other.ptr) {
+  other.ptr = nullptr;
+}
+
+template <typename T>
+Arc<T>::~Arc() noexcept {
+  if (this->ptr) {
+    this->drop();
+  }
+}
+
+template <typename T>
+Arc<T> &Arc<T>::operator=(const Arc &other) & noexcept {
+  if (this != &other) {
+    Arc copy(other);
+    this->swap(copy);
+  }
+  return *this;
+}
+
+template <typename T>
+Arc<T> &Arc<T>::operator=(Arc &&other) & noexcept {
+  if (this->ptr) {
+    this->drop();
+  }
+  this->ptr = other.ptr;
+  other.ptr = nullptr;
+  return *this;
+}
+
+template <typename T>
+const T *Arc<T>::operator->() const noexcept {
+  return this->ptr;
+}
+
+template <typename T>
+const T &Arc<T>::operator*() const noexcept {
+  return *this->ptr;
+}
+
+template <typename T>
+void Arc<T>::swap(Arc &rhs) noexcept {
+  using std::swap;
+  swap(this->ptr, rhs.ptr);
+}
+
+template <typename T>
+Arc<T> Arc<T>::from_raw(const T *raw) noexcept {
+  Arc arc = uninit{};
+  arc.ptr = raw;
+  return arc;
+}
+
+template <typename T>
+const T *Arc<T>::into_raw() noexcept {
+  const T *raw = this->ptr;
+  this->ptr = nullptr;
+  return raw;
+}
+
+template <typename T>
+Arc<T>::Arc(uninit) noexcept {}
+#endif // CXXBRIDGE1_RUST_ARC
+
 #ifndef CXXBRIDGE1_RUST_VEC
 #define CXXBRIDGE1_RUST_VEC
 template <typename T>
@@ -1147,3 +1427,94 @@ struct IsRelocatable
 
 } // namespace cxxbridge1
 } // namespace rust
+
+#ifdef __cpp_lib_format
+namespace rust {
+inline namespace cxxbridge1 {
+namespace detail {
+// Formats a rust::Slice or rust::Vec like Rust's Debug for slices, applying
+// the format spec to each element.
+template <typename T>
+class range_formatter {
+public:
+  constexpr auto parse(std::format_parse_context &ctx) {
+    return this->element.parse(ctx);
+  }
+
+  template <typename Range, typename FormatContext>
+  auto format(const Range &range, FormatContext &ctx) const
+      -> decltype(ctx.out()) {
+    auto out = ctx.out();
+    *out++ = '[';
+    bool first = true;
+    for (const auto &element : range) {
+      if (!first) {
+        *out++ = ',';
+        *out++ = ' ';
+      }
+      first = false;
+      ctx.advance_to(out);
+      out = this->element.format(element, ctx);
+    }
+    *out++ = ']';
+    return out;
+  }
+
+private:
+  std::formatter<typename std::remove_const<T>::type, char> element;
+};
+} // namespace detail
+} // namespace cxxbridge1
+} // namespace rust
+
+namespace std {
+template <>
+struct formatter<::rust::String, char> : formatter<string_view, char> {
+  template <typename FormatContext>
+  auto format(const ::rust::String &s, FormatContext &ctx) const
+      -> decltype(ctx.out()) {
+    return formatter<string_view, char>::format(string_view(s.data(), s.size()),
+                                                ctx);
+  }
+};
+
+template <>
+struct formatter<::rust::Str, char> : formatter<string_view, char> {
+  template <typename FormatContext>
+  auto format(::rust::Str s, FormatContext &ctx) const -> decltype(ctx.out()) {
+    return formatter<string_view, char>::format(string_view(s.data(), s.size()),
+                                                ctx);
+  }
+};
+
+template <typename T>
+struct formatter<::rust::Slice<T>, char>
+    : ::rust::detail::range_formatter<T> {};
+
+template <typename T>
+struct formatter<::rust::Vec<T>, char> : ::rust::detail::range_formatter<T> {};
+} // namespace std
+#endif // __cpp_lib_format
+
+// Formatting support for the {fmt} library. Its headers must be included
+// before rust/cxx.h. Slices and Vecs are formatted by fmt/ranges.h.
+#ifdef FMT_VERSION
+namespace fmt {
+template <>
+struct formatter<::rust::String> : formatter<string_view> {
+  template <typename FormatContext>
+  auto format(const ::rust::String &s, FormatContext &ctx) const
+      -> decltype(ctx.out()) {
+    return formatter<string_view>::format(string_view(s.data(), s.size()), ctx);
+  }
+};
+
+template <>
+struct formatter<::rust::Str> : formatter<string_view> {
+  template <typename FormatContext>
+  auto format(::rust::Str s, FormatContext &ctx) const -> decltype(ctx.out()) {
+    return formatter<string_view>::format(string_view(s.data(), s.size()), ctx);
+  }
+};
+} // namespace fmt
+#endif // FMT_VERSION
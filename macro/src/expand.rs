@@ -14,8 +14,8 @@ use crate::syntax::trivial::TrivialReason;
 use crate::syntax::types::ConditionalImpl;
 use crate::syntax::unpin::UnpinReason;
 use crate::syntax::{
-    self, Api, Doc, Enum, ExternFn, ExternType, FnKind, Lang, Pair, Signature, Struct, Trait, Type,
-    TypeAlias, Types, check, mangle,
+    self, Api, Const, Doc, Enum, ExternFn, ExternStatic, ExternType, FnInstance, FnKind, IterImpl,
+    Lang, Lifetimes, Pair, Signature, Struct, Trait, Type, TypeAlias, Types, check, mangle,
 };
 use crate::type_id::Crate;
 use crate::{derive, generics};
@@ -76,7 +76,10 @@ fn expand(ffi: Module, doc: Doc, attrs: OtherAttrs, apis: &[Api], types: &Types)
                 hidden.extend(expand_struct_operators(strct));
                 forbid.extend(expand_struct_forbid_drop(strct));
             }
-            Api::Enum(enm) => expanded.extend(expand_enum(enm)),
+            Api::Enum(enm) => {
+                expanded.extend(expand_enum(enm));
+                hidden.extend(expand_enum_operators(enm));
+            }
             Api::CxxType(ety) => {
                 let ident = &ety.name.rust;
                 if types.structs.contains_key(ident) {
@@ -84,6 +87,7 @@ fn expand(ffi: Module, doc: Doc, attrs: OtherAttrs, apis: &[Api], types: &Types)
                 } else if !types.enums.contains_key(ident) {
                     expanded.extend(expand_cxx_type(ety));
                     expanded.extend(expand_associated_functions(&ety.name.rust, types));
+                    expanded.extend(expand_shared_from_this(ety));
                     hidden.extend(expand_cxx_type_assert_pinned(ety, types));
                 }
             }
@@ -103,6 +107,14 @@ fn expand(ffi: Module, doc: Doc, attrs: OtherAttrs, apis: &[Api], types: &Types)
                 expanded.extend(expand_associated_functions(&alias.name.rust, types));
                 hidden.extend(expand_type_alias_verify(alias, types));
             }
+            Api::Const(konst) => expanded.extend(expand_const(konst, types)),
+            Api::CxxStatic(stc) => {
+                expanded.extend(expand_cxx_static(stc));
+                hidden.extend(expand_cxx_static_assert_sync(stc));
+            }
+            Api::RustStatic(stc) => hidden.extend(expand_rust_static(stc)),
+            Api::CxxIterator(iter) => expanded.extend(expand_cxx_iterator(iter, types)),
+            Api::RustIterator(iter) => hidden.extend(expand_rust_iterator(iter, types)),
         }
     }
 
@@ -111,6 +123,9 @@ fn expand(ffi: Module, doc: Doc, attrs: OtherAttrs, apis: &[Api], types: &Types)
             ImplKey::RustBox(ident) => {
                 hidden.extend(expand_rust_box(ident, types, conditional_impl));
             }
+            ImplKey::RustArc(ident) => {
+                hidden.extend(expand_rust_arc(ident, types, conditional_impl));
+            }
             ImplKey::RustVec(ident) => {
                 hidden.extend(expand_rust_vec(ident, types, conditional_impl));
             }
@@ -126,6 +141,15 @@ fn expand(ffi: Module, doc: Doc, attrs: OtherAttrs, apis: &[Api], types: &Types)
             ImplKey::CxxVector(ident) => {
                 expanded.extend(expand_cxx_vector(ident, conditional_impl, types));
             }
+            ImplKey::CxxDeque(ident) => {
+                expanded.extend(expand_cxx_deque(ident, conditional_impl, types));
+            }
+            ImplKey::CxxList(ident) => {
+                expanded.extend(expand_cxx_list(ident, conditional_impl, types));
+            }
+            ImplKey::CxxSet(ident) => {
+                expanded.extend(expand_cxx_set(ident, conditional_impl, types));
+            }
         }
     }
 
@@ -338,6 +362,12 @@ fn expand_struct_operators(strct: &Struct) -> TokenStream {
                     });
                 }
             }
+            Trait::Debug => operators.extend(expand_debug_operator(
+                &strct.name,
+                generics,
+                &strct.attrs,
+                span,
+            )),
             Trait::Hash => {
                 let link_name = mangle::operator(&strct.name, "hash");
                 let local_name = format_ident!("__operator_hash_{}", strct.name.rust);
@@ -360,6 +390,47 @@ fn expand_struct_operators(strct: &Struct) -> TokenStream {
     operators
 }
 
+fn expand_enum_operators(enm: &Enum) -> TokenStream {
+    let mut operators = TokenStream::new();
+
+    for derive in &enm.derives {
+        if derive.what == Trait::Debug {
+            operators.extend(expand_debug_operator(
+                &enm.name,
+                &enm.generics,
+                &enm.attrs,
+                derive.span,
+            ));
+        }
+    }
+
+    operators
+}
+
+// Backs the std::formatter specialization emitted for C++ by writing the Rust
+// Debug representation into a std::string.
+fn expand_debug_operator(
+    name: &Pair,
+    generics: &Lifetimes,
+    attrs: &OtherAttrs,
+    span: Span,
+) -> TokenStream {
+    let ident = &name.rust;
+    let cfg_and_lint_attrs = attrs.cfg_and_lint();
+    let link_name = mangle::operator(name, "debug");
+    let local_name = format_ident!("__operator_debug_{}", name.rust);
+    let prevent_unwind_label = format!("::{} as Debug>::fmt", name.rust);
+    quote_spanned! {span=>
+        #cfg_and_lint_attrs
+        #[doc(hidden)]
+        #[unsafe(export_name = #link_name)]
+        extern "C" fn #local_name #generics(this: &#ident #generics, out: ::cxx::core::pin::Pin<&mut ::cxx::CxxString>) {
+            let __fn = ::cxx::core::concat!("<", ::cxx::core::module_path!(), #prevent_unwind_label);
+            ::cxx::private::prevent_unwind(__fn, || ::cxx::private::debug(this, out));
+        }
+    }
+}
+
 fn expand_struct_forbid_drop(strct: &Struct) -> TokenStream {
     let ident = &strct.name.rust;
     let generics = &strct.generics;
@@ -479,6 +550,62 @@ fn expand_cxx_type(ety: &ExternType) -> TokenStream {
     }
 }
 
+fn expand_shared_from_this(ety: &ExternType) -> TokenStream {
+    if ety.shared_from_this.is_none() {
+        return TokenStream::new();
+    }
+
+    let ident = &ety.name.rust;
+    let generics = &ety.generics;
+    let cfg_and_lint_attrs = ety.attrs.cfg_and_lint();
+    let visibility = &ety.visibility;
+    let link_shared_from_this = mangle::enable_shared_from_this(&ety.name, "shared").to_string();
+    let link_weak_from_this = mangle::enable_shared_from_this(&ety.name, "weak").to_string();
+
+    quote! {
+        #cfg_and_lint_attrs
+        #[automatically_derived]
+        impl #generics #ident #generics {
+            /// Obtains a SharedPtr sharing ownership of this object with the
+            /// std::shared\_ptr instances which already own it.
+            ///
+            /// Matches the behavior of C++17 `weak_from_this().lock()`: if the
+            /// object is not owned by any std::shared\_ptr, the result is
+            /// null. Prior to C++17 this requires C++ exceptions to be enabled,
+            /// otherwise the object must be owned by a std::shared\_ptr.
+            #visibility fn shared_from_this(&self) -> ::cxx::SharedPtr<Self> {
+                unsafe extern "C" {
+                    #[link_name = #link_shared_from_this]
+                    fn __shared_from_this(this: *const ::cxx::core::ffi::c_void, new: *mut ::cxx::core::ffi::c_void);
+                }
+                let this = ::cxx::core::ptr::from_ref::<Self>(self).cast::<::cxx::core::ffi::c_void>();
+                let mut shared_ptr = ::cxx::core::mem::MaybeUninit::<::cxx::SharedPtr<Self>>::uninit();
+                let new = shared_ptr.as_mut_ptr().cast();
+                unsafe {
+                    __shared_from_this(this, new);
+                    shared_ptr.assume_init()
+                }
+            }
+
+            /// Obtains a WeakPtr referring to this object, which is expired
+            /// if the object is not owned by any std::shared\_ptr.
+            #visibility fn weak_from_this(&self) -> ::cxx::WeakPtr<Self> {
+                unsafe extern "C" {
+                    #[link_name = #link_weak_from_this]
+                    fn __weak_from_this(this: *const ::cxx::core::ffi::c_void, new: *mut ::cxx::core::ffi::c_void);
+                }
+                let this = ::cxx::core::ptr::from_ref::<Self>(self).cast::<::cxx::core::ffi::c_void>();
+                let mut weak_ptr = ::cxx::core::mem::MaybeUninit::<::cxx::WeakPtr<Self>>::uninit();
+                let new = weak_ptr.as_mut_ptr().cast();
+                unsafe {
+                    __weak_from_this(this, new);
+                    weak_ptr.assume_init()
+                }
+            }
+        }
+    }
+}
+
 fn expand_cxx_type_assert_pinned(ety: &ExternType, types: &Types) -> TokenStream {
     let ident = &ety.name.rust;
     let cfg_and_lint_attrs = ety.attrs.cfg_and_lint();
@@ -782,6 +909,13 @@ fn expand_cxx_function_shim(efn: &ExternFn, types: &Types) -> TokenStream {
                     quote_spanned!(span=> ::cxx::alloc::boxed::Box::into_raw(#var))
                 }
             }
+            Type::RustArc(ty) => {
+                if types.is_considered_improper_ctype(&ty.inner) {
+                    quote_spanned!(span=> ::cxx::alloc::sync::Arc::into_raw(#var).cast())
+                } else {
+                    quote_spanned!(span=> ::cxx::alloc::sync::Arc::into_raw(#var))
+                }
+            }
             Type::UniquePtr(ty) => {
                 if types.is_considered_improper_ctype(&ty.inner) {
                     quote_spanned!(span=> ::cxx::UniquePtr::into_raw(#var).cast())
@@ -895,6 +1029,13 @@ fn expand_cxx_function_shim(efn: &ExternFn, types: &Types) -> TokenStream {
                     quote_spanned!(span=> ::cxx::alloc::boxed::Box::from_raw(#call))
                 }
             }
+            Type::RustArc(ty) => {
+                if types.is_considered_improper_ctype(&ty.inner) {
+                    quote_spanned!(span=> ::cxx::alloc::sync::Arc::from_raw(#call.cast()))
+                } else {
+                    quote_spanned!(span=> ::cxx::alloc::sync::Arc::from_raw(#call))
+                }
+            }
             Type::RustVec(_) => {
                 quote_spanned!(span=> #call.into_vec())
             }
@@ -1008,6 +1149,7 @@ fn expand_function_pointer_trampoline(
         local_name,
         prevent_unwind_label,
         None,
+        None,
         Some(&efn.generics),
         &efn.attrs,
         body_span,
@@ -1153,13 +1295,15 @@ fn expand_forbid(impls: TokenStream) -> TokenStream {
 
 fn expand_rust_function_shim(efn: &ExternFn, types: &Types) -> TokenStream {
     let link_name = mangle::extern_fn(efn, types);
-    let local_name = match efn.self_type() {
-        None => format_ident!("__{}", efn.name.rust),
-        Some(self_type) => format_ident!("__{}__{}", self_type, efn.name.rust),
+    let local_name = match (efn.self_type(), &efn.instance) {
+        (None, None) => format_ident!("__{}", efn.name.rust),
+        (None, Some(instance)) => format_ident!("__{}__{}", efn.name.rust, instance.arg.rust),
+        (Some(self_type), _) => format_ident!("__{}__{}", self_type, efn.name.rust),
     };
-    let prevent_unwind_label = match efn.self_type() {
-        None => format!("::{}", efn.name.rust),
-        Some(self_type) => format!("::{}::{}", self_type, efn.name.rust),
+    let prevent_unwind_label = match (efn.self_type(), &efn.instance) {
+        (None, None) => format!("::{}", efn.name.rust),
+        (None, Some(instance)) => format!("::{}::<{}>", efn.name.rust, instance.arg.rust),
+        (Some(self_type), _) => format!("::{}::{}", self_type, efn.name.rust),
     };
     let invoke = Some(&efn.name.rust);
     let body_span = efn.semi_token.span;
@@ -1170,6 +1314,7 @@ fn expand_rust_function_shim(efn: &ExternFn, types: &Types) -> TokenStream {
         local_name,
         prevent_unwind_label,
         invoke,
+        efn.instance.as_ref(),
         None,
         &efn.attrs,
         body_span,
@@ -1183,6 +1328,7 @@ fn expand_rust_function_shim_impl(
     local_name: Ident,
     prevent_unwind_label: String,
     invoke: Option<&Ident>,
+    instance: Option<&FnInstance>,
     outer_generics: Option<&Generics>,
     attrs: &OtherAttrs,
     body_span: Span,
@@ -1222,6 +1368,10 @@ fn expand_rust_function_shim_impl(
                 requires_unsafe = true;
                 quote_spanned!(span=> ::cxx::alloc::boxed::Box::from_raw(#var))
             }
+            Type::RustArc(_) => {
+                requires_unsafe = true;
+                quote_spanned!(span=> ::cxx::alloc::sync::Arc::from_raw(#var))
+            }
             Type::RustVec(_) => {
                 requires_unsafe = true;
                 quote_spanned!(span=> ::cxx::core::mem::take((*#var).as_mut_vec()))
@@ -1281,35 +1431,14 @@ fn expand_rust_function_shim_impl(
         // If the wrapper function is being passed directly to prevent_unwind,
         // it must implement `FnOnce() -> R` and cannot be an unsafe fn.
         let unsafety = sig.unsafety.filter(|_| requires_closure);
-        expand_rust_function_shim_super(sig, &local_name, invoke, unsafety)
+        expand_rust_function_shim_super(sig, &local_name, invoke, instance, unsafety)
     });
 
     let span = body_span;
-    let conversion = sig.ret.as_ref().and_then(|ret| match ret {
-        Type::Ident(ident) if ident.rust == RustString => {
-            Some(quote_spanned!(span=> ::cxx::private::RustString::from))
-        }
-        Type::RustBox(_) => Some(quote_spanned!(span=> ::cxx::alloc::boxed::Box::into_raw)),
-        Type::RustVec(_) => Some(quote_spanned!(span=> ::cxx::private::RustVec::from)),
-        Type::UniquePtr(_) => Some(quote_spanned!(span=> ::cxx::UniquePtr::into_raw)),
-        Type::Ref(ty) => match &ty.inner {
-            Type::Ident(ident) if ident.rust == RustString => match ty.mutable {
-                false => Some(quote_spanned!(span=> ::cxx::private::RustString::from_ref)),
-                true => Some(quote_spanned!(span=> ::cxx::private::RustString::from_mut)),
-            },
-            Type::RustVec(_) => match ty.mutable {
-                false => Some(quote_spanned!(span=> ::cxx::private::RustVec::from_ref)),
-                true => Some(quote_spanned!(span=> ::cxx::private::RustVec::from_mut)),
-            },
-            _ => None,
-        },
-        Type::Str(_) => Some(quote_spanned!(span=> ::cxx::private::RustStr::from)),
-        Type::SliceRef(ty) => match ty.mutable {
-            false => Some(quote_spanned!(span=> ::cxx::private::RustSlice::from_ref)),
-            true => Some(quote_spanned!(span=> ::cxx::private::RustSlice::from_mut)),
-        },
-        _ => None,
-    });
+    let conversion = sig
+        .ret
+        .as_ref()
+        .and_then(|ret| expand_rust_return_conversion(ret, span));
 
     let mut expr = match conversion {
         None => call,
@@ -1329,21 +1458,31 @@ fn expand_rust_function_shim_impl(
         let ret = expand_extern_type(sig.ret.as_ref().unwrap(), types, false);
         outparam = Some(quote_spanned!(span=> __return: *mut #ret,));
     }
-    if sig.throws {
+    if sig.fallible() {
         let out = match sig.ret {
             Some(_) => quote_spanned!(span=> __return),
             None => quote_spanned!(span=> &mut ()),
         };
+        if !sig.throws {
+            expr = quote_spanned!(span=> ::cxx::core::result::Result::<_, ::cxx::core::convert::Infallible>::Ok(#expr));
+        }
         requires_closure = true;
         requires_unsafe = true;
-        expr = quote_spanned!(span=> ::cxx::private::r#try(#out, #expr));
+        let r#try = if sig.error_details {
+            quote_spanned!(span=> try_with_details)
+        } else {
+            quote_spanned!(span=> r#try)
+        };
+        expr = quote_spanned!(span=> ::cxx::private::#r#try(#out, #expr));
     } else if indirect_return {
         requires_closure = true;
         requires_unsafe = true;
         expr = quote_spanned!(span=> ::cxx::core::ptr::write(__return, #expr));
     }
 
-    if requires_unsafe {
+    // The call to catch_unwind is itself wrapped in unsafe below, which
+    // covers the closure body as well.
+    if requires_unsafe && !sig.catch_unwind {
         expr = quote_spanned!(span=> unsafe { #expr });
     }
 
@@ -1353,9 +1492,16 @@ fn expand_rust_function_shim_impl(
         quote!(#local_name)
     };
 
-    expr = quote_spanned!(span=> ::cxx::private::prevent_unwind(__fn, #closure));
+    let mut panicparam = None;
+    if sig.catch_unwind {
+        panicparam = Some(quote_spanned!(span=> __panic: *mut bool,));
+        expr =
+            quote_spanned!(span=> unsafe { ::cxx::private::catch_unwind(__fn, __panic, #closure) });
+    } else {
+        expr = quote_spanned!(span=> ::cxx::private::prevent_unwind(__fn, #closure));
+    }
 
-    let ret = if sig.throws {
+    let ret = if sig.fallible() {
         quote!(-> ::cxx::private::Result)
     } else {
         expand_extern_return_type(sig, types, false, Lang::Rust)
@@ -1370,7 +1516,7 @@ fn expand_rust_function_shim_impl(
         #all_attrs
         #[doc(hidden)]
         #[unsafe(export_name = #link_name)]
-        unsafe extern "C" fn #local_name #generics(#(#all_args,)* #outparam #pointer) #ret {
+        unsafe extern "C" fn #local_name #generics(#(#all_args,)* #outparam #panicparam #pointer) #ret {
             let __fn = ::cxx::core::concat!(::cxx::core::module_path!(), #prevent_unwind_label);
             #wrap_super
             #expr
@@ -1378,12 +1524,42 @@ fn expand_rust_function_shim_impl(
     }
 }
 
+fn expand_rust_return_conversion(ret: &Type, span: Span) -> Option<TokenStream> {
+    match ret {
+        Type::Ident(ident) if ident.rust == RustString => {
+            Some(quote_spanned!(span=> ::cxx::private::RustString::from))
+        }
+        Type::RustBox(_) => Some(quote_spanned!(span=> ::cxx::alloc::boxed::Box::into_raw)),
+        Type::RustArc(_) => Some(quote_spanned!(span=> ::cxx::alloc::sync::Arc::into_raw)),
+        Type::RustVec(_) => Some(quote_spanned!(span=> ::cxx::private::RustVec::from)),
+        Type::UniquePtr(_) => Some(quote_spanned!(span=> ::cxx::UniquePtr::into_raw)),
+        Type::Ref(ty) => match &ty.inner {
+            Type::Ident(ident) if ident.rust == RustString => match ty.mutable {
+                false => Some(quote_spanned!(span=> ::cxx::private::RustString::from_ref)),
+                true => Some(quote_spanned!(span=> ::cxx::private::RustString::from_mut)),
+            },
+            Type::RustVec(_) => match ty.mutable {
+                false => Some(quote_spanned!(span=> ::cxx::private::RustVec::from_ref)),
+                true => Some(quote_spanned!(span=> ::cxx::private::RustVec::from_mut)),
+            },
+            _ => None,
+        },
+        Type::Str(_) => Some(quote_spanned!(span=> ::cxx::private::RustStr::from)),
+        Type::SliceRef(ty) => match ty.mutable {
+            false => Some(quote_spanned!(span=> ::cxx::private::RustSlice::from_ref)),
+            true => Some(quote_spanned!(span=> ::cxx::private::RustSlice::from_mut)),
+        },
+        _ => None,
+    }
+}
+
 // A wrapper like `fn f(x: Arg) { super::f(x) }` just to ensure we have the
 // accurate unsafety declaration and no problematic elided lifetimes.
 fn expand_rust_function_shim_super(
     sig: &Signature,
     local_name: &Ident,
     invoke: &Ident,
+    instance: Option<&FnInstance>,
     unsafety: Option<Token![unsafe]>,
 ) -> TokenStream {
     let generics = &sig.generics;
@@ -1406,7 +1582,16 @@ fn expand_rust_function_shim_super(
         // Set spans that result in the `Result<...>` written by the user being
         // highlighted as the cause if their error type has no Display impl.
         let result_begin = quote_spanned!(result.span=> ::cxx::core::result::Result<#ok, impl);
-        let result_end = quote_spanned!(rangle.span=> ::cxx::core::fmt::Display + use<>>);
+        let result_end = if sig.error_details {
+            quote_spanned! {rangle.span=>
+                ::cxx::core::fmt::Display
+                    + ::cxx::core::fmt::Debug
+                    + ::cxx::core::convert::Into<::cxx::alloc::boxed::Box<dyn ::cxx::core::error::Error>>
+                    + use<>>
+            }
+        } else {
+            quote_spanned!(rangle.span=> ::cxx::core::fmt::Display + use<>>)
+        };
         quote!(-> #result_begin #result_end)
     } else {
         expand_return_type(&sig.ret)
@@ -1417,7 +1602,13 @@ fn expand_rust_function_shim_super(
 
     let span = invoke.span();
     let call = match sig.self_type() {
-        None => quote_spanned!(span=> super::#invoke),
+        None => match instance {
+            Some(instance) => {
+                let arg = &instance.arg.rust;
+                quote_spanned!(span=> super::#invoke::<#arg>)
+            }
+            None => quote_spanned!(span=> super::#invoke),
+        },
         Some(self_type) => quote_spanned!(span=> #self_type::#invoke),
     };
 
@@ -1436,6 +1627,173 @@ fn expand_rust_function_shim_super(
     }
 }
 
+fn expand_const(konst: &Const, types: &Types) -> TokenStream {
+    let doc = &konst.doc;
+    let all_attrs = konst.attrs.all();
+    let visibility = konst.visibility;
+    let const_token = konst.const_token;
+    let ident = &konst.name.rust;
+    let colon_token = konst.colon_token;
+    let ty = &konst.ty;
+    let eq_token = konst.eq_token;
+    let expr = &konst.expr;
+    let semi_token = konst.semi_token;
+    let item = quote! {
+        #doc
+        #all_attrs
+        #visibility #const_token #ident #colon_token #ty #eq_token #expr #semi_token
+    };
+
+    let Some(self_type) = &konst.self_type else {
+        return item;
+    };
+
+    let cfg_and_lint_attrs = konst.attrs.cfg_and_lint();
+    let resolve = types.resolve(self_type);
+    let self_type_cfg_attrs = resolve.attrs.cfg();
+    let lifetimes = resolve.generics.to_underscore_lifetimes();
+    quote! {
+        #self_type_cfg_attrs
+        #cfg_and_lint_attrs
+        impl #self_type #lifetimes {
+            #item
+        }
+    }
+}
+
+fn expand_cxx_static(stc: &ExternStatic) -> TokenStream {
+    let doc = &stc.doc;
+    let all_attrs = stc.attrs.all();
+    let cfg_and_lint_attrs = stc.attrs.cfg_and_lint();
+    let visibility = stc.visibility;
+    let static_token = stc.static_token;
+    let ident = &stc.name.rust;
+    let colon_token = stc.colon_token;
+    let ty = &stc.ty;
+    let semi_token = stc.semi_token;
+    let link_name = mangle::extern_static(stc);
+
+    quote! {
+        #doc
+        #all_attrs
+        #visibility #static_token #ident #colon_token #ident = #ident { __private: () } #semi_token
+
+        #[doc(hidden)]
+        #cfg_and_lint_attrs
+        #visibility struct #ident {
+            __private: (),
+        }
+
+        #cfg_and_lint_attrs
+        #[automatically_derived]
+        impl ::cxx::core::ops::Deref for #ident {
+            type Target = #ty;
+
+            fn deref(&self) -> &Self::Target {
+                unsafe extern "C" {
+                    #[link_name = #link_name]
+                    fn __get() -> *const #ty;
+                }
+                unsafe { &*__get() }
+            }
+        }
+    }
+}
+
+fn expand_cxx_static_assert_sync(stc: &ExternStatic) -> TokenStream {
+    let cfg_and_lint_attrs = stc.attrs.cfg_and_lint();
+    let ty = &stc.ty;
+    let span = stc.name.rust.span();
+
+    quote_spanned! {span=>
+        #cfg_and_lint_attrs
+        const _: fn() = ::cxx::private::require_sync::<#ty>;
+    }
+}
+
+fn expand_rust_static(stc: &ExternStatic) -> TokenStream {
+    let cfg_and_lint_attrs = stc.attrs.cfg_and_lint();
+    let ident = &stc.name.rust;
+    let local_name = format_ident!("__{}", ident);
+    let ty = &stc.ty;
+    let link_name = mangle::extern_static(stc);
+    let span = ident.span();
+
+    quote_spanned! {span=>
+        #cfg_and_lint_attrs
+        #[unsafe(export_name = #link_name)]
+        static #local_name: &#ty = &super::#ident;
+    }
+}
+
+fn expand_cxx_iterator(iter: &IterImpl, types: &Types) -> TokenStream {
+    let cfg_and_lint_attrs = iter.attrs.cfg_and_lint();
+    let resolve = types.resolve(&iter.self_ty);
+    let ident = &iter.self_ty.rust;
+    let Type::Ref(item) = &iter.item else {
+        unreachable!();
+    };
+    let inner = &item.inner;
+    let link_begin = mangle::iterator(resolve.name, "begin");
+    let link_next = mangle::iterator(resolve.name, "next");
+    let link_drop = mangle::iterator(resolve.name, "drop");
+    let span = iter.brace_token.span.join();
+
+    quote_spanned! {span=>
+        #cfg_and_lint_attrs
+        #[automatically_derived]
+        impl<'a> ::cxx::core::iter::IntoIterator for &'a #ident {
+            type Item = &'a #inner;
+            type IntoIter = ::cxx::CxxIter<'a, #inner>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                unsafe extern "C" {
+                    #[link_name = #link_begin]
+                    fn __iter(this: &#ident) -> *mut ::cxx::core::ffi::c_void;
+                    #[link_name = #link_next]
+                    fn __next(iter: *mut ::cxx::core::ffi::c_void) -> *const ::cxx::core::ffi::c_void;
+                    #[link_name = #link_drop]
+                    fn __drop(iter: *mut ::cxx::core::ffi::c_void);
+                }
+                unsafe { ::cxx::CxxIter::__new(__iter(self), __next, __drop) }
+            }
+        }
+    }
+}
+
+fn expand_rust_iterator(iter: &IterImpl, types: &Types) -> TokenStream {
+    let cfg_and_lint_attrs = iter.attrs.cfg_and_lint();
+    let resolve = types.resolve(&iter.self_ty);
+    let ident = &iter.self_ty.rust;
+    let local_name = format_ident!("__{}__next", ident);
+    let link_name = mangle::iterator(resolve.name, "next");
+    let item = expand_extern_type(&iter.item, types, false);
+    let span = iter.brace_token.span.join();
+    let next = match expand_rust_return_conversion(&iter.item, span) {
+        Some(conversion) => quote_spanned!(span=> #conversion(__next)),
+        None => quote_spanned!(span=> __next),
+    };
+    let prevent_unwind_label = format!("::{} as Iterator>::next", ident);
+
+    quote_spanned! {span=>
+        #cfg_and_lint_attrs
+        #[doc(hidden)]
+        #[unsafe(export_name = #link_name)]
+        unsafe extern "C" fn #local_name(__self: &mut #ident, __item: *mut #item) -> bool {
+            let __fn = ::cxx::core::concat!("<", ::cxx::core::module_path!(), #prevent_unwind_label);
+            ::cxx::private::prevent_unwind(__fn, move || {
+                match ::cxx::core::iter::Iterator::next(__self) {
+                    ::cxx::core::option::Option::Some(__next) => {
+                        unsafe { ::cxx::core::ptr::write(__item, #next) }
+                        true
+                    }
+                    ::cxx::core::option::Option::None => false,
+                }
+            })
+        }
+    }
+}
+
 fn expand_type_alias(alias: &TypeAlias) -> TokenStream {
     let doc = &alias.doc;
     let all_attrs = alias.attrs.all();
@@ -1637,6 +1995,25 @@ fn expand_type_alias_verify(alias: &TypeAlias, types: &Types) -> TokenStream {
         });
     }
 
+    let in_unique_ptr = types.all.keys().any(|ty| match ty {
+        Type::UniquePtr(ptr) => match &ptr.inner {
+            Type::Ident(inner) => inner.rust == *ident,
+            _ => false,
+        },
+        _ => false,
+    });
+    if in_unique_ptr {
+        let cxx_deleter = match &alias.cxx_deleter {
+            Some(cxx_deleter) => quote!(::cxx::core::option::Option::Some(#cxx_deleter)),
+            None => quote!(::cxx::core::option::Option::None),
+        };
+        let begin = quote_spanned!(begin_span=> ::cxx::private::verify_unique_ptr_deleter::<);
+        verify.extend(quote! {
+            #cfg_and_lint_attrs
+            const _: () = #begin #ident #lifetimes #end(#cxx_deleter);
+        });
+    }
+
     verify
 }
 
@@ -1704,6 +2081,52 @@ fn expand_rust_box(
     })
 }
 
+fn expand_rust_arc(
+    key: &NamedImplKey,
+    types: &Types,
+    conditional_impl: &ConditionalImpl,
+) -> TokenStream {
+    let link_prefix = format!("cxxbridge1$arc${}$", key.symbol);
+    let link_increment = format!("{}increment", link_prefix);
+    let link_drop = format!("{}drop", link_prefix);
+
+    let (impl_generics, inner_with_generics) =
+        generics::split_for_impl(key, conditional_impl, types);
+
+    let cfg = conditional_impl.cfg.into_attr();
+    let begin_span = conditional_impl
+        .explicit_impl
+        .map_or(key.begin_span, |explicit| explicit.impl_token.span);
+    let end_span = conditional_impl
+        .explicit_impl
+        .map_or(key.end_span, |explicit| explicit.brace_token.span.join());
+    let unsafe_token = format_ident!("unsafe", span = begin_span);
+    let prevent_unwind_type_label = generics::format_for_prevent_unwind_label(key.inner);
+
+    quote_spanned!(end_span=> {
+        #cfg
+        #[automatically_derived]
+        #[doc(hidden)]
+        #unsafe_token impl #impl_generics ::cxx::private::ImplArc for #inner_with_generics {}
+
+        #cfg
+        #[doc(hidden)]
+        #[unsafe(export_name = #link_increment)]
+        unsafe extern "C" fn __increment #impl_generics(ptr: *const #inner_with_generics) {
+            // No prevent_unwind: incrementing a reference count cannot panic.
+            unsafe { ::cxx::alloc::sync::Arc::increment_strong_count(ptr) }
+        }
+
+        #cfg
+        #[doc(hidden)]
+        #[unsafe(export_name = #link_drop)]
+        unsafe extern "C" fn __drop #impl_generics(ptr: *const #inner_with_generics) {
+            let __fn = ::cxx::core::concat!("<", #prevent_unwind_type_label, " as Drop>::drop");
+            ::cxx::private::prevent_unwind(__fn, || unsafe { ::cxx::alloc::sync::Arc::decrement_strong_count(ptr) });
+        }
+    })
+}
+
 fn expand_rust_vec(
     key: &NamedImplKey,
     types: &Types,
@@ -1732,11 +2155,20 @@ fn expand_rust_vec(
     let unsafe_token = format_ident!("unsafe", span = begin_span);
     let prevent_unwind_type_label = generics::format_for_prevent_unwind_label(key.inner);
 
+    // As for CxxVector, a Vec of Vecs or of smart pointers is marked on the
+    // type inside of the element to satisfy the orphan rule.
+    let impl_vec = match inner_with_generics.type_argument() {
+        Some(argument) => {
+            quote_spanned!(end_span=> ::cxx::private::ImplVecOf<#inner_with_generics> for #argument)
+        }
+        None => quote_spanned!(end_span=> ::cxx::private::ImplVec for #inner_with_generics),
+    };
+
     quote_spanned!(end_span=> {
         #cfg
         #[automatically_derived]
         #[doc(hidden)]
-        #unsafe_token impl #impl_generics ::cxx::private::ImplVec for #inner_with_generics {}
+        #unsafe_token impl #impl_generics #impl_vec {}
 
         #cfg
         #[doc(hidden)]
@@ -1833,7 +2265,8 @@ fn expand_unique_ptr(
     let (impl_generics, inner_with_generics) =
         generics::split_for_impl(key, conditional_impl, types);
 
-    let can_construct_from_value = types.is_maybe_trivial(key.inner);
+    let cxx_deleter = types.cxx_deleter(key.inner);
+    let can_construct_from_value = types.is_maybe_trivial(key.inner) && cxx_deleter.is_none();
     let new_method = if can_construct_from_value {
         Some(quote! {
             fn __new(value: Self) -> ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void> {
@@ -1852,6 +2285,12 @@ fn expand_unique_ptr(
         None
     };
 
+    let deleter_const = cxx_deleter.map(|cxx_deleter| {
+        quote! {
+            const __DELETER: ::cxx::core::option::Option<&'static str> = ::cxx::core::option::Option::Some(#cxx_deleter);
+        }
+    });
+
     let cfg = conditional_impl.cfg.into_attr();
     let begin_span = conditional_impl
         .explicit_impl
@@ -1914,6 +2353,7 @@ fn expand_unique_ptr(
                     __drop(&raw mut repr);
                 }
             }
+            #deleter_const
         }
     }
 }
@@ -1930,6 +2370,9 @@ fn expand_shared_ptr(
     let link_clone = format!("{}clone", prefix);
     let link_get = format!("{}get", prefix);
     let link_drop = format!("{}drop", prefix);
+    let link_use_count = format!("{}use_count", prefix);
+    let link_erase = format!("{}erase", prefix);
+    let link_alias = format!("{}alias", prefix);
 
     let name = generics::concise_rust_name(key.inner);
     let (impl_generics, inner_with_generics) =
@@ -2018,6 +2461,31 @@ fn expand_shared_ptr(
                     __drop(this);
                 }
             }
+            unsafe fn __use_count(this: *const ::cxx::core::ffi::c_void) -> ::cxx::core::primitive::usize {
+                unsafe extern "C" {
+                    #[link_name = #link_use_count]
+                    fn __use_count(this: *const ::cxx::core::ffi::c_void) -> ::cxx::core::primitive::usize;
+                }
+                unsafe { __use_count(this) }
+            }
+            unsafe fn __erase(this: *const ::cxx::core::ffi::c_void, erased: *mut ::cxx::core::ffi::c_void) {
+                unsafe extern "C" {
+                    #[link_name = #link_erase]
+                    fn __erase(this: *const ::cxx::core::ffi::c_void, erased: *mut ::cxx::core::ffi::c_void);
+                }
+                unsafe {
+                    __erase(this, erased);
+                }
+            }
+            unsafe fn __alias(erased: *mut ::cxx::core::ffi::c_void, ptr: *mut Self, new: *mut ::cxx::core::ffi::c_void) {
+                unsafe extern "C" {
+                    #[link_name = #link_alias]
+                    fn __alias(erased: *mut ::cxx::core::ffi::c_void, ptr: *mut ::cxx::core::ffi::c_void, new: *mut ::cxx::core::ffi::c_void);
+                }
+                unsafe {
+                    __alias(erased, ptr.cast::<::cxx::core::ffi::c_void>(), new);
+                }
+            }
         }
     }
 }
@@ -2033,6 +2501,7 @@ fn expand_weak_ptr(
     let link_downgrade = format!("{}downgrade", prefix);
     let link_upgrade = format!("{}upgrade", prefix);
     let link_drop = format!("{}drop", prefix);
+    let link_use_count = format!("{}use_count", prefix);
 
     let name = generics::concise_rust_name(key.inner);
     let (impl_generics, inner_with_generics) =
@@ -2099,6 +2568,13 @@ fn expand_weak_ptr(
                     __drop(this);
                 }
             }
+            unsafe fn __use_count(this: *const ::cxx::core::ffi::c_void) -> ::cxx::core::primitive::usize {
+                unsafe extern "C" {
+                    #[link_name = #link_use_count]
+                    fn __use_count(this: *const ::cxx::core::ffi::c_void) -> ::cxx::core::primitive::usize;
+                }
+                unsafe { __use_count(this) }
+            }
         }
     }
 }
@@ -2114,6 +2590,8 @@ fn expand_cxx_vector(
     let link_capacity = format!("{}capacity", prefix);
     let link_get_unchecked = format!("{}get_unchecked", prefix);
     let link_reserve = format!("{}reserve", prefix);
+    let link_truncate = format!("{}truncate", prefix);
+    let link_swap = format!("{}swap", prefix);
     let link_push_back = format!("{}push_back", prefix);
     let link_pop_back = format!("{}pop_back", prefix);
     let unique_ptr_prefix = format!("cxxbridge1$unique_ptr$std$vector${}$", key.symbol);
@@ -2122,6 +2600,9 @@ fn expand_cxx_vector(
     let link_unique_ptr_get = format!("{}get", unique_ptr_prefix);
     let link_unique_ptr_release = format!("{}release", unique_ptr_prefix);
     let link_unique_ptr_drop = format!("{}drop", unique_ptr_prefix);
+    let unique_ptr_array_prefix = format!("cxxbridge1$unique_ptr$array${}$", key.symbol);
+    let link_unique_ptr_array_new = format!("{}new", unique_ptr_array_prefix);
+    let link_unique_ptr_array_delete = format!("{}delete", unique_ptr_array_prefix);
 
     let name = generics::concise_rust_name(key.inner);
     let (impl_generics, inner_with_generics) =
@@ -2136,6 +2617,15 @@ fn expand_cxx_vector(
         .map_or(key.end_span, |explicit| explicit.brace_token.span.join());
     let unsafe_token = format_ident!("unsafe", span = begin_span);
 
+    let not_move_constructible_err = format!(
+        "{} is not move constructible",
+        generics::concise_cxx_name(key.inner, types),
+    );
+    let not_default_constructible_err = format!(
+        "{} is not default constructible",
+        generics::concise_cxx_name(key.inner, types),
+    );
+
     let can_pass_element_by_value = types.is_maybe_trivial(key.inner);
     let by_value_methods = if can_pass_element_by_value {
         Some(quote_spanned! {end_span=>
@@ -2175,45 +2665,72 @@ fn expand_cxx_vector(
                     );
                 }
             }
+            fn __unique_ptr_array_new(len: ::cxx::core::primitive::usize) -> *mut Self {
+                unsafe extern "C" {
+                    #[link_name = #link_unique_ptr_array_new]
+                    fn __unique_ptr_array_new(len: ::cxx::core::primitive::usize) -> *mut ::cxx::core::ffi::c_void;
+                }
+                let ptr = unsafe { __unique_ptr_array_new(len) };
+                if ptr.is_null() {
+                    ::cxx::core::panic!(#not_default_constructible_err);
+                }
+                ptr.cast::<Self>()
+            }
+            unsafe fn __unique_ptr_array_delete(ptr: *mut Self) {
+                unsafe extern "C" {
+                    #[link_name = #link_unique_ptr_array_delete]
+                    fn __unique_ptr_array_delete(ptr: *mut ::cxx::core::ffi::c_void);
+                }
+                unsafe { __unique_ptr_array_delete(ptr.cast::<::cxx::core::ffi::c_void>()) }
+            }
         })
     } else {
         None
     };
 
-    let not_move_constructible_err = format!(
-        "{} is not move constructible",
-        generics::concise_cxx_name(key.inner, types),
-    );
+    // A vector of smart pointers or of vectors cannot implement VectorElement
+    // for its element directly under the orphan rule. Instead the impl goes on
+    // the type inside the element, and cxx supplies the VectorElement impl.
+    let (impl_trait, element) = match inner_with_generics.type_argument() {
+        Some(argument) => (
+            quote_spanned!(end_span=> ::cxx::private::VectorElementOf<#inner_with_generics> for #argument),
+            inner_with_generics.to_token_stream(),
+        ),
+        None => (
+            quote_spanned!(end_span=> ::cxx::vector::VectorElement for #inner_with_generics),
+            quote_spanned!(end_span=> Self),
+        ),
+    };
 
     quote_spanned! {end_span=>
         #cfg
         #[automatically_derived]
-        #unsafe_token impl #impl_generics ::cxx::vector::VectorElement for #inner_with_generics {
+        #unsafe_token impl #impl_generics #impl_trait {
             fn __typename(f: &mut ::cxx::core::fmt::Formatter<'_>) -> ::cxx::core::fmt::Result {
                 f.write_str(#name)
             }
-            fn __vector_new() -> *mut ::cxx::CxxVector<Self> {
+            fn __vector_new() -> *mut ::cxx::CxxVector<#element> {
                 unsafe extern "C" {
                     #[link_name = #link_new]
                     fn __vector_new #impl_generics() -> *mut ::cxx::CxxVector<#inner_with_generics>;
                 }
                 unsafe { __vector_new() }
             }
-            fn __vector_size(v: &::cxx::CxxVector<Self>) -> ::cxx::core::primitive::usize {
+            fn __vector_size(v: &::cxx::CxxVector<#element>) -> ::cxx::core::primitive::usize {
                 unsafe extern "C" {
                     #[link_name = #link_size]
                     fn __vector_size #impl_generics(_: &::cxx::CxxVector<#inner_with_generics>) -> ::cxx::core::primitive::usize;
                 }
                 unsafe { __vector_size(v) }
             }
-            fn __vector_capacity(v: &::cxx::CxxVector<Self>) -> ::cxx::core::primitive::usize {
+            fn __vector_capacity(v: &::cxx::CxxVector<#element>) -> ::cxx::core::primitive::usize {
                 unsafe extern "C" {
                     #[link_name = #link_capacity]
                     fn __vector_capacity #impl_generics(_: &::cxx::CxxVector<#inner_with_generics>) -> ::cxx::core::primitive::usize;
                 }
                 unsafe { __vector_capacity(v) }
             }
-            unsafe fn __get_unchecked(v: *mut ::cxx::CxxVector<Self>, pos: ::cxx::core::primitive::usize) -> *mut Self {
+            unsafe fn __get_unchecked(v: *mut ::cxx::CxxVector<#element>, pos: ::cxx::core::primitive::usize) -> *mut #element {
                 unsafe extern "C" {
                     #[link_name = #link_get_unchecked]
                     fn __get_unchecked #impl_generics(
@@ -2221,9 +2738,9 @@ fn expand_cxx_vector(
                         pos: ::cxx::core::primitive::usize,
                     ) -> *mut ::cxx::core::ffi::c_void;
                 }
-                unsafe { __get_unchecked(v, pos).cast::<Self>() }
+                unsafe { __get_unchecked(v, pos).cast::<#element>() }
             }
-            unsafe fn __reserve(v: ::cxx::core::pin::Pin<&mut ::cxx::CxxVector<Self>>, new_cap: ::cxx::core::primitive::usize) {
+            unsafe fn __reserve(v: ::cxx::core::pin::Pin<&mut ::cxx::CxxVector<#element>>, new_cap: ::cxx::core::primitive::usize) {
                 unsafe extern "C" {
                     #[link_name = #link_reserve]
                     fn __reserve #impl_generics(
@@ -2235,6 +2752,26 @@ fn expand_cxx_vector(
                     ::cxx::core::panic!(#not_move_constructible_err);
                 }
             }
+            unsafe fn __truncate(v: ::cxx::core::pin::Pin<&mut ::cxx::CxxVector<#element>>, len: ::cxx::core::primitive::usize) {
+                unsafe extern "C" {
+                    #[link_name = #link_truncate]
+                    fn __truncate #impl_generics(
+                        v: ::cxx::core::pin::Pin<&mut ::cxx::CxxVector<#inner_with_generics>>,
+                        len: ::cxx::core::primitive::usize,
+                    );
+                }
+                unsafe { __truncate(v, len) }
+            }
+            unsafe fn __swap(v: ::cxx::core::pin::Pin<&mut ::cxx::CxxVector<#element>>, other: ::cxx::core::pin::Pin<&mut ::cxx::CxxVector<#element>>) {
+                unsafe extern "C" {
+                    #[link_name = #link_swap]
+                    fn __swap #impl_generics(
+                        v: ::cxx::core::pin::Pin<&mut ::cxx::CxxVector<#inner_with_generics>>,
+                        other: ::cxx::core::pin::Pin<&mut ::cxx::CxxVector<#inner_with_generics>>,
+                    );
+                }
+                unsafe { __swap(v, other) }
+            }
             #by_value_methods
             fn __unique_ptr_null() -> ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void> {
                 unsafe extern "C" {
@@ -2247,7 +2784,7 @@ fn expand_cxx_vector(
                 }
                 repr
             }
-            unsafe fn __unique_ptr_raw(raw: *mut ::cxx::CxxVector<Self>) -> ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void> {
+            unsafe fn __unique_ptr_raw(raw: *mut ::cxx::CxxVector<#element>) -> ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void> {
                 unsafe extern "C" {
                     #[link_name = #link_unique_ptr_raw]
                     fn __unique_ptr_raw #impl_generics(this: *mut ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>, raw: *mut ::cxx::CxxVector<#inner_with_generics>);
@@ -2258,14 +2795,14 @@ fn expand_cxx_vector(
                 }
                 repr
             }
-            unsafe fn __unique_ptr_get(repr: ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *const ::cxx::CxxVector<Self> {
+            unsafe fn __unique_ptr_get(repr: ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *const ::cxx::CxxVector<#element> {
                 unsafe extern "C" {
                     #[link_name = #link_unique_ptr_get]
                     fn __unique_ptr_get #impl_generics(this: *const ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *const ::cxx::CxxVector<#inner_with_generics>;
                 }
                 unsafe { __unique_ptr_get(&raw const repr) }
             }
-            unsafe fn __unique_ptr_release(mut repr: ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *mut ::cxx::CxxVector<Self> {
+            unsafe fn __unique_ptr_release(mut repr: ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *mut ::cxx::CxxVector<#element> {
                 unsafe extern "C" {
                     #[link_name = #link_unique_ptr_release]
                     fn __unique_ptr_release #impl_generics(this: *mut ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *mut ::cxx::CxxVector<#inner_with_generics>;
@@ -2285,6 +2822,367 @@ fn expand_cxx_vector(
     }
 }
 
+fn expand_cxx_deque(
+    key: &NamedImplKey,
+    conditional_impl: &ConditionalImpl,
+    types: &Types,
+) -> TokenStream {
+    let link_get_unchecked = format!("cxxbridge1$std$deque${}$get_unchecked", key.symbol);
+    let (impl_generics, inner_with_generics) =
+        generics::split_for_impl(key, conditional_impl, types);
+    let end_span = conditional_impl
+        .explicit_impl
+        .map_or(key.end_span, |explicit| explicit.brace_token.span.join());
+
+    let get_unchecked = quote_spanned! {end_span=>
+        unsafe fn __get_unchecked(d: *mut ::cxx::CxxDeque<Self>, pos: ::cxx::core::primitive::usize) -> *mut Self {
+            unsafe extern "C" {
+                #[link_name = #link_get_unchecked]
+                fn __get_unchecked #impl_generics(
+                    d: *mut ::cxx::CxxDeque<#inner_with_generics>,
+                    pos: ::cxx::core::primitive::usize,
+                ) -> *mut ::cxx::core::ffi::c_void;
+            }
+            unsafe { __get_unchecked(d, pos).cast::<Self>() }
+        }
+    };
+    let push_pop = expand_cxx_container_push_pop(key, conditional_impl, types, "deque", "CxxDeque");
+
+    expand_cxx_container(
+        key,
+        conditional_impl,
+        types,
+        "deque",
+        quote!(#get_unchecked #push_pop),
+    )
+}
+
+fn expand_cxx_list(
+    key: &NamedImplKey,
+    conditional_impl: &ConditionalImpl,
+    types: &Types,
+) -> TokenStream {
+    let push_pop = expand_cxx_container_push_pop(key, conditional_impl, types, "list", "CxxList");
+    expand_cxx_container(key, conditional_impl, types, "list", push_pop)
+}
+
+fn expand_cxx_set(
+    key: &NamedImplKey,
+    conditional_impl: &ConditionalImpl,
+    types: &Types,
+) -> TokenStream {
+    let prefix = format!("cxxbridge1$std$set${}$", key.symbol);
+    let link_contains = format!("{}contains", prefix);
+    let link_insert = format!("{}insert", prefix);
+    let link_remove = format!("{}remove", prefix);
+    let (impl_generics, inner_with_generics) =
+        generics::split_for_impl(key, conditional_impl, types);
+    let end_span = conditional_impl
+        .explicit_impl
+        .map_or(key.end_span, |explicit| explicit.brace_token.span.join());
+
+    let not_copy_constructible_err = format!(
+        "{} is not copy constructible",
+        generics::concise_cxx_name(key.inner, types),
+    );
+
+    let set_methods = quote_spanned! {end_span=>
+        fn __set_contains(s: &::cxx::CxxSet<Self>, value: &Self) -> ::cxx::core::primitive::bool {
+            unsafe extern "C" {
+                #[link_name = #link_contains]
+                fn __set_contains #impl_generics(
+                    s: &::cxx::CxxSet<#inner_with_generics>,
+                    value: *const ::cxx::core::ffi::c_void,
+                ) -> ::cxx::core::primitive::bool;
+            }
+            unsafe { __set_contains(s, ::cxx::core::ptr::from_ref::<Self>(value).cast::<::cxx::core::ffi::c_void>()) }
+        }
+        unsafe fn __set_insert(s: ::cxx::core::pin::Pin<&mut ::cxx::CxxSet<Self>>, value: &Self) -> ::cxx::core::primitive::bool {
+            unsafe extern "C" {
+                #[link_name = #link_insert]
+                fn __set_insert #impl_generics(
+                    s: ::cxx::core::pin::Pin<&mut ::cxx::CxxSet<#inner_with_generics>>,
+                    value: *const ::cxx::core::ffi::c_void,
+                    inserted: *mut ::cxx::core::primitive::bool,
+                ) -> ::cxx::core::primitive::bool;
+            }
+            let value = ::cxx::core::ptr::from_ref::<Self>(value).cast::<::cxx::core::ffi::c_void>();
+            let mut inserted = false;
+            if !unsafe { __set_insert(s, value, &raw mut inserted) } {
+                ::cxx::core::panic!(#not_copy_constructible_err);
+            }
+            inserted
+        }
+        unsafe fn __set_remove(s: ::cxx::core::pin::Pin<&mut ::cxx::CxxSet<Self>>, value: &Self) -> ::cxx::core::primitive::bool {
+            unsafe extern "C" {
+                #[link_name = #link_remove]
+                fn __set_remove #impl_generics(
+                    s: ::cxx::core::pin::Pin<&mut ::cxx::CxxSet<#inner_with_generics>>,
+                    value: *const ::cxx::core::ffi::c_void,
+                ) -> ::cxx::core::primitive::bool;
+            }
+            unsafe { __set_remove(s, ::cxx::core::ptr::from_ref::<Self>(value).cast::<::cxx::core::ffi::c_void>()) }
+        }
+    };
+
+    expand_cxx_container(key, conditional_impl, types, "set", set_methods)
+}
+
+// Shared by CxxDeque<T> and CxxList<T>, which both have push and pop at either
+// end, as well as insert and remove at a position, for elements that can be
+// passed by value.
+fn expand_cxx_container_push_pop(
+    key: &NamedImplKey,
+    conditional_impl: &ConditionalImpl,
+    types: &Types,
+    container: &str,
+    outer: &str,
+) -> Option<TokenStream> {
+    if !types.is_maybe_trivial(key.inner) {
+        return None;
+    }
+
+    let outer = Ident::new(outer, Span::call_site());
+    let (impl_generics, inner_with_generics) =
+        generics::split_for_impl(key, conditional_impl, types);
+    let end_span = conditional_impl
+        .explicit_impl
+        .map_or(key.end_span, |explicit| explicit.brace_token.span.join());
+
+    let mut methods = TokenStream::new();
+    for end in ["back", "front"] {
+        let link_push = format!("cxxbridge1$std${}${}$push_{}", container, key.symbol, end);
+        let link_pop = format!("cxxbridge1$std${}${}$pop_{}", container, key.symbol, end);
+        let push = format_ident!("__push_{}", end);
+        let pop = format_ident!("__pop_{}", end);
+        methods.extend(quote_spanned! {end_span=>
+            unsafe fn #push(
+                this: ::cxx::core::pin::Pin<&mut ::cxx::#outer<Self>>,
+                value: &mut ::cxx::core::mem::ManuallyDrop<Self>,
+            ) {
+                unsafe extern "C" {
+                    #[link_name = #link_push]
+                    fn #push #impl_generics(
+                        this: ::cxx::core::pin::Pin<&mut ::cxx::#outer<#inner_with_generics>>,
+                        value: *mut ::cxx::core::ffi::c_void,
+                    );
+                }
+                unsafe {
+                    #push(
+                        this,
+                        ::cxx::core::ptr::from_mut::<::cxx::core::mem::ManuallyDrop<Self>>(value).cast::<::cxx::core::ffi::c_void>(),
+                    );
+                }
+            }
+            unsafe fn #pop(
+                this: ::cxx::core::pin::Pin<&mut ::cxx::#outer<Self>>,
+                out: &mut ::cxx::core::mem::MaybeUninit<Self>,
+            ) {
+                unsafe extern "C" {
+                    #[link_name = #link_pop]
+                    fn #pop #impl_generics(
+                        this: ::cxx::core::pin::Pin<&mut ::cxx::#outer<#inner_with_generics>>,
+                        out: *mut ::cxx::core::ffi::c_void,
+                    );
+                }
+                unsafe {
+                    #pop(
+                        this,
+                        ::cxx::core::ptr::from_mut::<::cxx::core::mem::MaybeUninit<Self>>(out).cast::<::cxx::core::ffi::c_void>(),
+                    );
+                }
+            }
+        });
+    }
+
+    let link_insert = format!("cxxbridge1$std${}${}$insert", container, key.symbol);
+    let link_remove = format!("cxxbridge1$std${}${}$remove", container, key.symbol);
+    methods.extend(quote_spanned! {end_span=>
+        unsafe fn __insert(
+            this: ::cxx::core::pin::Pin<&mut ::cxx::#outer<Self>>,
+            pos: ::cxx::core::primitive::usize,
+            value: &mut ::cxx::core::mem::ManuallyDrop<Self>,
+        ) {
+            unsafe extern "C" {
+                #[link_name = #link_insert]
+                fn __insert #impl_generics(
+                    this: ::cxx::core::pin::Pin<&mut ::cxx::#outer<#inner_with_generics>>,
+                    pos: ::cxx::core::primitive::usize,
+                    value: *mut ::cxx::core::ffi::c_void,
+                );
+            }
+            unsafe {
+                __insert(
+                    this,
+                    pos,
+                    ::cxx::core::ptr::from_mut::<::cxx::core::mem::ManuallyDrop<Self>>(value).cast::<::cxx::core::ffi::c_void>(),
+                );
+            }
+        }
+        unsafe fn __remove(
+            this: ::cxx::core::pin::Pin<&mut ::cxx::#outer<Self>>,
+            pos: ::cxx::core::primitive::usize,
+            out: &mut ::cxx::core::mem::MaybeUninit<Self>,
+        ) {
+            unsafe extern "C" {
+                #[link_name = #link_remove]
+                fn __remove #impl_generics(
+                    this: ::cxx::core::pin::Pin<&mut ::cxx::#outer<#inner_with_generics>>,
+                    pos: ::cxx::core::primitive::usize,
+                    out: *mut ::cxx::core::ffi::c_void,
+                );
+            }
+            unsafe {
+                __remove(
+                    this,
+                    pos,
+                    ::cxx::core::ptr::from_mut::<::cxx::core::mem::MaybeUninit<Self>>(out).cast::<::cxx::core::ffi::c_void>(),
+                );
+            }
+        }
+    });
+    Some(methods)
+}
+
+// Shared by CxxDeque<T>, CxxList<T> and CxxSet<T>. The container-specific
+// methods of the element trait are passed in as `extra_methods`.
+fn expand_cxx_container(
+    key: &NamedImplKey,
+    conditional_impl: &ConditionalImpl,
+    types: &Types,
+    container: &str,
+    extra_methods: impl ToTokens,
+) -> TokenStream {
+    let prefix = format!("cxxbridge1$std${}${}$", container, key.symbol);
+    let link_new = format!("{}new", prefix);
+    let link_size = format!("{}size", prefix);
+    let link_clear = format!("{}clear", prefix);
+    let link_iter = format!("{}iter", prefix);
+    let link_iter_next = format!("{}iter_next", prefix);
+    let link_iter_drop = format!("{}iter_drop", prefix);
+    let unique_ptr_prefix = format!("cxxbridge1$unique_ptr$std${}${}$", container, key.symbol);
+    let link_unique_ptr_null = format!("{}null", unique_ptr_prefix);
+    let link_unique_ptr_raw = format!("{}raw", unique_ptr_prefix);
+    let link_unique_ptr_get = format!("{}get", unique_ptr_prefix);
+    let link_unique_ptr_release = format!("{}release", unique_ptr_prefix);
+    let link_unique_ptr_drop = format!("{}drop", unique_ptr_prefix);
+
+    let (outer, module, element_trait) = match container {
+        "deque" => ("CxxDeque", "deque", "DequeElement"),
+        "list" => ("CxxList", "list", "ListElement"),
+        "set" => ("CxxSet", "set", "SetElement"),
+        _ => unreachable!(),
+    };
+    let outer = Ident::new(outer, Span::call_site());
+    let module = Ident::new(module, Span::call_site());
+    let element_trait = Ident::new(element_trait, Span::call_site());
+    let container_new = format_ident!("__{}_new", container);
+    let container_size = format_ident!("__{}_size", container);
+    let container_clear = format_ident!("__{}_clear", container);
+    let container_iter = format_ident!("__{}_iter", container);
+
+    let name = generics::concise_rust_name(key.inner);
+    let (impl_generics, inner_with_generics) =
+        generics::split_for_impl(key, conditional_impl, types);
+
+    let cfg = conditional_impl.cfg.into_attr();
+    let begin_span = conditional_impl
+        .explicit_impl
+        .map_or(key.begin_span, |explicit| explicit.impl_token.span);
+    let end_span = conditional_impl
+        .explicit_impl
+        .map_or(key.end_span, |explicit| explicit.brace_token.span.join());
+    let unsafe_token = format_ident!("unsafe", span = begin_span);
+
+    quote_spanned! {end_span=>
+        #cfg
+        #[automatically_derived]
+        #unsafe_token impl #impl_generics ::cxx::#module::#element_trait for #inner_with_generics {
+            fn __typename(f: &mut ::cxx::core::fmt::Formatter<'_>) -> ::cxx::core::fmt::Result {
+                f.write_str(#name)
+            }
+            fn #container_new() -> *mut ::cxx::#outer<Self> {
+                unsafe extern "C" {
+                    #[link_name = #link_new]
+                    fn #container_new #impl_generics() -> *mut ::cxx::#outer<#inner_with_generics>;
+                }
+                unsafe { #container_new() }
+            }
+            fn #container_size(c: &::cxx::#outer<Self>) -> ::cxx::core::primitive::usize {
+                unsafe extern "C" {
+                    #[link_name = #link_size]
+                    fn #container_size #impl_generics(_: &::cxx::#outer<#inner_with_generics>) -> ::cxx::core::primitive::usize;
+                }
+                unsafe { #container_size(c) }
+            }
+            fn #container_iter(c: &::cxx::#outer<Self>) -> ::cxx::CxxIter<'_, Self> {
+                unsafe extern "C" {
+                    #[link_name = #link_iter]
+                    fn __iter #impl_generics(_: &::cxx::#outer<#inner_with_generics>) -> *mut ::cxx::core::ffi::c_void;
+                    #[link_name = #link_iter_next]
+                    fn __iter_next(iter: *mut ::cxx::core::ffi::c_void) -> *const ::cxx::core::ffi::c_void;
+                    #[link_name = #link_iter_drop]
+                    fn __iter_drop(iter: *mut ::cxx::core::ffi::c_void);
+                }
+                unsafe { ::cxx::CxxIter::__new(__iter(c), __iter_next, __iter_drop) }
+            }
+            unsafe fn #container_clear(c: ::cxx::core::pin::Pin<&mut ::cxx::#outer<Self>>) {
+                unsafe extern "C" {
+                    #[link_name = #link_clear]
+                    fn #container_clear #impl_generics(_: ::cxx::core::pin::Pin<&mut ::cxx::#outer<#inner_with_generics>>);
+                }
+                unsafe { #container_clear(c) }
+            }
+            #extra_methods
+            fn __unique_ptr_null() -> ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void> {
+                unsafe extern "C" {
+                    #[link_name = #link_unique_ptr_null]
+                    fn __unique_ptr_null(this: *mut ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>);
+                }
+                let mut repr = ::cxx::core::mem::MaybeUninit::uninit();
+                unsafe {
+                    __unique_ptr_null(&raw mut repr);
+                }
+                repr
+            }
+            unsafe fn __unique_ptr_raw(raw: *mut ::cxx::#outer<Self>) -> ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void> {
+                unsafe extern "C" {
+                    #[link_name = #link_unique_ptr_raw]
+                    fn __unique_ptr_raw #impl_generics(this: *mut ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>, raw: *mut ::cxx::#outer<#inner_with_generics>);
+                }
+                let mut repr = ::cxx::core::mem::MaybeUninit::uninit();
+                unsafe {
+                    __unique_ptr_raw(&raw mut repr, raw);
+                }
+                repr
+            }
+            unsafe fn __unique_ptr_get(repr: ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *const ::cxx::#outer<Self> {
+                unsafe extern "C" {
+                    #[link_name = #link_unique_ptr_get]
+                    fn __unique_ptr_get #impl_generics(this: *const ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *const ::cxx::#outer<#inner_with_generics>;
+                }
+                unsafe { __unique_ptr_get(&raw const repr) }
+            }
+            unsafe fn __unique_ptr_release(mut repr: ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *mut ::cxx::#outer<Self> {
+                unsafe extern "C" {
+                    #[link_name = #link_unique_ptr_release]
+                    fn __unique_ptr_release #impl_generics(this: *mut ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) -> *mut ::cxx::#outer<#inner_with_generics>;
+                }
+                unsafe { __unique_ptr_release(&raw mut repr) }
+            }
+            unsafe fn __unique_ptr_drop(mut repr: ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>) {
+                unsafe extern "C" {
+                    #[link_name = #link_unique_ptr_drop]
+                    fn __unique_ptr_drop(this: *mut ::cxx::core::mem::MaybeUninit<*mut ::cxx::core::ffi::c_void>);
+                }
+                unsafe {
+                    __unique_ptr_drop(&raw mut repr);
+                }
+            }
+        }
+    }
+}
+
 fn expand_return_type(ret: &Option<Type>) -> TokenStream {
     match ret {
         Some(ret) => quote!(-> #ret),
@@ -2294,7 +3192,7 @@ fn expand_return_type(ret: &Option<Type>) -> TokenStream {
 
 fn indirect_return(sig: &Signature, types: &Types, lang: Lang) -> bool {
     sig.ret.as_ref().is_some_and(|ret| {
-        sig.throws
+        sig.fallible()
             || types.needs_indirect_abi(ret)
             || match lang {
                 Lang::Cxx | Lang::CxxUnwind => types.contains_elided_lifetime(ret),
@@ -2318,6 +3216,15 @@ fn expand_extern_type(ty: &Type, types: &Types, proper: bool) -> TokenStream {
                 quote_spanned!(span=> *mut #inner)
             }
         }
+        Type::RustArc(ty) => {
+            let span = ty.name.span();
+            if proper && types.is_considered_improper_ctype(&ty.inner) {
+                quote_spanned!(span=> *const ::cxx::core::ffi::c_void)
+            } else {
+                let inner = expand_extern_type(&ty.inner, types, proper);
+                quote_spanned!(span=> *const #inner)
+            }
+        }
         Type::RustVec(ty) => {
             // Replace Vec<Foo> with ::cxx::private::RustVec<Foo>. Both have the
             // same layout but only the latter has a predictable ABI. Note that
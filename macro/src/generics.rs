@@ -71,15 +71,46 @@ impl<'a> ToTokens for ResolvedGenericType<'a> {
                     ::cxx::alloc::boxed::Box<#inner>
                 });
             }
+            Type::UniquePtr(_) | Type::SharedPtr(_) | Type::CxxVector(_) | Type::RustVec(_) => {
+                let inner = self.type_argument().unwrap();
+                tokens.extend(match self.ty {
+                    Type::UniquePtr(_) => quote!(::cxx::UniquePtr<#inner>),
+                    Type::SharedPtr(_) => quote!(::cxx::SharedPtr<#inner>),
+                    Type::CxxVector(_) => quote!(::cxx::CxxVector<#inner>),
+                    _ => quote!(::cxx::alloc::vec::Vec<#inner>),
+                });
+            }
             _ => unreachable!("syntax/check.rs should reject other types"),
         }
     }
 }
 
+impl<'a> ResolvedGenericType<'a> {
+    /// For a vector element like `SharedPtr<T>`, `UniquePtr<T>`,
+    /// `CxxVector<T>` or `Vec<T>`, returns the resolved `T`.
+    pub(crate) fn type_argument(&self) -> Option<Self> {
+        match self.ty {
+            Type::UniquePtr(ty1)
+            | Type::SharedPtr(ty1)
+            | Type::CxxVector(ty1)
+            | Type::RustVec(ty1) => Some(ResolvedGenericType {
+                ty: &ty1.inner,
+                explicit_impl: self.explicit_impl,
+                types: self.types,
+            }),
+            _ => None,
+        }
+    }
+}
+
 fn get_impl_generics<'a>(ty: &Type, types: &Types<'a>) -> &'a Lifetimes {
     match ty {
         Type::Ident(named_type) => types.resolve(named_type).generics,
-        Type::RustBox(ty1) => get_impl_generics(&ty1.inner, types),
+        Type::RustBox(ty1)
+        | Type::UniquePtr(ty1)
+        | Type::SharedPtr(ty1)
+        | Type::CxxVector(ty1)
+        | Type::RustVec(ty1) => get_impl_generics(&ty1.inner, types),
         _ => unreachable!("syntax/check.rs should reject other types"),
     }
 }
@@ -98,6 +129,18 @@ pub(crate) fn format_for_prevent_unwind_label(ty: &Type) -> TokenStream {
                 ::cxx::core::concat!("Box<", #inner, ">")
             }
         }
+        Type::RustVec(ty1) => {
+            let inner = format_for_prevent_unwind_label(&ty1.inner);
+            quote! {
+                ::cxx::core::concat!("Vec<", #inner, ">")
+            }
+        }
+        Type::SharedPtr(ty1) => {
+            let inner = format_for_prevent_unwind_label(&ty1.inner);
+            quote! {
+                ::cxx::core::concat!("SharedPtr<", #inner, ">")
+            }
+        }
         _ => unreachable!("syntax/check.rs should reject other types"),
     }
 }
@@ -109,6 +152,18 @@ pub(crate) fn concise_rust_name(ty: &Type) -> String {
             let inner = concise_rust_name(&ty1.inner);
             format!("Box<{inner}>")
         }
+        Type::UniquePtr(ty1) => {
+            let inner = concise_rust_name(&ty1.inner);
+            format!("UniquePtr<{inner}>")
+        }
+        Type::SharedPtr(ty1) => {
+            let inner = concise_rust_name(&ty1.inner);
+            format!("SharedPtr<{inner}>")
+        }
+        Type::CxxVector(ty1) => {
+            let inner = concise_rust_name(&ty1.inner);
+            format!("CxxVector<{inner}>")
+        }
         _ => unreachable!("syntax/check.rs should reject other types"),
     }
 }
@@ -123,6 +178,18 @@ pub(crate) fn concise_cxx_name(ty: &Type, types: &Types) -> String {
             let inner = concise_cxx_name(&ty1.inner, types);
             format!("rust::Box<{inner}>")
         }
+        Type::UniquePtr(ty1) => {
+            let inner = concise_cxx_name(&ty1.inner, types);
+            format!("std::unique_ptr<{inner}>")
+        }
+        Type::SharedPtr(ty1) => {
+            let inner = concise_cxx_name(&ty1.inner, types);
+            format!("std::shared_ptr<{inner}>")
+        }
+        Type::CxxVector(ty1) => {
+            let inner = concise_cxx_name(&ty1.inner, types);
+            format!("std::vector<{inner}>")
+        }
         _ => unreachable!("syntax/check.rs should reject other types"),
     }
 }
@@ -1,8 +1,21 @@
 #include "../include/cxx.h"
 #include <cstdio>
+#include <cstdlib>
 #include <cstring>
+#include <deque>
+#include <exception>
 #include <iostream>
+#include <list>
 #include <memory>
+#include <set>
+#include <typeinfo>
+
+#if defined(__has_include)
+#if __has_include(<cxxabi.h>)
+#include <cxxabi.h>
+#define RUST_CXX_HAS_CXXABI
+#endif
+#endif
 
 #ifdef __cpp_lib_bit_cast
 #include <bit>
@@ -22,6 +35,13 @@
 #define RUST_CXX_NO_EXCEPTIONS
 #endif
 
+// Likewise `-fno-rtti`, in which case exceptions are passed to Rust without
+// their dynamic type or nested exceptions.
+#if !defined(RUST_CXX_NO_RTTI) && !defined(__cpp_rtti) &&                      \
+    !defined(__GXX_RTTI) && !defined(_CPPRTTI)
+#define RUST_CXX_NO_RTTI
+#endif
+
 #ifdef __GNUC__
 #pragma GCC diagnostic ignored "-Wmissing-declarations"
 #pragma GCC diagnostic ignored "-Wshadow"
@@ -61,6 +81,88 @@ void cxxbridge1$cxx_string$push(std::string &s, const std::uint8_t *ptr,
   s.append(reinterpret_cast<const char *>(ptr), len);
 }
 
+std::string *cxxbridge1$cxx_string$new(const std::uint8_t *ptr,
+                                       std::size_t len) noexcept {
+  return new std::string(reinterpret_cast<const char *>(ptr), len);
+}
+
+std::size_t cxxbridge1$cxx_string$capacity(const std::string &s) noexcept {
+  return s.capacity();
+}
+
+void cxxbridge1$cxx_string$shrink_to_fit(std::string &s) noexcept {
+  s.shrink_to_fit();
+}
+
+void cxxbridge1$cxx_string$replace(std::string &s, std::size_t pos,
+                                   std::size_t count, const std::uint8_t *ptr,
+                                   std::size_t len) noexcept {
+  s.replace(pos, count, reinterpret_cast<const char *>(ptr), len);
+}
+
+void cxxbridge1$cxx_string$resize(std::string &s, std::size_t count,
+                                  char ch) noexcept {
+  s.resize(count, ch);
+}
+
+std::size_t cxxbridge1$cxx_string$find(const std::string &s,
+                                       const std::uint8_t *ptr,
+                                       std::size_t len) noexcept {
+  return s.find(reinterpret_cast<const char *>(ptr), 0, len);
+}
+
+std::size_t cxxbridge1$cxx_string$rfind(const std::string &s,
+                                        const std::uint8_t *ptr,
+                                        std::size_t len) noexcept {
+  return s.rfind(reinterpret_cast<const char *>(ptr), std::string::npos, len);
+}
+
+// std::u16string, std::u32string
+#define CXX_UNICODE_STRING_OPS(RUST_TYPE, CXX_TYPE, CHAR_TYPE)                 \
+  CXX_TYPE *cxxbridge1$cxx_##RUST_TYPE##$new() noexcept {                      \
+    return new CXX_TYPE();                                                     \
+  }                                                                            \
+  const CHAR_TYPE *cxxbridge1$cxx_##RUST_TYPE##$data(                          \
+      const CXX_TYPE &s) noexcept {                                            \
+    return s.data();                                                           \
+  }                                                                            \
+  std::size_t cxxbridge1$cxx_##RUST_TYPE##$length(                             \
+      const CXX_TYPE &s) noexcept {                                            \
+    return s.length();                                                         \
+  }                                                                            \
+  void cxxbridge1$cxx_##RUST_TYPE##$clear(CXX_TYPE &s) noexcept { s.clear(); } \
+  void cxxbridge1$cxx_##RUST_TYPE##$reserve_total(                             \
+      CXX_TYPE &s, std::size_t new_cap) noexcept {                             \
+    s.reserve(new_cap);                                                        \
+  }                                                                            \
+  void cxxbridge1$cxx_##RUST_TYPE##$push(CXX_TYPE &s, const CHAR_TYPE *ptr,    \
+                                         std::size_t len) noexcept {           \
+    s.append(ptr, len);                                                        \
+  }                                                                            \
+  void cxxbridge1$unique_ptr$std$##RUST_TYPE##$null(                           \
+      std::unique_ptr<CXX_TYPE> *ptr) noexcept {                               \
+    new (ptr) std::unique_ptr<CXX_TYPE>();                                     \
+  }                                                                            \
+  void cxxbridge1$unique_ptr$std$##RUST_TYPE##$raw(                            \
+      std::unique_ptr<CXX_TYPE> *ptr, CXX_TYPE *raw) noexcept {                \
+    new (ptr) std::unique_ptr<CXX_TYPE>(raw);                                  \
+  }                                                                            \
+  const CXX_TYPE *cxxbridge1$unique_ptr$std$##RUST_TYPE##$get(                 \
+      const std::unique_ptr<CXX_TYPE> &ptr) noexcept {                         \
+    return ptr.get();                                                          \
+  }                                                                            \
+  CXX_TYPE *cxxbridge1$unique_ptr$std$##RUST_TYPE##$release(                   \
+      std::unique_ptr<CXX_TYPE> &ptr) noexcept {                               \
+    return ptr.release();                                                      \
+  }                                                                            \
+  void cxxbridge1$unique_ptr$std$##RUST_TYPE##$drop(                           \
+      std::unique_ptr<CXX_TYPE> *ptr) noexcept {                               \
+    ptr->~unique_ptr();                                                        \
+  }
+
+CXX_UNICODE_STRING_OPS(u16string, std::u16string, char16_t)
+CXX_UNICODE_STRING_OPS(u32string, std::u32string, char32_t)
+
 // rust::String
 void cxxbridge1$string$new(rust::String *self) noexcept;
 void cxxbridge1$string$clone(rust::String *self,
@@ -84,6 +186,8 @@ void cxxbridge1$string$reserve_total(rust::String *self,
 
 // rust::Str
 void cxxbridge1$str$new(rust::Str *self) noexcept;
+void cxxbridge1$str$to_utf16(const char *ptr, std::size_t len,
+                             std::u16string &out) noexcept;
 void cxxbridge1$str$ref(rust::Str *self, const rust::String *string) noexcept;
 bool cxxbridge1$str$from(rust::Str *self, const char *ptr,
                          std::size_t len) noexcept;
@@ -238,6 +342,12 @@ String::operator std::string() const {
   return std::string(this->data(), this->size());
 }
 
+std::u16string String::to_utf16() const {
+  std::u16string utf16;
+  cxxbridge1$str$to_utf16(this->data(), this->size(), utf16);
+  return utf16;
+}
+
 const char *String::data() const noexcept {
   return cxxbridge1$string$ptr(this);
 }
@@ -357,6 +467,12 @@ Str::operator std::string_view() const {
 }
 #endif
 
+std::u16string Str::to_utf16() const {
+  std::u16string utf16;
+  cxxbridge1$str$to_utf16(this->data(), this->size(), utf16);
+  return utf16;
+}
+
 const char *Str::data() const noexcept { return cxxbridge1$str$ptr(this); }
 
 std::size_t Str::size() const noexcept { return cxxbridge1$str$len(this); }
@@ -515,18 +631,36 @@ const char *cxxbridge1$error(const char *ptr, std::size_t len) noexcept {
 }
 } // extern "C"
 
+namespace detail {
+struct ErrorDetails final {
+  std::string what;
+  std::string debug;
+  std::vector<std::string> chain;
+};
+} // namespace detail
+
+static detail::ErrorDetails *
+errorDetailsCopy(const detail::ErrorDetails *details) {
+  return details ? new detail::ErrorDetails(*details) : nullptr;
+}
+
 Error::Error(const Error &other)
     : std::exception(other),
       msg(other.msg ? errorCopy(other.msg, other.len) : nullptr),
-      len(other.len) {}
+      len(other.len), details(errorDetailsCopy(other.details)) {}
 
 Error::Error(Error &&other) noexcept
-    : std::exception(std::move(other)), msg(other.msg), len(other.len) {
+    : std::exception(std::move(other)), msg(other.msg), len(other.len),
+      details(other.details) {
   other.msg = nullptr;
   other.len = 0;
+  other.details = nullptr;
 }
 
-Error::~Error() noexcept { delete[] this->msg; }
+Error::~Error() noexcept {
+  delete[] this->msg;
+  delete this->details;
+}
 
 Error &Error::operator=(const Error &other) & {
   if (this != &other) {
@@ -537,6 +671,9 @@ Error &Error::operator=(const Error &other) & {
       this->msg = errorCopy(other.msg, other.len);
       this->len = other.len;
     }
+    detail::ErrorDetails *details = errorDetailsCopy(other.details);
+    delete this->details;
+    this->details = details;
   }
   return *this;
 }
@@ -544,14 +681,66 @@ Error &Error::operator=(const Error &other) & {
 Error &Error::operator=(Error &&other) & noexcept {
   std::exception::operator=(std::move(other));
   delete[] this->msg;
+  delete this->details;
   this->msg = other.msg;
   this->len = other.len;
+  this->details = other.details;
   other.msg = nullptr;
   other.len = 0;
+  other.details = nullptr;
   return *this;
 }
 
-const char *Error::what() const noexcept { return this->msg; }
+const char *Error::what() const noexcept {
+  return this->details ? this->details->what.c_str() : this->msg;
+}
+
+const char *Error::debug() const noexcept {
+  return this->details ? this->details->debug.c_str() : "";
+}
+
+const std::vector<std::string> &Error::chain() const noexcept {
+  static const std::vector<std::string> empty;
+  return this->details ? this->details->chain : empty;
+}
+
+Panic::Panic(const Panic &other)
+    : std::exception(other),
+      msg(other.msg ? errorCopy(other.msg, other.len) : nullptr),
+      len(other.len) {}
+
+Panic::Panic(Panic &&other) noexcept
+    : std::exception(std::move(other)), msg(other.msg), len(other.len) {
+  other.msg = nullptr;
+  other.len = 0;
+}
+
+Panic::~Panic() noexcept { delete[] this->msg; }
+
+Panic &Panic::operator=(const Panic &other) & {
+  if (this != &other) {
+    std::exception::operator=(other);
+    delete[] this->msg;
+    this->msg = nullptr;
+    if (other.msg) {
+      this->msg = errorCopy(other.msg, other.len);
+      this->len = other.len;
+    }
+  }
+  return *this;
+}
+
+Panic &Panic::operator=(Panic &&other) & noexcept {
+  std::exception::operator=(std::move(other));
+  delete[] this->msg;
+  this->msg = other.msg;
+  this->len = other.len;
+  other.msg = nullptr;
+  other.len = 0;
+  return *this;
+}
+
+const char *Panic::what() const noexcept { return this->msg; }
 
 namespace {
 template <typename T>
@@ -570,7 +759,24 @@ struct PtrLen final {
 } // namespace repr
 
 extern "C" {
-repr::PtrLen cxxbridge1$exception(const char *, std::size_t len) noexcept;
+void *cxxbridge1$error$details(const char *what, std::size_t what_len,
+                               const char *debug, std::size_t debug_len,
+                               const repr::PtrLen *chain,
+                               std::size_t chain_len) noexcept {
+  auto details = new detail::ErrorDetails;
+  details->what.assign(what, what_len);
+  details->debug.assign(debug, debug_len);
+  details->chain.reserve(chain_len);
+  for (std::size_t i = 0; i < chain_len; i++) {
+    details->chain.emplace_back(static_cast<const char *>(chain[i].ptr),
+                                chain[i].len);
+  }
+  return details;
+}
+
+void *cxxbridge1$exception(const char *what, std::size_t what_len,
+                           const char *type, std::size_t type_len,
+                           void *source) noexcept;
 }
 
 namespace detail {
@@ -599,14 +805,73 @@ public:
   Fail(repr::PtrLen &throw$) noexcept : throw$(throw$) {}
   void operator()(const char *) noexcept;
   void operator()(const std::string &) noexcept;
+  void operator()(const std::exception &) noexcept;
 };
 
+namespace {
+void *new_exception(const std::exception &e) noexcept;
+
+std::string exception_type(const std::exception &e) {
+#ifdef RUST_CXX_NO_RTTI
+  (void)e;
+  return std::string();
+#else
+  const char *name = typeid(e).name();
+#ifdef RUST_CXX_HAS_CXXABI
+  int status = 0;
+  char *demangled = abi::__cxa_demangle(name, nullptr, nullptr, &status);
+  if (demangled != nullptr) {
+    std::string type(demangled);
+    std::free(demangled);
+    return type;
+  }
+#endif
+  return std::string(name);
+#endif
+}
+
+void *exception_source(const std::exception &e) noexcept {
+#if defined(RUST_CXX_NO_RTTI) || defined(RUST_CXX_NO_EXCEPTIONS)
+  (void)e;
+#else
+  auto nested = dynamic_cast<const std::nested_exception *>(&e);
+  if (nested != nullptr && nested->nested_ptr() != nullptr) {
+    try {
+      std::rethrow_exception(nested->nested_ptr());
+    } catch (const std::exception &source) {
+      return new_exception(source);
+    } catch (...) {
+      // The chain ends at the first exception not derived from std::exception.
+    }
+  }
+#endif
+  return nullptr;
+}
+
+void *new_exception(const std::exception &e) noexcept {
+  void *source = exception_source(e);
+  const char *what = e.what();
+  std::string type = exception_type(e);
+  return cxxbridge1$exception(what, std::strlen(what),
+                              type.empty() ? nullptr : type.data(),
+                              type.length(), source);
+}
+} // namespace
+
 void Fail::operator()(const char *catch$) noexcept {
-  throw$ = cxxbridge1$exception(catch$, std::strlen(catch$));
+  void *exception = cxxbridge1$exception(catch$, std::strlen(catch$), nullptr,
+                                         0, nullptr);
+  throw$ = repr::PtrLen{exception, 0};
 }
 
 void Fail::operator()(const std::string &catch$) noexcept {
-  throw$ = cxxbridge1$exception(catch$.data(), catch$.length());
+  void *exception = cxxbridge1$exception(catch$.data(), catch$.length(),
+                                         nullptr, 0, nullptr);
+  throw$ = repr::PtrLen{exception, 0};
+}
+
+void Fail::operator()(const std::exception &catch$) noexcept {
+  throw$ = repr::PtrLen{new_exception(catch$), 0};
 }
 } // namespace detail
 
@@ -649,6 +914,26 @@ static_assert(alignof(std::string) <= alignof(void *),
               "unexpectedly large std::string alignment");
 static_assert(sizeof(std::string) <= kMaxExpectedWordsInString * sizeof(void *),
               "unexpectedly large std::string size");
+
+template <typename Container>
+class container_iter final {
+  using iterator = typename Container::const_iterator;
+
+public:
+  explicit container_iter(const Container &container)
+      : it(container.begin()), end(container.end()) {}
+
+  const typename Container::value_type *next() noexcept {
+    if (this->it == this->end) {
+      return nullptr;
+    }
+    return &*this->it++;
+  }
+
+private:
+  iterator it;
+  iterator end;
+};
 } // namespace
 
 #define STD_VECTOR_OPS(RUST_TYPE, CXX_TYPE)                                    \
@@ -671,6 +956,16 @@ static_assert(sizeof(std::string) <= kMaxExpectedWordsInString * sizeof(void *),
       std::vector<CXX_TYPE> *s, std::size_t new_cap) noexcept {                \
     s->reserve(new_cap);                                                       \
   }                                                                            \
+  void cxxbridge1$std$vector$##RUST_TYPE##$truncate(                           \
+      std::vector<CXX_TYPE> *s, std::size_t len) noexcept {                    \
+    while (s->size() > len) {                                                  \
+      s->pop_back();                                                           \
+    }                                                                          \
+  }                                                                            \
+  void cxxbridge1$std$vector$##RUST_TYPE##$swap(                               \
+      std::vector<CXX_TYPE> *s, std::vector<CXX_TYPE> *other) noexcept {       \
+    s->swap(*other);                                                           \
+  }                                                                            \
   void cxxbridge1$unique_ptr$std$vector$##RUST_TYPE##$null(                    \
       std::unique_ptr<std::vector<CXX_TYPE>> *ptr) noexcept {                  \
     new (ptr) std::unique_ptr<std::vector<CXX_TYPE>>();                        \
@@ -707,6 +1002,139 @@ static_assert(sizeof(std::string) <= kMaxExpectedWordsInString * sizeof(void *),
     v->pop_back();                                                             \
   }
 
+#define UNIQUE_PTR_ARRAY_OPS(RUST_TYPE, CXX_TYPE)                              \
+  CXX_TYPE *cxxbridge1$unique_ptr$array$##RUST_TYPE##$new(                     \
+      std::size_t len) noexcept {                                              \
+    return new CXX_TYPE[len]();                                                \
+  }                                                                            \
+  void cxxbridge1$unique_ptr$array$##RUST_TYPE##$delete(CXX_TYPE *ptr)         \
+      noexcept {                                                               \
+    delete[] ptr;                                                              \
+  }
+
+#define STD_VECTOR_NESTED_OPS(RUST_TYPE, CXX_TYPE)                             \
+  STD_VECTOR_OPS(std$vector$##RUST_TYPE, std::vector<CXX_TYPE>)
+
+#define STD_VECTOR_SHARED_PTR_OPS(RUST_TYPE, CXX_TYPE)                         \
+  STD_VECTOR_OPS(std$shared_ptr$##RUST_TYPE, std::shared_ptr<CXX_TYPE>)
+
+#define STD_CONTAINER_OPS(CONTAINER, RUST_TYPE, CXX_TYPE)                      \
+  std::CONTAINER<CXX_TYPE> *cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$new()   \
+      noexcept {                                                               \
+    return new std::CONTAINER<CXX_TYPE>();                                     \
+  }                                                                            \
+  std::size_t cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$size(                 \
+      const std::CONTAINER<CXX_TYPE> &s) noexcept {                            \
+    return s.size();                                                           \
+  }                                                                            \
+  void cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$clear(                       \
+      std::CONTAINER<CXX_TYPE> *s) noexcept {                                  \
+    s->clear();                                                                \
+  }                                                                            \
+  void *cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$iter(                       \
+      const std::CONTAINER<CXX_TYPE> &s) noexcept {                            \
+    return new container_iter<std::CONTAINER<CXX_TYPE>>(s);                    \
+  }                                                                            \
+  const CXX_TYPE *cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$iter_next(        \
+      void *iter) noexcept {                                                   \
+    return static_cast<container_iter<std::CONTAINER<CXX_TYPE>> *>(iter)       \
+        ->next();                                                              \
+  }                                                                            \
+  void cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$iter_drop(                   \
+      void *iter) noexcept {                                                   \
+    delete static_cast<container_iter<std::CONTAINER<CXX_TYPE>> *>(iter);      \
+  }                                                                            \
+  void cxxbridge1$unique_ptr$std$##CONTAINER##$##RUST_TYPE##$null(             \
+      std::unique_ptr<std::CONTAINER<CXX_TYPE>> *ptr) noexcept {               \
+    new (ptr) std::unique_ptr<std::CONTAINER<CXX_TYPE>>();                     \
+  }                                                                            \
+  void cxxbridge1$unique_ptr$std$##CONTAINER##$##RUST_TYPE##$raw(              \
+      std::unique_ptr<std::CONTAINER<CXX_TYPE>> *ptr,                          \
+      std::CONTAINER<CXX_TYPE> *raw) noexcept {                                \
+    new (ptr) std::unique_ptr<std::CONTAINER<CXX_TYPE>>(raw);                  \
+  }                                                                            \
+  const std::CONTAINER<CXX_TYPE>                                               \
+      *cxxbridge1$unique_ptr$std$##CONTAINER##$##RUST_TYPE##$get(              \
+          const std::unique_ptr<std::CONTAINER<CXX_TYPE>> &ptr) noexcept {     \
+    return ptr.get();                                                          \
+  }                                                                            \
+  std::CONTAINER<CXX_TYPE>                                                     \
+      *cxxbridge1$unique_ptr$std$##CONTAINER##$##RUST_TYPE##$release(          \
+          std::unique_ptr<std::CONTAINER<CXX_TYPE>> &ptr) noexcept {           \
+    return ptr.release();                                                      \
+  }                                                                            \
+  void cxxbridge1$unique_ptr$std$##CONTAINER##$##RUST_TYPE##$drop(             \
+      std::unique_ptr<std::CONTAINER<CXX_TYPE>> *ptr) noexcept {               \
+    ptr->~unique_ptr();                                                        \
+  }
+
+#define STD_CONTAINER_TRIVIAL_OPS(CONTAINER, RUST_TYPE, CXX_TYPE)              \
+  void cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$push_back(                   \
+      std::CONTAINER<CXX_TYPE> *s, CXX_TYPE *value) noexcept {                 \
+    s->push_back(std::move(*value));                                           \
+    destroy(value);                                                            \
+  }                                                                            \
+  void cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$push_front(                  \
+      std::CONTAINER<CXX_TYPE> *s, CXX_TYPE *value) noexcept {                 \
+    s->push_front(std::move(*value));                                          \
+    destroy(value);                                                            \
+  }                                                                            \
+  void cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$pop_back(                    \
+      std::CONTAINER<CXX_TYPE> *s, CXX_TYPE *out) noexcept {                   \
+    new (out) CXX_TYPE(std::move(s->back()));                                  \
+    s->pop_back();                                                             \
+  }                                                                            \
+  void cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$pop_front(                   \
+      std::CONTAINER<CXX_TYPE> *s, CXX_TYPE *out) noexcept {                   \
+    new (out) CXX_TYPE(std::move(s->front()));                                 \
+    s->pop_front();                                                            \
+  }                                                                            \
+  void cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$insert(                      \
+      std::CONTAINER<CXX_TYPE> *s, std::size_t pos,                            \
+      CXX_TYPE *value) noexcept {                                              \
+    s->insert(std::next(s->begin(), static_cast<std::ptrdiff_t>(pos)),         \
+              std::move(*value));                                              \
+    destroy(value);                                                            \
+  }                                                                            \
+  void cxxbridge1$std$##CONTAINER##$##RUST_TYPE##$remove(                      \
+      std::CONTAINER<CXX_TYPE> *s, std::size_t pos,                            \
+      CXX_TYPE *out) noexcept {                                                \
+    auto it = std::next(s->begin(), static_cast<std::ptrdiff_t>(pos));         \
+    new (out) CXX_TYPE(std::move(*it));                                        \
+    s->erase(it);                                                              \
+  }
+
+#define STD_DEQUE_OPS(RUST_TYPE, CXX_TYPE)                                     \
+  STD_CONTAINER_OPS(deque, RUST_TYPE, CXX_TYPE)                                \
+  CXX_TYPE *cxxbridge1$std$deque$##RUST_TYPE##$get_unchecked(                  \
+      std::deque<CXX_TYPE> *s, std::size_t pos) noexcept {                     \
+    return &(*s)[pos];                                                         \
+  }
+
+#define STD_DEQUE_TRIVIAL_OPS(RUST_TYPE, CXX_TYPE)                             \
+  STD_CONTAINER_TRIVIAL_OPS(deque, RUST_TYPE, CXX_TYPE)
+
+#define STD_LIST_OPS(RUST_TYPE, CXX_TYPE)                                      \
+  STD_CONTAINER_OPS(list, RUST_TYPE, CXX_TYPE)
+
+#define STD_LIST_TRIVIAL_OPS(RUST_TYPE, CXX_TYPE)                              \
+  STD_CONTAINER_TRIVIAL_OPS(list, RUST_TYPE, CXX_TYPE)
+
+#define STD_SET_OPS(RUST_TYPE, CXX_TYPE)                                       \
+  STD_CONTAINER_OPS(set, RUST_TYPE, CXX_TYPE)                                  \
+  bool cxxbridge1$std$set$##RUST_TYPE##$contains(                              \
+      const std::set<CXX_TYPE> &s, const CXX_TYPE &value) noexcept {           \
+    return s.find(value) != s.end();                                           \
+  }                                                                            \
+  bool cxxbridge1$std$set$##RUST_TYPE##$insert(                                \
+      std::set<CXX_TYPE> *s, const CXX_TYPE &value) noexcept {                 \
+    return s->insert(value).second;                                            \
+  }                                                                            \
+  bool cxxbridge1$std$set$##RUST_TYPE##$remove(                                \
+      std::set<CXX_TYPE> *s, const CXX_TYPE &value) noexcept {                 \
+    return s->erase(value) != 0;                                               \
+  }
+
 #define RUST_VEC_EXTERNS(RUST_TYPE, CXX_TYPE)                                  \
   void cxxbridge1$rust_vec$##RUST_TYPE##$new(                                  \
       rust::Vec<CXX_TYPE> *ptr) noexcept;                                      \
@@ -759,6 +1187,22 @@ static_assert(sizeof(std::string) <= kMaxExpectedWordsInString * sizeof(void *),
     cxxbridge1$rust_vec$##RUST_TYPE##$truncate(this, len);                     \
   }
 
+#define RUST_VEC_NESTED_EXTERNS(RUST_TYPE, CXX_TYPE)                           \
+  RUST_VEC_EXTERNS(rust_vec$##RUST_TYPE, rust::Vec<CXX_TYPE>)
+
+#define RUST_VEC_NESTED_OPS(RUST_TYPE, CXX_TYPE)                               \
+  RUST_VEC_OPS(rust_vec$##RUST_TYPE, Vec<CXX_TYPE>)
+
+#define RUST_VEC_SHARED_PTR_EXTERNS(RUST_TYPE, CXX_TYPE)                       \
+  RUST_VEC_EXTERNS(std$shared_ptr$##RUST_TYPE, std::shared_ptr<CXX_TYPE>)
+
+#define RUST_VEC_SHARED_PTR_OPS(RUST_TYPE, CXX_TYPE)                           \
+  RUST_VEC_OPS(std$shared_ptr$##RUST_TYPE, std::shared_ptr<CXX_TYPE>)
+
+// SharedPtr<T> aliasing passes the owner through a std::shared_ptr<void>.
+static_assert(sizeof(std::shared_ptr<void>) == 2 * sizeof(void *), "");
+static_assert(alignof(std::shared_ptr<void>) == alignof(void *), "");
+
 #define SHARED_PTR_OPS(RUST_TYPE, CXX_TYPE)                                    \
   static_assert(sizeof(std::shared_ptr<CXX_TYPE>) == 2 * sizeof(void *), "");  \
   static_assert(alignof(std::shared_ptr<CXX_TYPE>) == alignof(void *), "");    \
@@ -790,6 +1234,21 @@ static_assert(sizeof(std::string) <= kMaxExpectedWordsInString * sizeof(void *),
       const std::shared_ptr<CXX_TYPE> *self) noexcept {                        \
     self->~shared_ptr();                                                       \
   }                                                                            \
+  std::size_t cxxbridge1$std$shared_ptr$##RUST_TYPE##$use_count(               \
+      const std::shared_ptr<CXX_TYPE> &self) noexcept {                        \
+    return static_cast<std::size_t>(self.use_count());                         \
+  }                                                                            \
+  void cxxbridge1$std$shared_ptr$##RUST_TYPE##$erase(                          \
+      const std::shared_ptr<CXX_TYPE> &self,                                   \
+      std::shared_ptr<void> *erased) noexcept {                                \
+    new (erased) std::shared_ptr<void>(self);                                  \
+  }                                                                            \
+  void cxxbridge1$std$shared_ptr$##RUST_TYPE##$alias(                          \
+      std::shared_ptr<void> *erased, CXX_TYPE *ptr,                            \
+      std::shared_ptr<CXX_TYPE> *alias) noexcept {                             \
+    new (alias) std::shared_ptr<CXX_TYPE>(*erased, ptr);                       \
+    erased->~shared_ptr();                                                     \
+  }                                                                            \
   static_assert(sizeof(std::weak_ptr<CXX_TYPE>) == 2 * sizeof(void *), "");    \
   static_assert(alignof(std::weak_ptr<CXX_TYPE>) == alignof(void *), "");      \
   void cxxbridge1$std$weak_ptr$##RUST_TYPE##$null(                             \
@@ -814,6 +1273,10 @@ static_assert(sizeof(std::string) <= kMaxExpectedWordsInString * sizeof(void *),
   void cxxbridge1$std$weak_ptr$##RUST_TYPE##$drop(                             \
       const std::weak_ptr<CXX_TYPE> *self) noexcept {                          \
     self->~weak_ptr();                                                         \
+  }                                                                            \
+  std::size_t cxxbridge1$std$weak_ptr$##RUST_TYPE##$use_count(                 \
+      const std::weak_ptr<CXX_TYPE> &self) noexcept {                          \
+    return static_cast<std::size_t>(self.use_count());                         \
   }
 
 // Usize and isize are the same type as one of the below.
@@ -854,15 +1317,91 @@ static_assert(sizeof(std::string) <= kMaxExpectedWordsInString * sizeof(void *),
   MACRO(isize, rust::isize)                                                    \
   MACRO(string, std::string)
 
+#define FOR_EACH_RUST_VEC_SHARED_PTR(MACRO)                                    \
+  FOR_EACH_NUMERIC(MACRO)                                                      \
+  MACRO(bool, bool)                                                            \
+  MACRO(usize, rust::detail::usize_if_unique)                                  \
+  MACRO(isize, rust::detail::isize_if_unique)                                  \
+  MACRO(string, std::string)
+
 extern "C" {
+std::vector<bool> *cxxbridge1$std$vector$bool$new() noexcept {
+  return new std::vector<bool>();
+}
+std::size_t
+cxxbridge1$std$vector$bool$size(const std::vector<bool> &s) noexcept {
+  return s.size();
+}
+std::size_t
+cxxbridge1$std$vector$bool$capacity(const std::vector<bool> &s) noexcept {
+  return s.capacity();
+}
+bool cxxbridge1$std$vector$bool$get(const std::vector<bool> &s,
+                                    std::size_t pos) noexcept {
+  return s[pos];
+}
+void cxxbridge1$std$vector$bool$set(std::vector<bool> *s, std::size_t pos,
+                                    bool value) noexcept {
+  (*s)[pos] = value;
+}
+void cxxbridge1$std$vector$bool$reserve(std::vector<bool> *s,
+                                        std::size_t new_cap) noexcept {
+  s->reserve(new_cap);
+}
+void cxxbridge1$std$vector$bool$push_back(std::vector<bool> *s,
+                                          bool value) noexcept {
+  s->push_back(value);
+}
+bool cxxbridge1$std$vector$bool$pop_back(std::vector<bool> *s) noexcept {
+  bool value = s->back();
+  s->pop_back();
+  return value;
+}
+void cxxbridge1$unique_ptr$std$vector$bool$null(
+    std::unique_ptr<std::vector<bool>> *ptr) noexcept {
+  new (ptr) std::unique_ptr<std::vector<bool>>();
+}
+void cxxbridge1$unique_ptr$std$vector$bool$raw(
+    std::unique_ptr<std::vector<bool>> *ptr, std::vector<bool> *raw) noexcept {
+  new (ptr) std::unique_ptr<std::vector<bool>>(raw);
+}
+const std::vector<bool> *cxxbridge1$unique_ptr$std$vector$bool$get(
+    const std::unique_ptr<std::vector<bool>> &ptr) noexcept {
+  return ptr.get();
+}
+std::vector<bool> *cxxbridge1$unique_ptr$std$vector$bool$release(
+    std::unique_ptr<std::vector<bool>> &ptr) noexcept {
+  return ptr.release();
+}
+void cxxbridge1$unique_ptr$std$vector$bool$drop(
+    std::unique_ptr<std::vector<bool>> *ptr) noexcept {
+  ptr->~unique_ptr();
+}
+
 FOR_EACH_STD_VECTOR(STD_VECTOR_OPS)
 FOR_EACH_TRIVIAL_STD_VECTOR(STD_VECTOR_TRIVIAL_OPS)
+FOR_EACH_TRIVIAL_STD_VECTOR(UNIQUE_PTR_ARRAY_OPS)
+FOR_EACH_STD_VECTOR(STD_VECTOR_NESTED_OPS)
+STD_VECTOR_NESTED_OPS(bool, bool)
+FOR_EACH_SHARED_PTR(STD_VECTOR_SHARED_PTR_OPS)
+STD_VECTOR_OPS(std$unique_ptr$string, std::unique_ptr<std::string>)
+STD_VECTOR_OPS(u16string, std::u16string)
+STD_VECTOR_OPS(u32string, std::u32string)
+FOR_EACH_STD_VECTOR(STD_DEQUE_OPS)
+FOR_EACH_TRIVIAL_STD_VECTOR(STD_DEQUE_TRIVIAL_OPS)
+FOR_EACH_STD_VECTOR(STD_LIST_OPS)
+FOR_EACH_TRIVIAL_STD_VECTOR(STD_LIST_TRIVIAL_OPS)
+FOR_EACH_STD_VECTOR(STD_SET_OPS)
 FOR_EACH_RUST_VEC(RUST_VEC_EXTERNS)
+FOR_EACH_RUST_VEC(RUST_VEC_NESTED_EXTERNS)
+FOR_EACH_RUST_VEC_SHARED_PTR(RUST_VEC_SHARED_PTR_EXTERNS)
 FOR_EACH_SHARED_PTR(SHARED_PTR_OPS)
 } // extern "C"
 
 namespace rust {
 inline namespace cxxbridge1 {
 FOR_EACH_RUST_VEC(RUST_VEC_OPS)
+FOR_EACH_RUST_VEC(RUST_VEC_NESTED_OPS)
+FOR_EACH_RUST_VEC_SHARED_PTR(RUST_VEC_SHARED_PTR_OPS)
 } // namespace cxxbridge1
 } // namespace rust
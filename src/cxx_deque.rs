@@ -0,0 +1,486 @@
+//! Less used details of `CxxDeque` are exposed in this module. `CxxDeque`
+//! itself is exposed at the crate root.
+
+use crate::cxx_iter::CxxIter;
+use crate::extern_type::ExternType;
+use crate::kind::Trivial;
+use crate::string::CxxString;
+use crate::unique_ptr::UniquePtr;
+use core::ffi::c_void;
+use core::fmt::{self, Debug};
+use core::marker::{PhantomData, PhantomPinned};
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::pin::Pin;
+use core::ptr;
+
+/// Binding to C++ `std::deque<T, std::allocator<T>>`.
+///
+/// # Invariants
+///
+/// As an invariant of this API and the static analysis of the cxx::bridge
+/// macro, in Rust code we can never obtain a `CxxDeque` by value. Instead in
+/// Rust code we will only ever look at a deque behind a reference or smart
+/// pointer, as in `&CxxDeque<T>` or `UniquePtr<CxxDeque<T>>`.
+#[repr(C, packed)]
+pub struct CxxDeque<T> {
+    // A thing, because repr(C) structs are not allowed to consist exclusively
+    // of PhantomData fields.
+    _void: [c_void; 0],
+    // The conceptual deque elements to ensure that autotraits are propagated
+    // correctly, e.g. CxxDeque is UnwindSafe iff T is.
+    _elements: PhantomData<[T]>,
+    // Prevent unpin operation from Pin<&mut CxxDeque<T>> to &mut CxxDeque<T>.
+    _pinned: PhantomData<PhantomPinned>,
+}
+
+impl<T> CxxDeque<T>
+where
+    T: DequeElement,
+{
+    /// Constructs a new heap allocated deque, wrapped by UniquePtr.
+    ///
+    /// The C++ deque is default constructed.
+    pub fn new() -> UniquePtr<Self> {
+        unsafe { UniquePtr::from_raw(T::__deque_new()) }
+    }
+
+    /// Returns the number of elements in the deque.
+    ///
+    /// Matches the behavior of C++ [std::deque\<T\>::size][size].
+    ///
+    /// [size]: https://en.cppreference.com/w/cpp/container/deque/size
+    pub fn len(&self) -> usize {
+        T::__deque_size(self)
+    }
+
+    /// Returns true if the deque contains no elements.
+    ///
+    /// Matches the behavior of C++ [std::deque\<T\>::empty][empty].
+    ///
+    /// [empty]: https://en.cppreference.com/w/cpp/container/deque/empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to an element at the given position, or `None` if
+    /// out of bounds.
+    pub fn get(&self, pos: usize) -> Option<&T> {
+        if pos < self.len() {
+            let this = ptr::from_ref::<CxxDeque<T>>(self).cast_mut();
+            Some(unsafe { &*T::__get_unchecked(this, pos) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a pinned mutable reference to an element at the given position,
+    /// or `None` if out of bounds.
+    ///
+    /// This method cannot be named "get\_mut" due to a conflict with
+    /// `Pin::get_mut`.
+    #[doc(alias = "get_mut")]
+    pub fn index_mut(self: Pin<&mut Self>, pos: usize) -> Option<Pin<&mut T>> {
+        if pos < self.len() {
+            unsafe {
+                let ptr = T::__get_unchecked(self.get_unchecked_mut(), pos);
+                Some(Pin::new_unchecked(&mut *ptr))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over elements of type `&T`.
+    pub fn iter(&self) -> CxxIter<T> {
+        T::__deque_iter(self)
+    }
+
+    /// Removes all elements from the deque.
+    ///
+    /// Matches the behavior of C++ [std::deque\<T\>::clear][clear].
+    ///
+    /// [clear]: https://en.cppreference.com/w/cpp/container/deque/clear
+    pub fn clear(self: Pin<&mut Self>) {
+        unsafe { T::__deque_clear(self) }
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// Matches the behavior of C++ [std::deque\<T\>::push_back][push_back].
+    ///
+    /// [push_back]: https://en.cppreference.com/w/cpp/container/deque/push_back
+    pub fn push_back(self: Pin<&mut Self>, value: T)
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let mut value = ManuallyDrop::new(value);
+        unsafe {
+            // C++ calls move constructor followed by destructor on `value`.
+            T::__push_back(self, &mut value);
+        }
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// Matches the behavior of C++ [std::deque\<T\>::push_front][push_front].
+    ///
+    /// [push_front]: https://en.cppreference.com/w/cpp/container/deque/push_front
+    pub fn push_front(self: Pin<&mut Self>, value: T)
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let mut value = ManuallyDrop::new(value);
+        unsafe {
+            // C++ calls move constructor followed by destructor on `value`.
+            T::__push_front(self, &mut value);
+        }
+    }
+
+    /// Removes the last element from the deque and returns it, or `None` if
+    /// the deque is empty.
+    pub fn pop_back(self: Pin<&mut Self>) -> Option<T>
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            let mut value = MaybeUninit::uninit();
+            Some(unsafe {
+                T::__pop_back(self, &mut value);
+                value.assume_init()
+            })
+        }
+    }
+
+    /// Removes the first element from the deque and returns it, or `None` if
+    /// the deque is empty.
+    pub fn pop_front(self: Pin<&mut Self>) -> Option<T>
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            let mut value = MaybeUninit::uninit();
+            Some(unsafe {
+                T::__pop_front(self, &mut value);
+                value.assume_init()
+            })
+        }
+    }
+
+    /// Inserts an element at position `index` within the deque, shifting all
+    /// elements after it towards the back.
+    ///
+    /// Matches the behavior of C++ [std::deque\<T\>::insert][insert].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the deque's length.
+    ///
+    /// [insert]: https://en.cppreference.com/w/cpp/container/deque/insert
+    pub fn insert(self: Pin<&mut Self>, index: usize, value: T)
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            len,
+        );
+        let mut value = ManuallyDrop::new(value);
+        unsafe {
+            // C++ calls move constructor followed by destructor on `value`.
+            T::__insert(self, index, &mut value);
+        }
+    }
+
+    /// Removes and returns the element at position `index` within the deque,
+    /// or `None` if `index` is out of bounds.
+    ///
+    /// Matches the behavior of C++ [std::deque\<T\>::erase][erase].
+    ///
+    /// [erase]: https://en.cppreference.com/w/cpp/container/deque/erase
+    pub fn remove(self: Pin<&mut Self>, index: usize) -> Option<T>
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        if index < self.len() {
+            let mut value = MaybeUninit::uninit();
+            Some(unsafe {
+                T::__remove(self, index, &mut value);
+                value.assume_init()
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CxxDeque<T>
+where
+    T: DequeElement,
+{
+    type Item = &'a T;
+    type IntoIter = CxxIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Debug for CxxDeque<T>
+where
+    T: DequeElement + Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_list().entries(self).finish()
+    }
+}
+
+/// Trait bound for types which may be used as the `T` inside of a
+/// `CxxDeque<T>` in generic code.
+///
+/// This trait has no publicly callable or implementable methods. Implementing
+/// it outside of the CXX codebase requires using [explicit shim trait impls],
+/// adding the line `impl CxxDeque<MyType> {}` in the same `cxx::bridge` that
+/// defines `MyType`.
+///
+/// [explicit shim trait impls]: https://cxx.rs/extern-c++.html#explicit-shim-trait-impls
+pub unsafe trait DequeElement: Sized {
+    #[doc(hidden)]
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result;
+    #[doc(hidden)]
+    fn __deque_new() -> *mut CxxDeque<Self>;
+    #[doc(hidden)]
+    fn __deque_size(d: &CxxDeque<Self>) -> usize;
+    #[doc(hidden)]
+    fn __deque_iter(d: &CxxDeque<Self>) -> CxxIter<Self>;
+    #[doc(hidden)]
+    unsafe fn __deque_clear(d: Pin<&mut CxxDeque<Self>>);
+    #[doc(hidden)]
+    unsafe fn __get_unchecked(d: *mut CxxDeque<Self>, pos: usize) -> *mut Self;
+    #[doc(hidden)]
+    unsafe fn __push_back(d: Pin<&mut CxxDeque<Self>>, value: &mut ManuallyDrop<Self>) {
+        // Opaque C type deque elements do not get this method because they can
+        // never exist by value on the Rust side of the bridge.
+        let _ = d;
+        let _ = value;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __push_front(d: Pin<&mut CxxDeque<Self>>, value: &mut ManuallyDrop<Self>) {
+        let _ = d;
+        let _ = value;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __pop_back(d: Pin<&mut CxxDeque<Self>>, out: &mut MaybeUninit<Self>) {
+        let _ = d;
+        let _ = out;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __pop_front(d: Pin<&mut CxxDeque<Self>>, out: &mut MaybeUninit<Self>) {
+        let _ = d;
+        let _ = out;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __insert(d: Pin<&mut CxxDeque<Self>>, pos: usize, value: &mut ManuallyDrop<Self>) {
+        let _ = d;
+        let _ = pos;
+        let _ = value;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __remove(d: Pin<&mut CxxDeque<Self>>, pos: usize, out: &mut MaybeUninit<Self>) {
+        let _ = d;
+        let _ = pos;
+        let _ = out;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    fn __unique_ptr_null() -> MaybeUninit<*mut c_void>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_raw(raw: *mut CxxDeque<Self>) -> MaybeUninit<*mut c_void>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxDeque<Self>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_release(repr: MaybeUninit<*mut c_void>) -> *mut CxxDeque<Self>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_drop(repr: MaybeUninit<*mut c_void>);
+}
+
+macro_rules! deque_element_by_value_methods {
+    (opaque, $segment:expr, $ty:ty) => {};
+    (trivial, $segment:expr, $ty:ty) => {
+        unsafe fn __push_back(d: Pin<&mut CxxDeque<$ty>>, value: &mut ManuallyDrop<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$push_back")]
+                fn __push_back(_: Pin<&mut CxxDeque<$ty>>, _: &mut ManuallyDrop<$ty>);
+            }
+            unsafe { __push_back(d, value) }
+        }
+        unsafe fn __push_front(d: Pin<&mut CxxDeque<$ty>>, value: &mut ManuallyDrop<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$push_front")]
+                fn __push_front(_: Pin<&mut CxxDeque<$ty>>, _: &mut ManuallyDrop<$ty>);
+            }
+            unsafe { __push_front(d, value) }
+        }
+        unsafe fn __pop_back(d: Pin<&mut CxxDeque<$ty>>, out: &mut MaybeUninit<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$pop_back")]
+                fn __pop_back(_: Pin<&mut CxxDeque<$ty>>, _: &mut MaybeUninit<$ty>);
+            }
+            unsafe { __pop_back(d, out) }
+        }
+        unsafe fn __pop_front(d: Pin<&mut CxxDeque<$ty>>, out: &mut MaybeUninit<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$pop_front")]
+                fn __pop_front(_: Pin<&mut CxxDeque<$ty>>, _: &mut MaybeUninit<$ty>);
+            }
+            unsafe { __pop_front(d, out) }
+        }
+        unsafe fn __insert(d: Pin<&mut CxxDeque<$ty>>, pos: usize, value: &mut ManuallyDrop<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$insert")]
+                fn __insert(_: Pin<&mut CxxDeque<$ty>>, _: usize, _: &mut ManuallyDrop<$ty>);
+            }
+            unsafe { __insert(d, pos, value) }
+        }
+        unsafe fn __remove(d: Pin<&mut CxxDeque<$ty>>, pos: usize, out: &mut MaybeUninit<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$remove")]
+                fn __remove(_: Pin<&mut CxxDeque<$ty>>, _: usize, _: &mut MaybeUninit<$ty>);
+            }
+            unsafe { __remove(d, pos, out) }
+        }
+    };
+}
+
+macro_rules! impl_deque_element {
+    ($kind:ident, $segment:expr, $name:expr, $ty:ty) => {
+        const_assert_eq!(0, mem::size_of::<CxxDeque<$ty>>());
+        const_assert_eq!(1, mem::align_of::<CxxDeque<$ty>>());
+
+        unsafe impl DequeElement for $ty {
+            fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str($name)
+            }
+            fn __deque_new() -> *mut CxxDeque<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$new")]
+                    fn __deque_new() -> *mut CxxDeque<$ty>;
+                }
+                unsafe { __deque_new() }
+            }
+            fn __deque_size(d: &CxxDeque<$ty>) -> usize {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$size")]
+                    fn __deque_size(_: &CxxDeque<$ty>) -> usize;
+                }
+                unsafe { __deque_size(d) }
+            }
+            fn __deque_iter(d: &CxxDeque<$ty>) -> CxxIter<$ty> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$iter")]
+                    fn __deque_iter(_: &CxxDeque<$ty>) -> *mut c_void;
+                    #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$iter_next")]
+                    fn __deque_iter_next(_: *mut c_void) -> *const c_void;
+                    #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$iter_drop")]
+                    fn __deque_iter_drop(_: *mut c_void);
+                }
+                unsafe { CxxIter::__new(__deque_iter(d), __deque_iter_next, __deque_iter_drop) }
+            }
+            unsafe fn __deque_clear(d: Pin<&mut CxxDeque<$ty>>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$clear")]
+                    fn __deque_clear(_: Pin<&mut CxxDeque<$ty>>);
+                }
+                unsafe { __deque_clear(d) }
+            }
+            unsafe fn __get_unchecked(d: *mut CxxDeque<$ty>, pos: usize) -> *mut $ty {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$deque$", $segment, "$get_unchecked")]
+                    fn __get_unchecked(_: *mut CxxDeque<$ty>, _: usize) -> *mut $ty;
+                }
+                unsafe { __get_unchecked(d, pos) }
+            }
+            deque_element_by_value_methods!($kind, $segment, $ty);
+            fn __unique_ptr_null() -> MaybeUninit<*mut c_void> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$deque$", $segment, "$null")]
+                    fn __unique_ptr_null(this: *mut MaybeUninit<*mut c_void>);
+                }
+                let mut repr = MaybeUninit::uninit();
+                unsafe { __unique_ptr_null(&mut repr) }
+                repr
+            }
+            unsafe fn __unique_ptr_raw(raw: *mut CxxDeque<Self>) -> MaybeUninit<*mut c_void> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$deque$", $segment, "$raw")]
+                    fn __unique_ptr_raw(
+                        this: *mut MaybeUninit<*mut c_void>,
+                        raw: *mut CxxDeque<$ty>,
+                    );
+                }
+                let mut repr = MaybeUninit::uninit();
+                unsafe { __unique_ptr_raw(&mut repr, raw) }
+                repr
+            }
+            unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxDeque<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$deque$", $segment, "$get")]
+                    fn __unique_ptr_get(
+                        this: *const MaybeUninit<*mut c_void>,
+                    ) -> *const CxxDeque<$ty>;
+                }
+                unsafe { __unique_ptr_get(&repr) }
+            }
+            unsafe fn __unique_ptr_release(
+                mut repr: MaybeUninit<*mut c_void>,
+            ) -> *mut CxxDeque<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$deque$", $segment, "$release")]
+                    fn __unique_ptr_release(
+                        this: *mut MaybeUninit<*mut c_void>,
+                    ) -> *mut CxxDeque<$ty>;
+                }
+                unsafe { __unique_ptr_release(&mut repr) }
+            }
+            unsafe fn __unique_ptr_drop(mut repr: MaybeUninit<*mut c_void>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$deque$", $segment, "$drop")]
+                    fn __unique_ptr_drop(this: *mut MaybeUninit<*mut c_void>);
+                }
+                unsafe { __unique_ptr_drop(&mut repr) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_deque_element_for_primitive {
+    ($ty:ident) => {
+        impl_deque_element!(trivial, stringify!($ty), stringify!($ty), $ty);
+    };
+}
+
+impl_deque_element_for_primitive!(u8);
+impl_deque_element_for_primitive!(u16);
+impl_deque_element_for_primitive!(u32);
+impl_deque_element_for_primitive!(u64);
+impl_deque_element_for_primitive!(usize);
+impl_deque_element_for_primitive!(i8);
+impl_deque_element_for_primitive!(i16);
+impl_deque_element_for_primitive!(i32);
+impl_deque_element_for_primitive!(i64);
+impl_deque_element_for_primitive!(isize);
+impl_deque_element_for_primitive!(f32);
+impl_deque_element_for_primitive!(f64);
+
+impl_deque_element!(opaque, "string", "CxxString", CxxString);
@@ -0,0 +1,58 @@
+use core::ffi::c_void;
+use core::fmt::{self, Debug};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Iterator over the elements of a C++ range by shared reference.
+///
+/// This is the iterator produced by `&Container` for an opaque C++ type whose
+/// bridge declares `impl IntoIterator for &Container { type Item = &T; }`. It
+/// holds onto the C++ `begin()` and `end()` iterators of the container and
+/// steps the former on every call to `next`.
+///
+/// The iterator element type is `&'a T`.
+pub struct CxxIter<'a, T> {
+    repr: *mut c_void,
+    next: unsafe extern "C" fn(*mut c_void) -> *const c_void,
+    drop: unsafe extern "C" fn(*mut c_void),
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> CxxIter<'a, T> {
+    #[doc(hidden)]
+    pub unsafe fn __new(
+        repr: *mut c_void,
+        next: unsafe extern "C" fn(*mut c_void) -> *const c_void,
+        drop: unsafe extern "C" fn(*mut c_void),
+    ) -> Self {
+        CxxIter {
+            repr,
+            next,
+            drop,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for CxxIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = unsafe { (self.next)(self.repr) };
+        unsafe { next.cast::<T>().as_ref() }
+    }
+}
+
+impl<'a, T> FusedIterator for CxxIter<'a, T> {}
+
+impl<'a, T> Drop for CxxIter<'a, T> {
+    fn drop(&mut self) {
+        unsafe { (self.drop)(self.repr) }
+    }
+}
+
+impl<'a, T> Debug for CxxIter<'a, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_struct("CxxIter").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,452 @@
+//! Less used details of `CxxList` are exposed in this module. `CxxList`
+//! itself is exposed at the crate root.
+
+use crate::cxx_iter::CxxIter;
+use crate::extern_type::ExternType;
+use crate::kind::Trivial;
+use crate::string::CxxString;
+use crate::unique_ptr::UniquePtr;
+use core::ffi::c_void;
+use core::fmt::{self, Debug};
+use core::marker::{PhantomData, PhantomPinned};
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::pin::Pin;
+
+/// Binding to C++ `std::list<T, std::allocator<T>>`.
+///
+/// # Invariants
+///
+/// As an invariant of this API and the static analysis of the cxx::bridge
+/// macro, in Rust code we can never obtain a `CxxList` by value. Instead in
+/// Rust code we will only ever look at a list behind a reference or smart
+/// pointer, as in `&CxxList<T>` or `UniquePtr<CxxList<T>>`.
+#[repr(C, packed)]
+pub struct CxxList<T> {
+    // A thing, because repr(C) structs are not allowed to consist exclusively
+    // of PhantomData fields.
+    _voil: [c_void; 0],
+    // The conceptual list elements to ensure that autotraits are propagated
+    // correctly, e.g. CxxList is UnwindSafe iff T is.
+    _elements: PhantomData<[T]>,
+    // Prevent unpin operation from Pin<&mut CxxList<T>> to &mut CxxList<T>.
+    _pinnel: PhantomData<PhantomPinned>,
+}
+
+impl<T> CxxList<T>
+where
+    T: ListElement,
+{
+    /// Constructs a new heap allocated list, wrapped by UniquePtr.
+    ///
+    /// The C++ list is default constructed.
+    pub fn new() -> UniquePtr<Self> {
+        unsafe { UniquePtr::from_raw(T::__list_new()) }
+    }
+
+    /// Returns the number of elements in the list.
+    ///
+    /// Matches the behavior of C++ [std::list\<T\>::size][size].
+    ///
+    /// [size]: https://en.cppreference.com/w/cpp/container/list/size
+    pub fn len(&self) -> usize {
+        T::__list_size(self)
+    }
+
+    /// Returns true if the list contains no elements.
+    ///
+    /// Matches the behavior of C++ [std::list\<T\>::empty][empty].
+    ///
+    /// [empty]: https://en.cppreference.com/w/cpp/container/list/empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over elements of type `&T`.
+    pub fn iter(&self) -> CxxIter<T> {
+        T::__list_iter(self)
+    }
+
+    /// Removes all elements from the list.
+    ///
+    /// Matches the behavior of C++ [std::list\<T\>::clear][clear].
+    ///
+    /// [clear]: https://en.cppreference.com/w/cpp/container/list/clear
+    pub fn clear(self: Pin<&mut Self>) {
+        unsafe { T::__list_clear(self) }
+    }
+
+    /// Appends an element to the back of the list.
+    ///
+    /// Matches the behavior of C++ [std::list\<T\>::push_back][push_back].
+    ///
+    /// [push_back]: https://en.cppreference.com/w/cpp/container/list/push_back
+    pub fn push_back(self: Pin<&mut Self>, value: T)
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let mut value = ManuallyDrop::new(value);
+        unsafe {
+            // C++ calls move constructor followed by destructor on `value`.
+            T::__push_back(self, &mut value);
+        }
+    }
+
+    /// Prepends an element to the front of the list.
+    ///
+    /// Matches the behavior of C++ [std::list\<T\>::push_front][push_front].
+    ///
+    /// [push_front]: https://en.cppreference.com/w/cpp/container/list/push_front
+    pub fn push_front(self: Pin<&mut Self>, value: T)
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let mut value = ManuallyDrop::new(value);
+        unsafe {
+            // C++ calls move constructor followed by destructor on `value`.
+            T::__push_front(self, &mut value);
+        }
+    }
+
+    /// Removes the last element from the list and returns it, or `None` if
+    /// the list is empty.
+    pub fn pop_back(self: Pin<&mut Self>) -> Option<T>
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            let mut value = MaybeUninit::uninit();
+            Some(unsafe {
+                T::__pop_back(self, &mut value);
+                value.assume_init()
+            })
+        }
+    }
+
+    /// Removes the first element from the list and returns it, or `None` if
+    /// the list is empty.
+    pub fn pop_front(self: Pin<&mut Self>) -> Option<T>
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            let mut value = MaybeUninit::uninit();
+            Some(unsafe {
+                T::__pop_front(self, &mut value);
+                value.assume_init()
+            })
+        }
+    }
+
+    /// Inserts an element at position `index` within the list, shifting all
+    /// elements after it towards the back.
+    ///
+    /// This walks the list from the front, so it takes time linear in `index`.
+    ///
+    /// Matches the behavior of C++ [std::list\<T\>::insert][insert].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the list's length.
+    ///
+    /// [insert]: https://en.cppreference.com/w/cpp/container/list/insert
+    pub fn insert(self: Pin<&mut Self>, index: usize, value: T)
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            len,
+        );
+        let mut value = ManuallyDrop::new(value);
+        unsafe {
+            // C++ calls move constructor followed by destructor on `value`.
+            T::__insert(self, index, &mut value);
+        }
+    }
+
+    /// Removes and returns the element at position `index` within the list,
+    /// or `None` if `index` is out of bounds.
+    ///
+    /// This walks the list from the front, so it takes time linear in `index`.
+    ///
+    /// Matches the behavior of C++ [std::list\<T\>::erase][erase].
+    ///
+    /// [erase]: https://en.cppreference.com/w/cpp/container/list/erase
+    pub fn remove(self: Pin<&mut Self>, index: usize) -> Option<T>
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        if index < self.len() {
+            let mut value = MaybeUninit::uninit();
+            Some(unsafe {
+                T::__remove(self, index, &mut value);
+                value.assume_init()
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CxxList<T>
+where
+    T: ListElement,
+{
+    type Item = &'a T;
+    type IntoIter = CxxIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Debug for CxxList<T>
+where
+    T: ListElement + Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_list().entries(self).finish()
+    }
+}
+
+/// Trait bound for types which may be used as the `T` inside of a
+/// `CxxList<T>` in generic code.
+///
+/// This trait has no publicly callable or implementable methods. Implementing
+/// it outside of the CXX codebase requires using [explicit shim trait impls],
+/// adding the line `impl CxxList<MyType> {}` in the same `cxx::bridge` that
+/// defines `MyType`.
+///
+/// [explicit shim trait impls]: https://cxx.rs/extern-c++.html#explicit-shim-trait-impls
+pub unsafe trait ListElement: Sized {
+    #[doc(hidden)]
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result;
+    #[doc(hidden)]
+    fn __list_new() -> *mut CxxList<Self>;
+    #[doc(hidden)]
+    fn __list_size(l: &CxxList<Self>) -> usize;
+    #[doc(hidden)]
+    fn __list_iter(l: &CxxList<Self>) -> CxxIter<Self>;
+    #[doc(hidden)]
+    unsafe fn __list_clear(l: Pin<&mut CxxList<Self>>);
+    #[doc(hidden)]
+    unsafe fn __push_back(l: Pin<&mut CxxList<Self>>, value: &mut ManuallyDrop<Self>) {
+        // Opaque C type list elements do not get this method because they can
+        // never exist by value on the Rust side of the bridge.
+        let _ = l;
+        let _ = value;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __push_front(l: Pin<&mut CxxList<Self>>, value: &mut ManuallyDrop<Self>) {
+        let _ = l;
+        let _ = value;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __pop_back(l: Pin<&mut CxxList<Self>>, out: &mut MaybeUninit<Self>) {
+        let _ = l;
+        let _ = out;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __pop_front(l: Pin<&mut CxxList<Self>>, out: &mut MaybeUninit<Self>) {
+        let _ = l;
+        let _ = out;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __insert(l: Pin<&mut CxxList<Self>>, pos: usize, value: &mut ManuallyDrop<Self>) {
+        let _ = l;
+        let _ = pos;
+        let _ = value;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __remove(l: Pin<&mut CxxList<Self>>, pos: usize, out: &mut MaybeUninit<Self>) {
+        let _ = l;
+        let _ = pos;
+        let _ = out;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    fn __unique_ptr_null() -> MaybeUninit<*mut c_void>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_raw(raw: *mut CxxList<Self>) -> MaybeUninit<*mut c_void>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxList<Self>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_release(repr: MaybeUninit<*mut c_void>) -> *mut CxxList<Self>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_drop(repr: MaybeUninit<*mut c_void>);
+}
+
+macro_rules! list_element_by_value_methods {
+    (opaque, $segment:expr, $ty:ty) => {};
+    (trivial, $segment:expr, $ty:ty) => {
+        unsafe fn __push_back(l: Pin<&mut CxxList<$ty>>, value: &mut ManuallyDrop<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$list$", $segment, "$push_back")]
+                fn __push_back(_: Pin<&mut CxxList<$ty>>, _: &mut ManuallyDrop<$ty>);
+            }
+            unsafe { __push_back(l, value) }
+        }
+        unsafe fn __push_front(l: Pin<&mut CxxList<$ty>>, value: &mut ManuallyDrop<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$list$", $segment, "$push_front")]
+                fn __push_front(_: Pin<&mut CxxList<$ty>>, _: &mut ManuallyDrop<$ty>);
+            }
+            unsafe { __push_front(l, value) }
+        }
+        unsafe fn __pop_back(l: Pin<&mut CxxList<$ty>>, out: &mut MaybeUninit<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$list$", $segment, "$pop_back")]
+                fn __pop_back(_: Pin<&mut CxxList<$ty>>, _: &mut MaybeUninit<$ty>);
+            }
+            unsafe { __pop_back(l, out) }
+        }
+        unsafe fn __pop_front(l: Pin<&mut CxxList<$ty>>, out: &mut MaybeUninit<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$list$", $segment, "$pop_front")]
+                fn __pop_front(_: Pin<&mut CxxList<$ty>>, _: &mut MaybeUninit<$ty>);
+            }
+            unsafe { __pop_front(l, out) }
+        }
+        unsafe fn __insert(l: Pin<&mut CxxList<$ty>>, pos: usize, value: &mut ManuallyDrop<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$list$", $segment, "$insert")]
+                fn __insert(_: Pin<&mut CxxList<$ty>>, _: usize, _: &mut ManuallyDrop<$ty>);
+            }
+            unsafe { __insert(l, pos, value) }
+        }
+        unsafe fn __remove(l: Pin<&mut CxxList<$ty>>, pos: usize, out: &mut MaybeUninit<$ty>) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$std$list$", $segment, "$remove")]
+                fn __remove(_: Pin<&mut CxxList<$ty>>, _: usize, _: &mut MaybeUninit<$ty>);
+            }
+            unsafe { __remove(l, pos, out) }
+        }
+    };
+}
+
+macro_rules! impl_list_element {
+    ($kind:ident, $segment:expr, $name:expr, $ty:ty) => {
+        const_assert_eq!(0, mem::size_of::<CxxList<$ty>>());
+        const_assert_eq!(1, mem::align_of::<CxxList<$ty>>());
+
+        unsafe impl ListElement for $ty {
+            fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str($name)
+            }
+            fn __list_new() -> *mut CxxList<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$list$", $segment, "$new")]
+                    fn __list_new() -> *mut CxxList<$ty>;
+                }
+                unsafe { __list_new() }
+            }
+            fn __list_size(l: &CxxList<$ty>) -> usize {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$list$", $segment, "$size")]
+                    fn __list_size(_: &CxxList<$ty>) -> usize;
+                }
+                unsafe { __list_size(l) }
+            }
+            fn __list_iter(l: &CxxList<$ty>) -> CxxIter<$ty> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$list$", $segment, "$iter")]
+                    fn __list_iter(_: &CxxList<$ty>) -> *mut c_void;
+                    #[link_name = concat!("cxxbridge1$std$list$", $segment, "$iter_next")]
+                    fn __list_iter_next(_: *mut c_void) -> *const c_void;
+                    #[link_name = concat!("cxxbridge1$std$list$", $segment, "$iter_drop")]
+                    fn __list_iter_drop(_: *mut c_void);
+                }
+                unsafe { CxxIter::__new(__list_iter(l), __list_iter_next, __list_iter_drop) }
+            }
+            unsafe fn __list_clear(l: Pin<&mut CxxList<$ty>>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$list$", $segment, "$clear")]
+                    fn __list_clear(_: Pin<&mut CxxList<$ty>>);
+                }
+                unsafe { __list_clear(l) }
+            }
+            list_element_by_value_methods!($kind, $segment, $ty);
+            fn __unique_ptr_null() -> MaybeUninit<*mut c_void> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$list$", $segment, "$null")]
+                    fn __unique_ptr_null(this: *mut MaybeUninit<*mut c_void>);
+                }
+                let mut repr = MaybeUninit::uninit();
+                unsafe { __unique_ptr_null(&mut repr) }
+                repr
+            }
+            unsafe fn __unique_ptr_raw(raw: *mut CxxList<Self>) -> MaybeUninit<*mut c_void> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$list$", $segment, "$raw")]
+                    fn __unique_ptr_raw(
+                        this: *mut MaybeUninit<*mut c_void>,
+                        raw: *mut CxxList<$ty>,
+                    );
+                }
+                let mut repr = MaybeUninit::uninit();
+                unsafe { __unique_ptr_raw(&mut repr, raw) }
+                repr
+            }
+            unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxList<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$list$", $segment, "$get")]
+                    fn __unique_ptr_get(
+                        this: *const MaybeUninit<*mut c_void>,
+                    ) -> *const CxxList<$ty>;
+                }
+                unsafe { __unique_ptr_get(&repr) }
+            }
+            unsafe fn __unique_ptr_release(
+                mut repr: MaybeUninit<*mut c_void>,
+            ) -> *mut CxxList<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$list$", $segment, "$release")]
+                    fn __unique_ptr_release(
+                        this: *mut MaybeUninit<*mut c_void>,
+                    ) -> *mut CxxList<$ty>;
+                }
+                unsafe { __unique_ptr_release(&mut repr) }
+            }
+            unsafe fn __unique_ptr_drop(mut repr: MaybeUninit<*mut c_void>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$list$", $segment, "$drop")]
+                    fn __unique_ptr_drop(this: *mut MaybeUninit<*mut c_void>);
+                }
+                unsafe { __unique_ptr_drop(&mut repr) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_list_element_for_primitive {
+    ($ty:ident) => {
+        impl_list_element!(trivial, stringify!($ty), stringify!($ty), $ty);
+    };
+}
+
+impl_list_element_for_primitive!(u8);
+impl_list_element_for_primitive!(u16);
+impl_list_element_for_primitive!(u32);
+impl_list_element_for_primitive!(u64);
+impl_list_element_for_primitive!(usize);
+impl_list_element_for_primitive!(i8);
+impl_list_element_for_primitive!(i16);
+impl_list_element_for_primitive!(i32);
+impl_list_element_for_primitive!(i64);
+impl_list_element_for_primitive!(isize);
+impl_list_element_for_primitive!(f32);
+impl_list_element_for_primitive!(f64);
+
+impl_list_element!(opaque, "string", "CxxString", CxxString);
@@ -0,0 +1,303 @@
+//! Less used details of `CxxSet` are exposed in this module. `CxxSet` itself
+//! is exposed at the crate root.
+
+use crate::cxx_iter::CxxIter;
+use crate::string::CxxString;
+use crate::unique_ptr::UniquePtr;
+use core::ffi::c_void;
+use core::fmt::{self, Debug};
+use core::marker::{PhantomData, PhantomPinned};
+use core::mem::{self, MaybeUninit};
+use core::pin::Pin;
+
+/// Binding to C++ `std::set<T, std::less<T>, std::allocator<T>>`.
+///
+/// # Invariants
+///
+/// As an invariant of this API and the static analysis of the cxx::bridge
+/// macro, in Rust code we can never obtain a `CxxSet` by value. Instead in
+/// Rust code we will only ever look at a set behind a reference or smart
+/// pointer, as in `&CxxSet<T>` or `UniquePtr<CxxSet<T>>`.
+#[repr(C, packed)]
+pub struct CxxSet<T> {
+    // A thing, because repr(C) structs are not allowed to consist exclusively
+    // of PhantomData fields.
+    _void: [c_void; 0],
+    // The conceptual set elements to ensure that autotraits are propagated
+    // correctly, e.g. CxxSet is UnwindSafe iff T is.
+    _elements: PhantomData<[T]>,
+    // Prevent unpin operation from Pin<&mut CxxSet<T>> to &mut CxxSet<T>.
+    _pinned: PhantomData<PhantomPinned>,
+}
+
+impl<T> CxxSet<T>
+where
+    T: SetElement,
+{
+    /// Constructs a new heap allocated set, wrapped by UniquePtr.
+    ///
+    /// The C++ set is default constructed.
+    pub fn new() -> UniquePtr<Self> {
+        unsafe { UniquePtr::from_raw(T::__set_new()) }
+    }
+
+    /// Returns the number of elements in the set.
+    ///
+    /// Matches the behavior of C++ [std::set\<T\>::size][size].
+    ///
+    /// [size]: https://en.cppreference.com/w/cpp/container/set/size
+    pub fn len(&self) -> usize {
+        T::__set_size(self)
+    }
+
+    /// Returns true if the set contains no elements.
+    ///
+    /// Matches the behavior of C++ [std::set\<T\>::empty][empty].
+    ///
+    /// [empty]: https://en.cppreference.com/w/cpp/container/set/empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the set contains an element equivalent to `value`.
+    ///
+    /// Matches the behavior of C++ [std::set\<T\>::find][find] compared
+    /// against `end()`.
+    ///
+    /// [find]: https://en.cppreference.com/w/cpp/container/set/find
+    pub fn contains(&self, value: &T) -> bool {
+        T::__set_contains(self, value)
+    }
+
+    /// Returns an iterator over elements of type `&T`, in ascending order.
+    pub fn iter(&self) -> CxxIter<T> {
+        T::__set_iter(self)
+    }
+
+    /// Inserts a copy of `value` into the set.
+    ///
+    /// Returns whether the value was newly inserted, i.e. false if the set
+    /// already contained an equivalent element.
+    ///
+    /// Matches the behavior of C++ [std::set\<T\>::insert][insert].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` is not copy-constructible in C++.
+    ///
+    /// [insert]: https://en.cppreference.com/w/cpp/container/set/insert
+    pub fn insert(self: Pin<&mut Self>, value: &T) -> bool {
+        unsafe { T::__set_insert(self, value) }
+    }
+
+    /// Removes the element equivalent to `value` from the set.
+    ///
+    /// Returns whether such an element was present.
+    ///
+    /// Matches the behavior of C++ [std::set\<T\>::erase][erase].
+    ///
+    /// [erase]: https://en.cppreference.com/w/cpp/container/set/erase
+    pub fn remove(self: Pin<&mut Self>, value: &T) -> bool {
+        unsafe { T::__set_remove(self, value) }
+    }
+
+    /// Removes all elements from the set.
+    ///
+    /// Matches the behavior of C++ [std::set\<T\>::clear][clear].
+    ///
+    /// [clear]: https://en.cppreference.com/w/cpp/container/set/clear
+    pub fn clear(self: Pin<&mut Self>) {
+        unsafe { T::__set_clear(self) }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CxxSet<T>
+where
+    T: SetElement,
+{
+    type Item = &'a T;
+    type IntoIter = CxxIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> Debug for CxxSet<T>
+where
+    T: SetElement + Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_set().entries(self).finish()
+    }
+}
+
+/// Trait bound for types which may be used as the `T` inside of a
+/// `CxxSet<T>` in generic code.
+///
+/// This trait has no publicly callable or implementable methods. Implementing
+/// it outside of the CXX codebase requires using [explicit shim trait impls],
+/// adding the line `impl CxxSet<MyType> {}` in the same `cxx::bridge` that
+/// defines `MyType`.
+///
+/// [explicit shim trait impls]: https://cxx.rs/extern-c++.html#explicit-shim-trait-impls
+pub unsafe trait SetElement: Sized {
+    #[doc(hidden)]
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result;
+    #[doc(hidden)]
+    fn __set_new() -> *mut CxxSet<Self>;
+    #[doc(hidden)]
+    fn __set_size(s: &CxxSet<Self>) -> usize;
+    #[doc(hidden)]
+    fn __set_iter(s: &CxxSet<Self>) -> CxxIter<Self>;
+    #[doc(hidden)]
+    fn __set_contains(s: &CxxSet<Self>, value: &Self) -> bool;
+    #[doc(hidden)]
+    unsafe fn __set_insert(s: Pin<&mut CxxSet<Self>>, value: &Self) -> bool;
+    #[doc(hidden)]
+    unsafe fn __set_remove(s: Pin<&mut CxxSet<Self>>, value: &Self) -> bool;
+    #[doc(hidden)]
+    unsafe fn __set_clear(s: Pin<&mut CxxSet<Self>>);
+    #[doc(hidden)]
+    fn __unique_ptr_null() -> MaybeUninit<*mut c_void>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_raw(raw: *mut CxxSet<Self>) -> MaybeUninit<*mut c_void>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxSet<Self>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_release(repr: MaybeUninit<*mut c_void>) -> *mut CxxSet<Self>;
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_drop(repr: MaybeUninit<*mut c_void>);
+}
+
+macro_rules! impl_set_element {
+    ($segment:expr, $name:expr, $ty:ty) => {
+        const_assert_eq!(0, mem::size_of::<CxxSet<$ty>>());
+        const_assert_eq!(1, mem::align_of::<CxxSet<$ty>>());
+
+        unsafe impl SetElement for $ty {
+            fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str($name)
+            }
+            fn __set_new() -> *mut CxxSet<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$new")]
+                    fn __set_new() -> *mut CxxSet<$ty>;
+                }
+                unsafe { __set_new() }
+            }
+            fn __set_size(s: &CxxSet<$ty>) -> usize {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$size")]
+                    fn __set_size(_: &CxxSet<$ty>) -> usize;
+                }
+                unsafe { __set_size(s) }
+            }
+            fn __set_iter(s: &CxxSet<$ty>) -> CxxIter<$ty> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$iter")]
+                    fn __set_iter(_: &CxxSet<$ty>) -> *mut c_void;
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$iter_next")]
+                    fn __set_iter_next(_: *mut c_void) -> *const c_void;
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$iter_drop")]
+                    fn __set_iter_drop(_: *mut c_void);
+                }
+                unsafe { CxxIter::__new(__set_iter(s), __set_iter_next, __set_iter_drop) }
+            }
+            fn __set_contains(s: &CxxSet<$ty>, value: &$ty) -> bool {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$contains")]
+                    fn __set_contains(_: &CxxSet<$ty>, _: &$ty) -> bool;
+                }
+                unsafe { __set_contains(s, value) }
+            }
+            unsafe fn __set_insert(s: Pin<&mut CxxSet<$ty>>, value: &$ty) -> bool {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$insert")]
+                    fn __set_insert(_: Pin<&mut CxxSet<$ty>>, _: &$ty) -> bool;
+                }
+                unsafe { __set_insert(s, value) }
+            }
+            unsafe fn __set_remove(s: Pin<&mut CxxSet<$ty>>, value: &$ty) -> bool {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$remove")]
+                    fn __set_remove(_: Pin<&mut CxxSet<$ty>>, _: &$ty) -> bool;
+                }
+                unsafe { __set_remove(s, value) }
+            }
+            unsafe fn __set_clear(s: Pin<&mut CxxSet<$ty>>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$set$", $segment, "$clear")]
+                    fn __set_clear(_: Pin<&mut CxxSet<$ty>>);
+                }
+                unsafe { __set_clear(s) }
+            }
+            fn __unique_ptr_null() -> MaybeUninit<*mut c_void> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$set$", $segment, "$null")]
+                    fn __unique_ptr_null(this: *mut MaybeUninit<*mut c_void>);
+                }
+                let mut repr = MaybeUninit::uninit();
+                unsafe { __unique_ptr_null(&mut repr) }
+                repr
+            }
+            unsafe fn __unique_ptr_raw(raw: *mut CxxSet<Self>) -> MaybeUninit<*mut c_void> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$set$", $segment, "$raw")]
+                    fn __unique_ptr_raw(this: *mut MaybeUninit<*mut c_void>, raw: *mut CxxSet<$ty>);
+                }
+                let mut repr = MaybeUninit::uninit();
+                unsafe { __unique_ptr_raw(&mut repr, raw) }
+                repr
+            }
+            unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxSet<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$set$", $segment, "$get")]
+                    fn __unique_ptr_get(
+                        this: *const MaybeUninit<*mut c_void>,
+                    ) -> *const CxxSet<$ty>;
+                }
+                unsafe { __unique_ptr_get(&repr) }
+            }
+            unsafe fn __unique_ptr_release(
+                mut repr: MaybeUninit<*mut c_void>,
+            ) -> *mut CxxSet<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$set$", $segment, "$release")]
+                    fn __unique_ptr_release(
+                        this: *mut MaybeUninit<*mut c_void>,
+                    ) -> *mut CxxSet<$ty>;
+                }
+                unsafe { __unique_ptr_release(&mut repr) }
+            }
+            unsafe fn __unique_ptr_drop(mut repr: MaybeUninit<*mut c_void>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$set$", $segment, "$drop")]
+                    fn __unique_ptr_drop(this: *mut MaybeUninit<*mut c_void>);
+                }
+                unsafe { __unique_ptr_drop(&mut repr) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_set_element_for_primitive {
+    ($ty:ident) => {
+        impl_set_element!(stringify!($ty), stringify!($ty), $ty);
+    };
+}
+
+impl_set_element_for_primitive!(u8);
+impl_set_element_for_primitive!(u16);
+impl_set_element_for_primitive!(u32);
+impl_set_element_for_primitive!(u64);
+impl_set_element_for_primitive!(usize);
+impl_set_element_for_primitive!(i8);
+impl_set_element_for_primitive!(i16);
+impl_set_element_for_primitive!(i32);
+impl_set_element_for_primitive!(i64);
+impl_set_element_for_primitive!(isize);
+impl_set_element_for_primitive!(f32);
+impl_set_element_for_primitive!(f64);
+
+impl_set_element!("string", "CxxString", CxxString);
@@ -1,16 +1,19 @@
 use crate::actually_private::Private;
 use crate::lossy;
+use crate::unique_ptr::UniquePtr;
 #[cfg(feature = "alloc")]
 use alloc::borrow::Cow;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
 use core::cell::UnsafeCell;
+use core::char::REPLACEMENT_CHARACTER;
 use core::cmp::Ordering;
 use core::ffi::{CStr, c_char};
 use core::fmt::{self, Debug, Display};
 use core::hash::{Hash, Hasher};
 use core::marker::{PhantomData, PhantomPinned};
 use core::mem::MaybeUninit;
+use core::ops::{Bound, RangeBounds};
 use core::panic::RefUnwindSafe;
 use core::pin::Pin;
 use core::slice;
@@ -31,8 +34,31 @@ unsafe extern "C" {
     fn string_reserve_total(this: Pin<&mut CxxString>, new_cap: usize);
     #[link_name = "cxxbridge1$cxx_string$push"]
     fn string_push(this: Pin<&mut CxxString>, ptr: *const u8, len: usize);
+    #[link_name = "cxxbridge1$cxx_string$new"]
+    fn string_new(ptr: *const u8, len: usize) -> *mut CxxString;
+    #[link_name = "cxxbridge1$cxx_string$capacity"]
+    fn string_capacity(this: &CxxString) -> usize;
+    #[link_name = "cxxbridge1$cxx_string$shrink_to_fit"]
+    fn string_shrink_to_fit(this: Pin<&mut CxxString>);
+    #[link_name = "cxxbridge1$cxx_string$replace"]
+    fn string_replace(
+        this: Pin<&mut CxxString>,
+        pos: usize,
+        count: usize,
+        ptr: *const u8,
+        len: usize,
+    );
+    #[link_name = "cxxbridge1$cxx_string$resize"]
+    fn string_resize(this: Pin<&mut CxxString>, count: usize, ch: c_char);
+    #[link_name = "cxxbridge1$cxx_string$find"]
+    fn string_find(this: &CxxString, ptr: *const u8, len: usize) -> usize;
+    #[link_name = "cxxbridge1$cxx_string$rfind"]
+    fn string_rfind(this: &CxxString, ptr: *const u8, len: usize) -> usize;
 }
 
+// std::string::npos
+const NPOS: usize = usize::MAX;
+
 /// Binding to C++ `std::string`.
 ///
 /// # Invariants
@@ -103,6 +129,16 @@ impl CxxString {
         unreachable!()
     }
 
+    /// Constructs a new heap allocated C++ string holding a copy of `value`,
+    /// wrapped by UniquePtr.
+    ///
+    /// Unlike [`let_cxx_string!`], the resulting string is not tied to the
+    /// current stack frame.
+    pub fn new_unique(value: impl AsRef<[u8]>) -> UniquePtr<Self> {
+        let value = value.as_ref();
+        unsafe { UniquePtr::from_raw(string_new(value.as_ptr(), value.len())) }
+    }
+
     /// Returns the length of the string in bytes.
     ///
     /// Matches the behavior of C++ [std::string::size][size].
@@ -145,6 +181,43 @@ impl CxxString {
         unsafe { string_data(self) }
     }
 
+    /// Returns the number of bytes the string can hold without reallocating.
+    ///
+    /// Matches the behavior of C++ [std::string::capacity][capacity].
+    ///
+    /// [capacity]: https://en.cppreference.com/w/cpp/string/basic_string/capacity
+    pub fn capacity(&self) -> usize {
+        unsafe { string_capacity(self) }
+    }
+
+    /// Returns the byte index of the first occurrence of `needle` in this
+    /// string, or `None` if there is none.
+    ///
+    /// Matches the behavior of C++ [std::string::find][find].
+    ///
+    /// [find]: https://en.cppreference.com/w/cpp/string/basic_string/find
+    pub fn find(&self, needle: impl AsRef<[u8]>) -> Option<usize> {
+        let needle = needle.as_ref();
+        match unsafe { string_find(self, needle.as_ptr(), needle.len()) } {
+            NPOS => None,
+            pos => Some(pos),
+        }
+    }
+
+    /// Returns the byte index of the last occurrence of `needle` in this
+    /// string, or `None` if there is none.
+    ///
+    /// Matches the behavior of C++ [std::string::rfind][rfind].
+    ///
+    /// [rfind]: https://en.cppreference.com/w/cpp/string/basic_string/rfind
+    pub fn rfind(&self, needle: impl AsRef<[u8]>) -> Option<usize> {
+        let needle = needle.as_ref();
+        match unsafe { string_rfind(self, needle.as_ptr(), needle.len()) } {
+            NPOS => None,
+            pos => Some(pos),
+        }
+    }
+
     /// Produces a nul-terminated string view of this string's contents.
     ///
     /// Matches the behavior of C++ [std::string::c_str][c_str].
@@ -229,6 +302,131 @@ impl CxxString {
     pub fn push_bytes(self: Pin<&mut Self>, bytes: &[u8]) {
         unsafe { string_push(self, bytes.as_ptr(), bytes.len()) }
     }
+
+    /// Appends UTF-16 text onto the end of this C++ string, transcoded to
+    /// UTF-8. Unpaired surrogates are replaced with the U+FFFD [replacement
+    /// character].
+    ///
+    /// [replacement character]: char::REPLACEMENT_CHARACTER
+    pub fn push_utf16_lossy(mut self: Pin<&mut Self>, units: &[u16]) {
+        let mut buf = [0; 64];
+        let mut len = 0;
+        for ch in char::decode_utf16(units.iter().copied()) {
+            if len + 4 > buf.len() {
+                self.as_mut().push_bytes(&buf[..len]);
+                len = 0;
+            }
+            let ch = ch.unwrap_or(REPLACEMENT_CHARACTER);
+            len += ch.encode_utf8(&mut buf[len..]).len();
+        }
+        self.push_bytes(&buf[..len]);
+    }
+
+    /// Inserts bytes into this C++ string at byte position `idx`.
+    ///
+    /// Matches the behavior of C++ [std::string::insert][insert].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the string's length.
+    ///
+    /// [insert]: https://en.cppreference.com/w/cpp/string/basic_string/insert
+    pub fn insert(self: Pin<&mut Self>, idx: usize, bytes: impl AsRef<[u8]>) {
+        let len = self.len();
+        assert!(
+            idx <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            idx,
+            len,
+        );
+        let bytes = bytes.as_ref();
+        unsafe { string_replace(self, idx, 0, bytes.as_ptr(), bytes.len()) }
+    }
+
+    /// Shortens this C++ string to the specified length in bytes.
+    ///
+    /// If `new_len` is greater than or equal to the string's current length,
+    /// this has no effect.
+    pub fn truncate(self: Pin<&mut Self>, new_len: usize) {
+        let len = self.len();
+        if new_len < len {
+            unsafe { string_replace(self, new_len, len - new_len, [].as_ptr(), 0) }
+        }
+    }
+
+    /// Removes the specified range of bytes from the string and replaces it
+    /// with the given bytes, which need not be the same length.
+    ///
+    /// Matches the behavior of C++ [std::string::replace][replace].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range has `start > end`, or if `end` is larger than the
+    /// string's length.
+    ///
+    /// [replace]: https://en.cppreference.com/w/cpp/string/basic_string/replace
+    pub fn replace_range<R>(self: Pin<&mut Self>, range: R, replace_with: impl AsRef<[u8]>)
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start
+                .checked_add(1)
+                .expect("attempted to index CxxString from after maximum usize"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end
+                .checked_add(1)
+                .expect("attempted to index CxxString up to maximum usize"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end,
+            "CxxString index starts at {} but ends at {}",
+            start,
+            end,
+        );
+        assert!(
+            end <= len,
+            "range end index {} out of range for CxxString of length {}",
+            end,
+            len,
+        );
+        let replace_with = replace_with.as_ref();
+        unsafe {
+            string_replace(
+                self,
+                start,
+                end - start,
+                replace_with.as_ptr(),
+                replace_with.len(),
+            );
+        }
+    }
+
+    /// Resizes the string to `new_len` bytes, either truncating it or
+    /// appending copies of `byte`.
+    ///
+    /// Matches the behavior of C++ [std::string::resize][resize].
+    ///
+    /// [resize]: https://en.cppreference.com/w/cpp/string/basic_string/resize
+    pub fn resize(self: Pin<&mut Self>, new_len: usize, byte: u8) {
+        unsafe { string_resize(self, new_len, byte as c_char) }
+    }
+
+    /// Requests that the string's capacity be reduced to fit its length.
+    ///
+    /// Matches the behavior of C++ [std::string::shrink_to_fit][shrink_to_fit],
+    /// which is a non-binding request.
+    ///
+    /// [shrink_to_fit]: https://en.cppreference.com/w/cpp/string/basic_string/shrink_to_fit
+    pub fn shrink_to_fit(self: Pin<&mut Self>) {
+        unsafe { string_shrink_to_fit(self) }
+    }
 }
 
 impl Display for CxxString {
@@ -347,7 +545,9 @@ impl StackString {
 #[cfg(all(miri, feature = "alloc"))]
 mod miri {
     use super::CxxString;
+    use alloc::boxed::Box;
     use alloc::vec::Vec;
+    use core::ffi::{c_char, c_void};
     use core::mem;
     use core::mem::MaybeUninit;
     use core::pin::Pin;
@@ -369,6 +569,12 @@ mod miri {
         }
     }
 
+    #[unsafe(export_name = "cxxbridge1$cxx_string$new")]
+    unsafe extern "C" fn string_new(ptr: *const u8, len: usize) -> *mut CxxString {
+        let vec = unsafe { slice::from_raw_parts(ptr, len) }.to_vec();
+        Box::into_raw(Box::new(vec)).cast::<CxxString>()
+    }
+
     #[unsafe(export_name = "cxxbridge1$cxx_string$destroy")]
     unsafe extern "C" fn string_destroy(this: &mut MaybeUninit<CxxString>) {
         unsafe {
@@ -405,4 +611,91 @@ mod miri {
         let vec = unsafe { &mut *ptr::from_mut(this.get_unchecked_mut()).cast::<Vec<u8>>() };
         vec.extend_from_slice(unsafe { slice::from_raw_parts(ptr, len) });
     }
+
+    #[unsafe(export_name = "cxxbridge1$cxx_string$capacity")]
+    unsafe extern "C" fn string_capacity(this: &CxxString) -> usize {
+        let vec = unsafe { &*ptr::from_ref(this).cast::<Vec<u8>>() };
+        vec.capacity()
+    }
+
+    #[unsafe(export_name = "cxxbridge1$cxx_string$shrink_to_fit")]
+    unsafe extern "C" fn string_shrink_to_fit(this: Pin<&mut CxxString>) {
+        let vec = unsafe { &mut *ptr::from_mut(this.get_unchecked_mut()).cast::<Vec<u8>>() };
+        vec.shrink_to_fit();
+    }
+
+    #[unsafe(export_name = "cxxbridge1$cxx_string$replace")]
+    unsafe extern "C" fn string_replace(
+        this: Pin<&mut CxxString>,
+        pos: usize,
+        count: usize,
+        ptr: *const u8,
+        len: usize,
+    ) {
+        let vec = unsafe { &mut *ptr::from_mut(this.get_unchecked_mut()).cast::<Vec<u8>>() };
+        let replace_with = unsafe { slice::from_raw_parts(ptr, len) };
+        vec.splice(pos..pos + count, replace_with.iter().copied());
+    }
+
+    #[unsafe(export_name = "cxxbridge1$cxx_string$resize")]
+    unsafe extern "C" fn string_resize(this: Pin<&mut CxxString>, count: usize, ch: c_char) {
+        let vec = unsafe { &mut *ptr::from_mut(this.get_unchecked_mut()).cast::<Vec<u8>>() };
+        vec.resize(count, ch as u8);
+    }
+
+    #[unsafe(export_name = "cxxbridge1$cxx_string$find")]
+    unsafe extern "C" fn string_find(this: &CxxString, ptr: *const u8, len: usize) -> usize {
+        let vec = unsafe { &*ptr::from_ref(this).cast::<Vec<u8>>() };
+        let needle = unsafe { slice::from_raw_parts(ptr, len) };
+        if needle.is_empty() {
+            return 0;
+        }
+        vec.windows(len)
+            .position(|window| window == needle)
+            .unwrap_or(usize::MAX)
+    }
+
+    #[unsafe(export_name = "cxxbridge1$cxx_string$rfind")]
+    unsafe extern "C" fn string_rfind(this: &CxxString, ptr: *const u8, len: usize) -> usize {
+        let vec = unsafe { &*ptr::from_ref(this).cast::<Vec<u8>>() };
+        let needle = unsafe { slice::from_raw_parts(ptr, len) };
+        if needle.is_empty() {
+            return vec.len();
+        }
+        vec.windows(len)
+            .rposition(|window| window == needle)
+            .unwrap_or(usize::MAX)
+    }
+
+    // std::unique_ptr<std::string> is emulated as a bare pointer to a string
+    // allocated by string_new.
+
+    #[unsafe(export_name = "cxxbridge1$unique_ptr$std$string$null")]
+    unsafe extern "C" fn unique_ptr_null(this: *mut MaybeUninit<*mut c_void>) {
+        unsafe { this.write(MaybeUninit::new(ptr::null_mut())) }
+    }
+
+    #[unsafe(export_name = "cxxbridge1$unique_ptr$std$string$raw")]
+    unsafe extern "C" fn unique_ptr_raw(this: *mut MaybeUninit<*mut c_void>, raw: *mut CxxString) {
+        unsafe { this.write(MaybeUninit::new(raw.cast::<c_void>())) }
+    }
+
+    #[unsafe(export_name = "cxxbridge1$unique_ptr$std$string$get")]
+    unsafe extern "C" fn unique_ptr_get(this: *const MaybeUninit<*mut c_void>) -> *const CxxString {
+        unsafe { (*this).assume_init().cast::<CxxString>() }
+    }
+
+    #[unsafe(export_name = "cxxbridge1$unique_ptr$std$string$release")]
+    unsafe extern "C" fn unique_ptr_release(this: *mut MaybeUninit<*mut c_void>) -> *mut CxxString {
+        unsafe { mem::replace(&mut *this, MaybeUninit::new(ptr::null_mut())).assume_init() }
+            .cast::<CxxString>()
+    }
+
+    #[unsafe(export_name = "cxxbridge1$unique_ptr$std$string$drop")]
+    unsafe extern "C" fn unique_ptr_drop(this: *mut MaybeUninit<*mut c_void>) {
+        let raw = unsafe { (*this).assume_init() };
+        if !raw.is_null() {
+            drop(unsafe { Box::from_raw(raw.cast::<Vec<u8>>()) });
+        }
+    }
 }
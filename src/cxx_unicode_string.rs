@@ -0,0 +1,317 @@
+use crate::unique_ptr::UniquePtr;
+#[cfg(feature = "alloc")]
+use alloc::string::{FromUtf16Error, String};
+#[cfg(feature = "alloc")]
+use core::char::CharTryFromError;
+use core::char::REPLACEMENT_CHARACTER;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Write as _};
+use core::hash::{Hash, Hasher};
+use core::marker::{PhantomData, PhantomPinned};
+use core::pin::Pin;
+use core::slice;
+
+macro_rules! unicode_string {
+    (
+        $(#[$attr:meta])*
+        $name:ident($unit:ty, $segment:literal, $cxx:literal),
+        checked: $error:ty,
+    ) => {
+        $(#[$attr])*
+        ///
+        /// # Invariants
+        ///
+        /// Just like [`CxxString`](crate::CxxString), in Rust code we can never
+        #[doc = concat!("obtain a `", stringify!($name), "` by value. We only ever look at")]
+        #[doc = concat!("one through a reference or smart pointer, as in `&", stringify!($name), "`")]
+        #[doc = concat!("or `UniquePtr<", stringify!($name), ">`.")]
+        #[repr(C)]
+        pub struct $name {
+            _private: [u8; 0],
+            _pinned: PhantomData<PhantomPinned>,
+        }
+
+        impl $name {
+            #[doc = concat!("Constructs a new empty heap allocated `", $cxx, "`, wrapped by")]
+            /// UniquePtr.
+            pub fn new() -> UniquePtr<Self> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$cxx_", $segment, "$new")]
+                    fn __new() -> *mut $name;
+                }
+                unsafe { UniquePtr::from_raw(__new()) }
+            }
+
+            /// Returns the length of the string in code units.
+            ///
+            #[doc = concat!("Matches the behavior of C++ [", $cxx, "::size][size].")]
+            ///
+            /// [size]: https://en.cppreference.com/w/cpp/string/basic_string/size
+            pub fn len(&self) -> usize {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$cxx_", $segment, "$length")]
+                    fn __length(this: &$name) -> usize;
+                }
+                unsafe { __length(self) }
+            }
+
+            /// Returns true if `self` has a length of zero code units.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Returns a slice of this string's code units.
+            pub fn as_slice(&self) -> &[$unit] {
+                let data = self.as_ptr();
+                let len = self.len();
+                unsafe { slice::from_raw_parts(data, len) }
+            }
+
+            /// Produces a pointer to the first code unit of the string.
+            ///
+            #[doc = concat!("Matches the behavior of C++ [", $cxx, "::data][data].")]
+            ///
+            /// Modifying the string data through this pointer has undefined
+            /// behavior.
+            ///
+            /// [data]: https://en.cppreference.com/w/cpp/string/basic_string/data
+            pub fn as_ptr(&self) -> *const $unit {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$cxx_", $segment, "$data")]
+                    fn __data(this: &$name) -> *const $unit;
+                }
+                unsafe { __data(self) }
+            }
+
+            /// Iterates over the chars of the string, substituting the U+FFFD
+            /// [replacement character] for any invalid code units.
+            ///
+            /// [replacement character]: char::REPLACEMENT_CHARACTER
+            pub fn chars_lossy(&self) -> impl Iterator<Item = char> + '_ {
+                decode(self.as_slice()).map(|ch| ch.unwrap_or(REPLACEMENT_CHARACTER))
+            }
+
+            /// Validates that the C++ string contains well-formed Unicode and
+            /// converts it to a Rust String, otherwise an error.
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            pub fn try_to_string(&self) -> Result<String, $error> {
+                to_string(self.as_slice())
+            }
+
+            /// Converts the C++ string to a Rust String, replacing any invalid
+            /// code units with the U+FFFD [replacement character].
+            ///
+            /// [replacement character]: char::REPLACEMENT_CHARACTER
+            #[cfg(feature = "alloc")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+            pub fn to_string_lossy(&self) -> String {
+                self.chars_lossy().collect()
+            }
+
+            /// Removes all characters from the string.
+            ///
+            #[doc = concat!("Matches the behavior of C++ [", $cxx, "::clear][clear].")]
+            ///
+            /// [clear]: https://en.cppreference.com/w/cpp/string/basic_string/clear
+            pub fn clear(self: Pin<&mut Self>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$cxx_", $segment, "$clear")]
+                    fn __clear(this: Pin<&mut $name>);
+                }
+                unsafe { __clear(self) }
+            }
+
+            /// Ensures that this string's capacity is at least `additional`
+            /// code units larger than its length.
+            ///
+            /// Follows the Rust convention for the meaning of the argument,
+            /// like [`CxxString::reserve`](crate::CxxString::reserve).
+            ///
+            /// # Panics
+            ///
+            /// Panics if the new capacity overflows usize.
+            pub fn reserve(self: Pin<&mut Self>, additional: usize) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$cxx_", $segment, "$reserve_total")]
+                    fn __reserve_total(this: Pin<&mut $name>, new_cap: usize);
+                }
+                let new_cap = self
+                    .len()
+                    .checked_add(additional)
+                    .expect(concat!(stringify!($name), " capacity overflow"));
+                unsafe { __reserve_total(self, new_cap) }
+            }
+
+            /// Appends a char onto the end of this C++ string.
+            pub fn push(self: Pin<&mut Self>, ch: char) {
+                let mut buf = [0; 2];
+                self.push_slice(encode(ch, &mut buf));
+            }
+
+            /// Appends a given string slice onto the end of this C++ string.
+            pub fn push_str(mut self: Pin<&mut Self>, s: &str) {
+                let mut buf = [0; 64];
+                let mut len = 0;
+                for ch in s.chars() {
+                    if len + 2 > buf.len() {
+                        self.as_mut().push_slice(&buf[..len]);
+                        len = 0;
+                    }
+                    len += encode(ch, &mut buf[len..]).len();
+                }
+                self.push_slice(&buf[..len]);
+            }
+
+            /// Appends arbitrary code units onto the end of this C++ string.
+            pub fn push_slice(self: Pin<&mut Self>, units: &[$unit]) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$cxx_", $segment, "$push")]
+                    fn __push(this: Pin<&mut $name>, ptr: *const $unit, len: usize);
+                }
+                unsafe { __push(self, units.as_ptr(), units.len()) }
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                for ch in self.chars_lossy() {
+                    f.write_char(ch)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_char('"')?;
+                for ch in decode(self.as_slice()) {
+                    match ch {
+                        Ok(ch) => {
+                            for escaped in ch.escape_debug() {
+                                f.write_char(escaped)?;
+                            }
+                        }
+                        Err(unit) => write!(f, "\\u{{{:x}}}", unit)?,
+                    }
+                }
+                f.write_char('"')
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+
+        impl PartialEq<$name> for str {
+            fn eq(&self, other: &$name) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                let mut buf = [0; 2];
+                let mut units = self.as_slice();
+                for ch in other.chars() {
+                    let encoded = encode(ch, &mut buf);
+                    match units.strip_prefix(&*encoded) {
+                        Some(rest) => units = rest,
+                        None => return false,
+                    }
+                }
+                units.is_empty()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.as_slice().cmp(other.as_slice())
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.as_slice().hash(state);
+            }
+        }
+
+        impl fmt::Write for Pin<&mut $name> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.as_mut().push_str(s);
+                Ok(())
+            }
+        }
+    };
+}
+
+mod utf16 {
+    use super::*;
+
+    fn decode(units: &[u16]) -> impl Iterator<Item = Result<char, u16>> + '_ {
+        char::decode_utf16(units.iter().copied())
+            .map(|ch| ch.map_err(|err| err.unpaired_surrogate()))
+    }
+
+    fn encode(ch: char, buf: &mut [u16]) -> &mut [u16] {
+        ch.encode_utf16(buf)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_string(units: &[u16]) -> Result<String, FromUtf16Error> {
+        String::from_utf16(units)
+    }
+
+    unicode_string! {
+        /// Binding to C++ `std::u16string`.
+        ///
+        /// The string holds UTF-16 code units, which are not guaranteed to be
+        /// well-formed; unpaired surrogates are reported as errors by
+        /// [`try_to_string`][Self::try_to_string] and replaced by the lossy
+        /// conversions.
+        CxxU16String(u16, "u16string", "std::u16string"),
+        checked: FromUtf16Error,
+    }
+}
+
+mod utf32 {
+    use super::*;
+
+    fn decode(units: &[u32]) -> impl Iterator<Item = Result<char, u32>> + '_ {
+        units.iter().map(|&unit| char::from_u32(unit).ok_or(unit))
+    }
+
+    fn encode(ch: char, buf: &mut [u32]) -> &mut [u32] {
+        buf[0] = u32::from(ch);
+        &mut buf[..1]
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_string(units: &[u32]) -> Result<String, CharTryFromError> {
+        units.iter().map(|&unit| char::try_from(unit)).collect()
+    }
+
+    unicode_string! {
+        /// Binding to C++ `std::u32string`.
+        ///
+        /// The string holds UTF-32 code units, which are not guaranteed to be
+        /// valid chars; surrogates and values past U+10FFFF are reported as
+        /// errors by [`try_to_string`][Self::try_to_string] and replaced by the
+        /// lossy conversions.
+        CxxU32String(u32, "u32string", "std::u32string"),
+        checked: CharTryFromError,
+    }
+}
+
+pub use self::utf16::CxxU16String;
+pub use self::utf32::CxxU32String;
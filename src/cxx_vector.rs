@@ -1,13 +1,15 @@
 //! Less used details of `CxxVector` are exposed in this module. `CxxVector`
 //! itself is exposed at the crate root.
 
+use crate::cxx_unicode_string::{CxxU16String, CxxU32String};
 use crate::extern_type::ExternType;
 use crate::kind::Trivial;
+use crate::shared_ptr::{SharedPtr, SharedPtrTarget};
 use crate::string::CxxString;
-use crate::unique_ptr::UniquePtr;
+use crate::unique_ptr::{UniquePtr, UniquePtrTarget};
 use core::ffi::c_void;
 use core::fmt::{self, Debug};
-use core::iter::FusedIterator;
+use core::iter::{self, FusedIterator};
 use core::marker::{PhantomData, PhantomPinned};
 use core::mem::{self, ManuallyDrop, MaybeUninit};
 use core::pin::Pin;
@@ -215,6 +217,148 @@ where
         }
     }
 
+    /// Inserts an element at position `index` within the vector, shifting all
+    /// elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(mut self: Pin<&mut Self>, index: usize, element: T)
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            len,
+        );
+        self.as_mut().push(element);
+        self.as_mut_slice()[index..].rotate_right(1);
+    }
+
+    /// Removes and returns the element at position `index` within the vector,
+    /// shifting all elements after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(mut self: Pin<&mut Self>, index: usize) -> T
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            len,
+        );
+        self.as_mut().as_mut_slice()[index..].rotate_left(1);
+        self.pop().unwrap()
+    }
+
+    /// Removes an element from the vector and returns it, replacing it with
+    /// the last element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(mut self: Pin<&mut Self>, index: usize) -> T
+    where
+        T: ExternType<Kind = Trivial>,
+    {
+        let len = self.len();
+        assert!(
+            index < len,
+            "swap_remove index (is {}) should be < len (is {})",
+            index,
+            len,
+        );
+        self.as_mut().as_mut_slice().swap(index, len - 1);
+        self.pop().unwrap()
+    }
+
+    /// Retains only the elements for which the predicate returns true, and
+    /// destroys the rest.
+    ///
+    /// The retained elements keep their original order. If the predicate
+    /// panics, the vector is left holding all of its elements in an
+    /// unspecified order.
+    pub fn retain<F>(mut self: Pin<&mut Self>, mut f: F)
+    where
+        T: ExternType<Kind = Trivial>,
+        F: FnMut(&T) -> bool,
+    {
+        let slice = self.as_mut().as_mut_slice();
+        let mut kept = 0;
+        for i in 0..slice.len() {
+            if f(&slice[i]) {
+                slice.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.truncate(kept);
+    }
+
+    /// Clones and appends all elements in a slice to the vector.
+    pub fn extend_from_slice(mut self: Pin<&mut Self>, other: &[T])
+    where
+        T: ExternType<Kind = Trivial> + Clone,
+    {
+        self.extend(other.iter().cloned());
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and destroying
+    /// the rest.
+    ///
+    /// If `len` is greater than or equal to the vector's current length, this
+    /// has no effect.
+    pub fn truncate(self: Pin<&mut Self>, len: usize) {
+        if len < self.len() {
+            unsafe { T::__truncate(self, len) }
+        }
+    }
+
+    /// Removes all elements from the vector.
+    ///
+    /// Matches the behavior of C++ [std::vector\<T\>::clear][clear].
+    ///
+    /// [clear]: https://en.cppreference.com/w/cpp/container/vector/clear
+    pub fn clear(self: Pin<&mut Self>) {
+        self.truncate(0);
+    }
+
+    /// Resizes the vector so that its length is `new_len`.
+    ///
+    /// If `new_len` is greater than the current length, the vector is
+    /// extended by clones of `value`, as with Rust's `Vec::resize`. If it is
+    /// less, the vector is truncated.
+    pub fn resize(mut self: Pin<&mut Self>, new_len: usize, value: T)
+    where
+        T: ExternType<Kind = Trivial> + Clone,
+    {
+        let len = self.len();
+        if new_len <= len {
+            self.truncate(new_len);
+        } else {
+            self.extend(iter::repeat_n(value, new_len - len));
+        }
+    }
+
+    /// Exchanges the contents of this vector with those of `other`, without
+    /// moving, copying or destroying any elements.
+    ///
+    /// Matches the behavior of C++ [std::vector\<T\>::swap][swap].
+    ///
+    /// [swap]: https://en.cppreference.com/w/cpp/container/vector/swap
+    pub fn swap(self: Pin<&mut Self>, other: Pin<&mut Self>) {
+        unsafe { T::__swap(self, other) }
+    }
+
     /// Ensures that this vector's capacity is at least `additional` elements
     /// larger than its length.
     ///
@@ -381,6 +525,10 @@ where
 /// adding the line `impl CxxVector<MyType> {}` in the same `cxx::bridge` that
 /// defines `MyType`.
 ///
+/// Besides primitives, `CxxString` and types from a `cxx::bridge`, the vector
+/// element may be a `SharedPtr<T>`, `UniquePtr<T>` or `CxxVector<T>` of one of
+/// those, as in `CxxVector<SharedPtr<MyType>>` or `CxxVector<CxxVector<f32>>`.
+///
 /// # Example
 ///
 /// A bound `T: VectorElement` may be necessary when manipulating [`CxxVector`]
@@ -419,6 +567,10 @@ pub unsafe trait VectorElement: Sized {
     #[doc(hidden)]
     unsafe fn __reserve(v: Pin<&mut CxxVector<Self>>, new_cap: usize);
     #[doc(hidden)]
+    unsafe fn __truncate(v: Pin<&mut CxxVector<Self>>, len: usize);
+    #[doc(hidden)]
+    unsafe fn __swap(v: Pin<&mut CxxVector<Self>>, other: Pin<&mut CxxVector<Self>>);
+    #[doc(hidden)]
     unsafe fn __push_back(v: Pin<&mut CxxVector<Self>>, value: &mut ManuallyDrop<Self>) {
         // Opaque C type vector elements do not get this method because they can
         // never exist by value on the Rust side of the bridge.
@@ -435,6 +587,20 @@ pub unsafe trait VectorElement: Sized {
         unreachable!()
     }
     #[doc(hidden)]
+    fn __unique_ptr_array_new(len: usize) -> *mut Self {
+        // Opaque C type vector elements do not get this method because they can
+        // never exist by value on the Rust side of the bridge.
+        let _ = len;
+        unreachable!()
+    }
+    #[doc(hidden)]
+    unsafe fn __unique_ptr_array_delete(ptr: *mut Self) {
+        // Opaque C type vector elements do not get this method because they can
+        // never exist by value on the Rust side of the bridge.
+        let _ = ptr;
+        unreachable!()
+    }
+    #[doc(hidden)]
     fn __unique_ptr_null() -> MaybeUninit<*mut c_void>;
     #[doc(hidden)]
     unsafe fn __unique_ptr_raw(raw: *mut CxxVector<Self>) -> MaybeUninit<*mut c_void>;
@@ -446,6 +612,80 @@ pub unsafe trait VectorElement: Sized {
     unsafe fn __unique_ptr_drop(repr: MaybeUninit<*mut c_void>);
 }
 
+/// Not public API. Implemented by the bridge for `T` in order to provide the
+/// `VectorElement` impl of `W`, which is `SharedPtr<T>`, `UniquePtr<T>` or
+/// `CxxVector<T>`. The orphan rule would not allow implementing
+/// `VectorElement` for `W` outside of this crate.
+#[doc(hidden)]
+pub unsafe trait VectorElementOf<W>: Sized {
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result;
+    fn __vector_new() -> *mut CxxVector<W>;
+    fn __vector_size(v: &CxxVector<W>) -> usize;
+    fn __vector_capacity(v: &CxxVector<W>) -> usize;
+    unsafe fn __get_unchecked(v: *mut CxxVector<W>, pos: usize) -> *mut W;
+    unsafe fn __reserve(v: Pin<&mut CxxVector<W>>, new_cap: usize);
+    unsafe fn __truncate(v: Pin<&mut CxxVector<W>>, len: usize);
+    unsafe fn __swap(v: Pin<&mut CxxVector<W>>, other: Pin<&mut CxxVector<W>>);
+    fn __unique_ptr_null() -> MaybeUninit<*mut c_void>;
+    unsafe fn __unique_ptr_raw(raw: *mut CxxVector<W>) -> MaybeUninit<*mut c_void>;
+    unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxVector<W>;
+    unsafe fn __unique_ptr_release(repr: MaybeUninit<*mut c_void>) -> *mut CxxVector<W>;
+    unsafe fn __unique_ptr_drop(repr: MaybeUninit<*mut c_void>);
+}
+
+macro_rules! impl_vector_element_for_wrapper {
+    ($wrapper:ident $(: $bound:ident)?) => {
+        unsafe impl<T> VectorElement for $wrapper<T>
+        where
+            T: $($bound +)? VectorElementOf<$wrapper<T>>,
+        {
+            fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+                <T as VectorElementOf<Self>>::__typename(f)
+            }
+            fn __vector_new() -> *mut CxxVector<Self> {
+                <T as VectorElementOf<Self>>::__vector_new()
+            }
+            fn __vector_size(v: &CxxVector<Self>) -> usize {
+                <T as VectorElementOf<Self>>::__vector_size(v)
+            }
+            fn __vector_capacity(v: &CxxVector<Self>) -> usize {
+                <T as VectorElementOf<Self>>::__vector_capacity(v)
+            }
+            unsafe fn __get_unchecked(v: *mut CxxVector<Self>, pos: usize) -> *mut Self {
+                unsafe { <T as VectorElementOf<Self>>::__get_unchecked(v, pos) }
+            }
+            unsafe fn __reserve(v: Pin<&mut CxxVector<Self>>, new_cap: usize) {
+                unsafe { <T as VectorElementOf<Self>>::__reserve(v, new_cap) }
+            }
+            unsafe fn __truncate(v: Pin<&mut CxxVector<Self>>, len: usize) {
+                unsafe { <T as VectorElementOf<Self>>::__truncate(v, len) }
+            }
+            unsafe fn __swap(v: Pin<&mut CxxVector<Self>>, other: Pin<&mut CxxVector<Self>>) {
+                unsafe { <T as VectorElementOf<Self>>::__swap(v, other) }
+            }
+            fn __unique_ptr_null() -> MaybeUninit<*mut c_void> {
+                <T as VectorElementOf<Self>>::__unique_ptr_null()
+            }
+            unsafe fn __unique_ptr_raw(raw: *mut CxxVector<Self>) -> MaybeUninit<*mut c_void> {
+                unsafe { <T as VectorElementOf<Self>>::__unique_ptr_raw(raw) }
+            }
+            unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxVector<Self> {
+                unsafe { <T as VectorElementOf<Self>>::__unique_ptr_get(repr) }
+            }
+            unsafe fn __unique_ptr_release(repr: MaybeUninit<*mut c_void>) -> *mut CxxVector<Self> {
+                unsafe { <T as VectorElementOf<Self>>::__unique_ptr_release(repr) }
+            }
+            unsafe fn __unique_ptr_drop(repr: MaybeUninit<*mut c_void>) {
+                unsafe { <T as VectorElementOf<Self>>::__unique_ptr_drop(repr) }
+            }
+        }
+    };
+}
+
+impl_vector_element_for_wrapper!(UniquePtr: UniquePtrTarget);
+impl_vector_element_for_wrapper!(SharedPtr: SharedPtrTarget);
+impl_vector_element_for_wrapper!(CxxVector);
+
 macro_rules! vector_element_by_value_methods {
     (opaque, $segment:expr, $ty:ty) => {};
     (trivial, $segment:expr, $ty:ty) => {
@@ -463,19 +703,36 @@ macro_rules! vector_element_by_value_methods {
             }
             unsafe { __pop_back(v, out) }
         }
+        fn __unique_ptr_array_new(len: usize) -> *mut $ty {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$unique_ptr$array$", $segment, "$new")]
+                fn __unique_ptr_array_new(_: usize) -> *mut $ty;
+            }
+            unsafe { __unique_ptr_array_new(len) }
+        }
+        unsafe fn __unique_ptr_array_delete(ptr: *mut $ty) {
+            unsafe extern "C" {
+                #[link_name = concat!("cxxbridge1$unique_ptr$array$", $segment, "$delete")]
+                fn __unique_ptr_array_delete(_: *mut $ty);
+            }
+            unsafe { __unique_ptr_array_delete(ptr) }
+        }
     };
 }
 
 macro_rules! impl_vector_element {
     ($kind:ident, $segment:expr, $name:expr, $ty:ty) => {
+        impl_vector_element!($kind, $segment, $name, $ty, VectorElement for $ty);
+    };
+    ($kind:ident, $segment:expr, $name:expr, $ty:ty, $($impl:tt)*) => {
         const_assert_eq!(0, mem::size_of::<CxxVector<$ty>>());
         const_assert_eq!(1, mem::align_of::<CxxVector<$ty>>());
 
-        unsafe impl VectorElement for $ty {
+        unsafe impl $($impl)* {
             fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
                 f.write_str($name)
             }
-            fn __vector_new() -> *mut CxxVector<Self> {
+            fn __vector_new() -> *mut CxxVector<$ty> {
                 unsafe extern "C" {
                     #[link_name = concat!("cxxbridge1$std$vector$", $segment, "$new")]
                     fn __vector_new() -> *mut CxxVector<$ty>;
@@ -510,6 +767,20 @@ macro_rules! impl_vector_element {
                 }
                 unsafe { __reserve(v, new_cap) }
             }
+            unsafe fn __truncate(v: Pin<&mut CxxVector<$ty>>, len: usize) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$vector$", $segment, "$truncate")]
+                    fn __truncate(_: Pin<&mut CxxVector<$ty>>, _: usize);
+                }
+                unsafe { __truncate(v, len) }
+            }
+            unsafe fn __swap(v: Pin<&mut CxxVector<$ty>>, other: Pin<&mut CxxVector<$ty>>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$vector$", $segment, "$swap")]
+                    fn __swap(_: Pin<&mut CxxVector<$ty>>, _: Pin<&mut CxxVector<$ty>>);
+                }
+                unsafe { __swap(v, other) }
+            }
             vector_element_by_value_methods!($kind, $segment, $ty);
             fn __unique_ptr_null() -> MaybeUninit<*mut c_void> {
                 unsafe extern "C" {
@@ -520,7 +791,7 @@ macro_rules! impl_vector_element {
                 unsafe { __unique_ptr_null(&mut repr) }
                 repr
             }
-            unsafe fn __unique_ptr_raw(raw: *mut CxxVector<Self>) -> MaybeUninit<*mut c_void> {
+            unsafe fn __unique_ptr_raw(raw: *mut CxxVector<$ty>) -> MaybeUninit<*mut c_void> {
                 unsafe extern "C" {
                     #[link_name = concat!("cxxbridge1$unique_ptr$std$vector$", $segment, "$raw")]
                     fn __unique_ptr_raw(this: *mut MaybeUninit<*mut c_void>, raw: *mut CxxVector<$ty>);
@@ -529,14 +800,14 @@ macro_rules! impl_vector_element {
                 unsafe { __unique_ptr_raw(&mut repr, raw) }
                 repr
             }
-            unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxVector<Self> {
+            unsafe fn __unique_ptr_get(repr: MaybeUninit<*mut c_void>) -> *const CxxVector<$ty> {
                 unsafe extern "C" {
                     #[link_name = concat!("cxxbridge1$unique_ptr$std$vector$", $segment, "$get")]
                     fn __unique_ptr_get(this: *const MaybeUninit<*mut c_void>) -> *const CxxVector<$ty>;
                 }
                 unsafe { __unique_ptr_get(&repr) }
             }
-            unsafe fn __unique_ptr_release(mut repr: MaybeUninit<*mut c_void>) -> *mut CxxVector<Self> {
+            unsafe fn __unique_ptr_release(mut repr: MaybeUninit<*mut c_void>) -> *mut CxxVector<$ty> {
                 unsafe extern "C" {
                     #[link_name = concat!("cxxbridge1$unique_ptr$std$vector$", $segment, "$release")]
                     fn __unique_ptr_release(this: *mut MaybeUninit<*mut c_void>) -> *mut CxxVector<$ty>;
@@ -574,3 +845,235 @@ impl_vector_element_for_primitive!(f32);
 impl_vector_element_for_primitive!(f64);
 
 impl_vector_element!(opaque, "string", "CxxString", CxxString);
+impl_vector_element!(opaque, "u16string", "CxxU16String", CxxU16String);
+impl_vector_element!(opaque, "u32string", "CxxU32String", CxxU32String);
+
+macro_rules! impl_vector_element_of {
+    ($wrapper:ident, $segment:expr, $name:expr, $ty:ty) => {
+        impl_vector_element!(
+            opaque,
+            concat!("std$", $segment),
+            concat!(stringify!($wrapper), "<", $name, ">"),
+            $wrapper<$ty>,
+            VectorElementOf<$wrapper<$ty>> for $ty
+        );
+    };
+}
+
+macro_rules! impl_vector_element_of_for_primitive {
+    ($ty:ident) => {
+        impl_vector_element_of!(
+            CxxVector,
+            concat!("vector$", stringify!($ty)),
+            stringify!($ty),
+            $ty
+        );
+        impl_vector_element_of!(
+            SharedPtr,
+            concat!("shared_ptr$", stringify!($ty)),
+            stringify!($ty),
+            $ty
+        );
+    };
+}
+
+impl_vector_element_of_for_primitive!(bool);
+impl_vector_element_of_for_primitive!(u8);
+impl_vector_element_of_for_primitive!(u16);
+impl_vector_element_of_for_primitive!(u32);
+impl_vector_element_of_for_primitive!(u64);
+impl_vector_element_of_for_primitive!(usize);
+impl_vector_element_of_for_primitive!(i8);
+impl_vector_element_of_for_primitive!(i16);
+impl_vector_element_of_for_primitive!(i32);
+impl_vector_element_of_for_primitive!(i64);
+impl_vector_element_of_for_primitive!(isize);
+impl_vector_element_of_for_primitive!(f32);
+impl_vector_element_of_for_primitive!(f64);
+
+impl_vector_element_of!(CxxVector, "vector$string", "CxxString", CxxString);
+impl_vector_element_of!(SharedPtr, "shared_ptr$string", "CxxString", CxxString);
+impl_vector_element_of!(UniquePtr, "unique_ptr$string", "CxxString", CxxString);
+
+/// `std::vector<bool>` is a bit-packed specialization in C++ whose elements
+/// are not individually addressable, so it cannot implement `VectorElement`.
+/// Instead `CxxVector<bool>` gets its own API which hands out elements by value
+/// where other vectors hand out references.
+impl CxxVector<bool> {
+    /// Constructs a new heap allocated vector, wrapped by UniquePtr.
+    ///
+    /// The C++ vector is default constructed.
+    pub fn new() -> UniquePtr<Self> {
+        unsafe { UniquePtr::from_raw(vector_bool_new()) }
+    }
+
+    /// Returns the number of elements in the vector.
+    ///
+    /// Matches the behavior of C++ [std::vector\<bool\>::size][size].
+    ///
+    /// [size]: https://en.cppreference.com/w/cpp/container/vector_bool
+    pub fn len(&self) -> usize {
+        unsafe { vector_bool_size(self) }
+    }
+
+    /// Returns the capacity of the vector.
+    pub fn capacity(&self) -> usize {
+        unsafe { vector_bool_capacity(self) }
+    }
+
+    /// Returns true if the vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the element at the given position, or `None` if out of bounds.
+    pub fn get(&self, pos: usize) -> Option<bool> {
+        if pos < self.len() {
+            Some(unsafe { vector_bool_get(self, pos) })
+        } else {
+            None
+        }
+    }
+
+    /// Overwrites the element at the given position.
+    ///
+    /// This goes through the C++ `std::vector<bool>::reference` proxy, which is
+    /// the only way to write a single bit of the packed representation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    pub fn set(self: Pin<&mut Self>, pos: usize, value: bool) {
+        let len = self.len();
+        assert!(
+            pos < len,
+            "CxxVector<bool> index out of bounds: the len is {} but the index is {}",
+            len,
+            pos,
+        );
+        unsafe { vector_bool_set(self, pos, value) }
+    }
+
+    /// Returns an iterator over elements of type `bool`.
+    pub fn iter(&self) -> BoolIter {
+        BoolIter { v: self, index: 0 }
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// Matches the behavior of C++ [std::vector\<bool\>::push_back][push_back].
+    ///
+    /// [push_back]: https://en.cppreference.com/w/cpp/container/vector/push_back
+    pub fn push(self: Pin<&mut Self>, value: bool) {
+        unsafe { vector_bool_push_back(self, value) }
+    }
+
+    /// Removes the last element from a vector and returns it, or `None` if the
+    /// vector is empty.
+    pub fn pop(self: Pin<&mut Self>) -> Option<bool> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(unsafe { vector_bool_pop_back(self) })
+        }
+    }
+
+    /// Ensures that this vector's capacity is at least `additional` elements
+    /// larger than its length.
+    ///
+    /// Follows the Rust convention for the meaning of the argument, like
+    /// [`CxxVector::reserve`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows usize.
+    pub fn reserve(self: Pin<&mut Self>, additional: usize) {
+        let new_cap = self
+            .len()
+            .checked_add(additional)
+            .expect("CxxVector capacity overflow");
+        unsafe { vector_bool_reserve(self, new_cap) }
+    }
+}
+
+impl Extend<bool> for Pin<&mut CxxVector<bool>> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let iter = iter.into_iter();
+        self.as_mut().reserve(iter.size_hint().0);
+        for element in iter {
+            self.as_mut().push(element);
+        }
+    }
+}
+
+/// Iterator over elements of a `CxxVector<bool>`.
+///
+/// The iterator element type is `bool`, since the bit-packed elements of
+/// `std::vector<bool>` cannot be borrowed.
+pub struct BoolIter<'a> {
+    v: &'a CxxVector<bool>,
+    index: usize,
+}
+
+impl<'a> IntoIterator for &'a CxxVector<bool> {
+    type Item = bool;
+    type IntoIter = BoolIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> Iterator for BoolIter<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.v.get(self.index)?;
+        self.index += 1;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for BoolIter<'a> {
+    fn len(&self) -> usize {
+        self.v.len() - self.index
+    }
+}
+
+impl<'a> FusedIterator for BoolIter<'a> {}
+
+impl Debug for CxxVector<bool> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_list().entries(self).finish()
+    }
+}
+
+const_assert_eq!(0, mem::size_of::<CxxVector<bool>>());
+const_assert_eq!(1, mem::align_of::<CxxVector<bool>>());
+
+unsafe extern "C" {
+    #[link_name = "cxxbridge1$std$vector$bool$new"]
+    fn vector_bool_new() -> *mut CxxVector<bool>;
+    #[link_name = "cxxbridge1$std$vector$bool$size"]
+    fn vector_bool_size(_: &CxxVector<bool>) -> usize;
+    #[link_name = "cxxbridge1$std$vector$bool$capacity"]
+    fn vector_bool_capacity(_: &CxxVector<bool>) -> usize;
+    #[link_name = "cxxbridge1$std$vector$bool$get"]
+    fn vector_bool_get(_: &CxxVector<bool>, pos: usize) -> bool;
+    #[link_name = "cxxbridge1$std$vector$bool$set"]
+    fn vector_bool_set(_: Pin<&mut CxxVector<bool>>, pos: usize, value: bool);
+    #[link_name = "cxxbridge1$std$vector$bool$reserve"]
+    fn vector_bool_reserve(_: Pin<&mut CxxVector<bool>>, new_cap: usize);
+    #[link_name = "cxxbridge1$std$vector$bool$push_back"]
+    fn vector_bool_push_back(_: Pin<&mut CxxVector<bool>>, value: bool);
+    #[link_name = "cxxbridge1$std$vector$bool$pop_back"]
+    fn vector_bool_pop_back(_: Pin<&mut CxxVector<bool>>) -> bool;
+}
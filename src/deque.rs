@@ -0,0 +1,7 @@
+//! Less used details of `CxxDeque`.
+//!
+//! `CxxDeque` itself is exposed at the crate root.
+
+pub use crate::cxx_deque::DequeElement;
+#[doc(no_inline)]
+pub use cxx::CxxDeque;
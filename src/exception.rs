@@ -10,6 +10,8 @@ use core::error::Error as StdError;
 #[derive(Debug)]
 pub struct Exception {
     pub(crate) what: Box<str>,
+    pub(crate) type_name: Option<Box<str>>,
+    pub(crate) source: Option<Box<Exception>>,
 }
 
 impl Display for Exception {
@@ -18,11 +20,31 @@ impl Display for Exception {
     }
 }
 
-impl StdError for Exception {}
+impl StdError for Exception {
+    /// The exception nested inside of this one by `std::throw_with_nested`,
+    /// if any.
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match &self.source {
+            Some(source) => Some(&**source),
+            None => None,
+        }
+    }
+}
 
 impl Exception {
     #[allow(missing_docs)]
     pub fn what(&self) -> &str {
         &self.what
     }
+
+    /// The dynamic type of the C++ exception object, such as
+    /// `std::runtime_error`.
+    ///
+    /// The name comes from `typeid(e).name()`, demangled where the C++ ABI
+    /// supports it. It is `None` if C++ was built without RTTI or if the
+    /// exception was reported by a custom `rust::behavior::trycatch` as only a
+    /// message.
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
 }
@@ -1,4 +1,6 @@
-use core::fmt::{self, Display};
+use crate::CxxString;
+use core::fmt::{self, Debug, Display, Write as _};
+use core::pin::Pin;
 
 pub(crate) fn display(fmt: impl Fn(&mut fmt::Formatter) -> fmt::Result) -> impl Display {
     DisplayInvoke(fmt)
@@ -14,3 +16,9 @@ where
         (self.0)(formatter)
     }
 }
+
+// Used by the std::formatter generated for shared types that derive Debug.
+#[doc(hidden)]
+pub fn debug<V: Debug>(value: &V, mut out: Pin<&mut CxxString>) {
+    let _ = write!(out, "{:?}", value);
+}
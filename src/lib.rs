@@ -333,12 +333,17 @@
 //! <tr><td>&amp;[T]</td><td>rust::Slice&lt;const T&gt;</td><td><sup><i>cannot hold opaque C++ type</i></sup></td></tr>
 //! <tr><td>&amp;mut [T]</td><td>rust::Slice&lt;T&gt;</td><td><sup><i>cannot hold opaque C++ type</i></sup></td></tr>
 //! <tr><td><a href="struct.CxxString.html">CxxString</a></td><td>std::string</td><td><sup><i>cannot be passed by value</i></sup></td></tr>
+//! <tr><td><a href="struct.CxxU16String.html">CxxU16String</a></td><td>std::u16string</td><td><sup><i>cannot be passed by value</i></sup></td></tr>
+//! <tr><td><a href="struct.CxxU32String.html">CxxU32String</a></td><td>std::u32string</td><td><sup><i>cannot be passed by value</i></sup></td></tr>
 //! <tr><td>Box&lt;T&gt;</td><td>rust::Box&lt;T&gt;</td><td><sup><i>cannot hold opaque C++ type</i></sup></td></tr>
 //! <tr><td><a href="struct.UniquePtr.html">UniquePtr&lt;T&gt;</a></td><td>std::unique_ptr&lt;T&gt;</td><td><sup><i>cannot hold opaque Rust type</i></sup></td></tr>
 //! <tr><td><a href="struct.SharedPtr.html">SharedPtr&lt;T&gt;</a></td><td>std::shared_ptr&lt;T&gt;</td><td><sup><i>cannot hold opaque Rust type</i></sup></td></tr>
 //! <tr><td>[T; N]</td><td>std::array&lt;T, N&gt;</td><td><sup><i>cannot hold opaque C++ type</i></sup></td></tr>
 //! <tr><td>Vec&lt;T&gt;</td><td>rust::Vec&lt;T&gt;</td><td><sup><i>cannot hold opaque C++ type</i></sup></td></tr>
 //! <tr><td><a href="struct.CxxVector.html">CxxVector&lt;T&gt;</a></td><td>std::vector&lt;T&gt;</td><td><sup><i>cannot be passed by value, cannot hold opaque Rust type</i></sup></td></tr>
+//! <tr><td><a href="struct.CxxDeque.html">CxxDeque&lt;T&gt;</a></td><td>std::deque&lt;T&gt;</td><td><sup><i>cannot be passed by value, cannot hold opaque Rust type</i></sup></td></tr>
+//! <tr><td><a href="struct.CxxList.html">CxxList&lt;T&gt;</a></td><td>std::list&lt;T&gt;</td><td><sup><i>cannot be passed by value, cannot hold opaque Rust type</i></sup></td></tr>
+//! <tr><td><a href="struct.CxxSet.html">CxxSet&lt;T&gt;</a></td><td>std::set&lt;T&gt;</td><td><sup><i>cannot be passed by value, cannot hold opaque Rust type</i></sup></td></tr>
 //! <tr><td>*mut T, *const T</td><td>T*, const T*</td><td><sup><i>fn with a raw pointer argument must be declared unsafe to call</i></sup></td></tr>
 //! <tr><td>fn(T, U) -&gt; V</td><td>rust::Fn&lt;V(T, U)&gt;</td><td><sup><i>only passing from Rust to C++ is implemented so far</i></sup></td></tr>
 //! <tr><td>Result&lt;T&gt;</td><td>throw/catch</td><td><sup><i>allowed as return type only</i></sup></td></tr>
@@ -432,12 +437,19 @@ compile_error! {
 #[macro_use]
 mod macros;
 
+mod cxx_deque;
+mod cxx_iter;
+mod cxx_list;
+mod cxx_set;
+mod cxx_unicode_string;
 mod cxx_vector;
+pub mod deque;
 mod exception;
 mod extern_type;
 mod fmt;
 mod function;
 mod hash;
+pub mod list;
 mod lossy;
 pub mod memory;
 mod opaque;
@@ -447,6 +459,7 @@ mod rust_str;
 mod rust_string;
 mod rust_type;
 mod rust_vec;
+pub mod set;
 mod shared_ptr;
 #[path = "cxx_string.rs"]
 mod string;
@@ -457,6 +470,11 @@ mod unwind;
 pub mod vector;
 mod weak_ptr;
 
+pub use crate::cxx_deque::CxxDeque;
+pub use crate::cxx_iter::CxxIter;
+pub use crate::cxx_list::CxxList;
+pub use crate::cxx_set::CxxSet;
+pub use crate::cxx_unicode_string::{CxxU16String, CxxU32String};
 pub use crate::cxx_vector::CxxVector;
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
@@ -485,23 +503,27 @@ pub type Vector<T> = CxxVector<T>;
 // Not public API.
 #[doc(hidden)]
 pub mod private {
+    pub use crate::cxx_vector::VectorElementOf;
     pub use crate::extern_type::{verify_extern_kind, verify_extern_type};
+    pub use crate::fmt::debug;
     pub use crate::function::FatFunction;
     pub use crate::hash::hash;
     pub use crate::opaque::Opaque;
     #[cfg(feature = "alloc")]
-    pub use crate::result::{Result, r#try};
+    pub use crate::result::{Result, r#try, try_with_details};
     pub use crate::rust_slice::RustSlice;
     pub use crate::rust_str::RustStr;
     #[cfg(feature = "alloc")]
     pub use crate::rust_string::RustString;
     pub use crate::rust_type::{
-        ImplBox, ImplVec, RustType, Without, require_box, require_unpin, require_vec, with,
+        ImplArc, ImplBox, ImplVec, ImplVecOf, RustType, Without, require_box, require_sync,
+        require_unpin, require_vec, with,
     };
     #[cfg(feature = "alloc")]
     pub use crate::rust_vec::RustVec;
     pub use crate::string::StackString;
-    pub use crate::unwind::prevent_unwind;
+    pub use crate::unique_ptr::verify_unique_ptr_deleter;
+    pub use crate::unwind::{catch_unwind, prevent_unwind};
     pub use cxxbridge_macro::type_id;
 }
 
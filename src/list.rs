@@ -0,0 +1,7 @@
+//! Less used details of `CxxList`.
+//!
+//! `CxxList` itself is exposed at the crate root.
+
+pub use crate::cxx_list::ListElement;
+#[doc(no_inline)]
+pub use cxx::CxxList;
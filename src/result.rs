@@ -3,12 +3,14 @@
 
 use crate::exception::Exception;
 use alloc::boxed::Box;
+use alloc::format;
 use alloc::string::{String, ToString};
-use core::fmt::Display;
+use alloc::vec::Vec;
+use core::error::Error as StdError;
+use core::fmt::{Debug, Display};
+use core::iter;
 use core::ptr::{self, NonNull};
 use core::result::Result as StdResult;
-use core::slice;
-use core::str;
 
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -36,7 +38,74 @@ where
     }
 }
 
-unsafe fn to_c_error(msg: String) -> Result {
+// Used by extern "Rust" functions marked #[error_details]. In addition to the
+// Display message, C++ receives the Debug representation and the Display of
+// every error in the source() chain starting with the error itself. No
+// backtrace is captured here, since by now it would only show the bridge
+// shims; an error that captured its own, such as anyhow::Error, includes it in
+// its Debug representation.
+pub unsafe fn try_with_details<T, E>(ret: *mut T, result: StdResult<T, E>) -> Result
+where
+    E: Display + Debug + Into<Box<dyn StdError>>,
+{
+    match result {
+        Ok(ok) => {
+            unsafe { ptr::write(ret, ok) }
+            Result { ok: ptr::null() }
+        }
+        Err(err) => unsafe { to_c_error_details(err) },
+    }
+}
+
+unsafe fn to_c_error_details<E>(err: E) -> Result
+where
+    E: Display + Debug + Into<Box<dyn StdError>>,
+{
+    let what = err.to_string();
+    let debug = format!("{:?}", err);
+    let err: Box<dyn StdError> = err.into();
+    let chain: Vec<String> = iter::successors(Some(&*err), |&err| err.source())
+        .map(ToString::to_string)
+        .collect();
+
+    let chain: Vec<PtrLen> = chain
+        .iter()
+        .map(|msg| PtrLen {
+            ptr: NonNull::from(msg.as_bytes()).cast(),
+            len: msg.len(),
+        })
+        .collect();
+
+    unsafe extern "C" {
+        #[link_name = "cxxbridge1$error$details"]
+        fn error_details(
+            what: *const u8,
+            what_len: usize,
+            debug: *const u8,
+            debug_len: usize,
+            chain: *const PtrLen,
+            chain_len: usize,
+        ) -> NonNull<u8>;
+    }
+
+    let details = unsafe {
+        error_details(
+            what.as_ptr(),
+            what.len(),
+            debug.as_ptr(),
+            debug.len(),
+            chain.as_ptr(),
+            chain.len(),
+        )
+    };
+    let err = PtrLen {
+        ptr: details,
+        len: what.len(),
+    };
+    Result { err }
+}
+
+pub(crate) unsafe fn to_c_error(msg: String) -> Result {
     let ptr = msg.as_ptr();
     let len = msg.len();
 
@@ -56,12 +125,8 @@ impl Result {
             if self.ok.is_null() {
                 Ok(())
             } else {
-                let err = self.err;
-                let slice = slice::from_raw_parts_mut(err.ptr.as_ptr(), err.len);
-                let s = str::from_utf8_unchecked_mut(slice);
-                Err(Exception {
-                    what: Box::from_raw(s),
-                })
+                let exception = self.err.ptr.as_ptr().cast::<Exception>();
+                Err(*Box::from_raw(exception))
             }
         }
     }
@@ -2,16 +2,23 @@
 
 use crate::extern_type::ExternType;
 use crate::kind::Trivial;
-use core::marker::{PhantomData, Unpin};
+use core::marker::{PhantomData, Sync, Unpin};
 use core::ops::Deref;
 
 pub unsafe trait RustType {}
 pub unsafe trait ImplBox {}
+// C++ is free to copy a rust::Arc<T> to another thread, so the target of an
+// Arc in a bridge is required to be Send and Sync.
+pub unsafe trait ImplArc: Send + Sync {}
 pub unsafe trait ImplVec {}
+pub unsafe trait ImplVecOf<W> {}
 
 // Opaque Rust types are required to be Unpin.
 pub fn require_unpin<T: ?Sized + Unpin>() {}
 
+// Types exposed through an extern C++ static are required to be Sync.
+pub fn require_sync<T: ?Sized + Sync>() {}
+
 pub fn require_box<T: ImplBox>() {}
 pub fn require_vec<T: ImplVec>() {}
 
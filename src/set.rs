@@ -0,0 +1,7 @@
+//! Less used details of `CxxSet`.
+//!
+//! `CxxSet` itself is exposed at the crate root.
+
+pub use crate::cxx_set::SetElement;
+#[doc(no_inline)]
+pub use cxx::CxxSet;
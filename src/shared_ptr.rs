@@ -206,6 +206,79 @@ where
         self.as_ptr().cast_mut()
     }
 
+    /// Returns the number of SharedPtr and C++ std::shared\_ptr instances
+    /// sharing ownership of the managed object, or 0 if **empty**.
+    ///
+    /// Matches the behavior of [std::shared_ptr\<T\>::use_count](https://en.cppreference.com/w/cpp/memory/shared_ptr/use_count).
+    /// In a multithreaded program the value may be stale by the time it is
+    /// returned.
+    pub fn use_count(&self) -> usize {
+        let this = ptr::from_ref::<Self>(self).cast::<c_void>();
+        unsafe { T::__use_count(this) }
+    }
+
+    /// Returns true if the two SharedPtrs hold the same stored pointer.
+    ///
+    /// This matches the behavior of comparing two std::shared\_ptr with
+    /// `operator==`, unlike the PartialEq impl of SharedPtr which compares the
+    /// pointed-to values.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        ptr::eq(this.as_ptr(), other.as_ptr())
+    }
+
+    /// Makes a SharedPtr\<U\> which shares ownership of the object managed by
+    /// `self`, but whose stored pointer is `ptr`.
+    ///
+    /// Matches the behavior of std::shared\_ptr's aliasing constructor
+    /// `shared_ptr(const shared_ptr<Y> &, element_type *)`. The result keeps
+    /// the managed object of `self` alive, so it is typically used to point at
+    /// a member of that object. See [`project`][Self::project] for a safe
+    /// alternative.
+    ///
+    /// # Safety
+    ///
+    /// Pointer must either be null or point to a valid instance of U for as
+    /// long as the object managed by `self` is alive.
+    pub unsafe fn alias<U>(&self, ptr: *mut U) -> SharedPtr<U>
+    where
+        U: SharedPtrTarget,
+    {
+        let this = ptr::from_ref::<Self>(self).cast::<c_void>();
+        let mut erased = MaybeUninit::<[MaybeUninit<*mut c_void>; 2]>::uninit();
+        let erased = erased.as_mut_ptr().cast();
+        let mut shared_ptr = MaybeUninit::<SharedPtr<U>>::uninit();
+        let new = shared_ptr.as_mut_ptr().cast();
+        unsafe {
+            T::__erase(this, erased);
+            U::__alias(erased, ptr, new);
+            shared_ptr.assume_init()
+        }
+    }
+
+    /// Makes a SharedPtr\<U\> to a part of the object pointed to by `self`,
+    /// which keeps the whole managed object alive.
+    ///
+    /// The closure receives the pointed-to object and returns a reference into
+    /// it, such as to one of its fields.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the SharedPtr holds a null stored pointer.
+    pub fn project<U>(&self, f: impl FnOnce(&T) -> &U) -> SharedPtr<U>
+    where
+        U: SharedPtrTarget,
+    {
+        let target = match self.as_ref() {
+            Some(target) => target,
+            None => panic!(
+                "called project on a null SharedPtr<{}>",
+                display(T::__typename),
+            ),
+        };
+        let ptr = ptr::from_ref::<U>(f(target)).cast_mut();
+        unsafe { self.alias(ptr) }
+    }
+
     /// Constructs new WeakPtr as a non-owning reference to the object managed
     /// by `self`. If `self` manages no object, the WeakPtr manages no object
     /// too.
@@ -397,6 +470,12 @@ pub unsafe trait SharedPtrTarget {
     unsafe fn __get(this: *const c_void) -> *const Self;
     #[doc(hidden)]
     unsafe fn __drop(this: *mut c_void);
+    #[doc(hidden)]
+    unsafe fn __use_count(this: *const c_void) -> usize;
+    #[doc(hidden)]
+    unsafe fn __erase(this: *const c_void, erased: *mut c_void);
+    #[doc(hidden)]
+    unsafe fn __alias(erased: *mut c_void, ptr: *mut Self, new: *mut c_void);
 }
 
 macro_rules! impl_shared_ptr_target {
@@ -447,6 +526,27 @@ macro_rules! impl_shared_ptr_target {
                 }
                 unsafe { __drop(this) }
             }
+            unsafe fn __use_count(this: *const c_void) -> usize {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$shared_ptr$", $segment, "$use_count")]
+                    fn __use_count(this: *const c_void) -> usize;
+                }
+                unsafe { __use_count(this) }
+            }
+            unsafe fn __erase(this: *const c_void, erased: *mut c_void) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$shared_ptr$", $segment, "$erase")]
+                    fn __erase(this: *const c_void, erased: *mut c_void);
+                }
+                unsafe { __erase(this, erased) }
+            }
+            unsafe fn __alias(erased: *mut c_void, ptr: *mut Self, new: *mut c_void) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$shared_ptr$", $segment, "$alias")]
+                    fn __alias(erased: *mut c_void, ptr: *mut c_void, new: *mut c_void);
+                }
+                unsafe { __alias(erased, ptr.cast::<c_void>(), new) }
+            }
         }
     };
 }
@@ -1,18 +1,39 @@
 #![cfg(feature = "alloc")]
 
-use crate::result::PtrLen;
+use crate::exception::Exception;
 use alloc::boxed::Box;
 use alloc::string::String;
-use core::ptr::NonNull;
+use core::ffi::c_void;
 use core::slice;
 
 #[unsafe(export_name = "cxxbridge1$exception")]
-unsafe extern "C" fn exception(ptr: *const u8, len: usize) -> PtrLen {
+unsafe extern "C" fn exception(
+    what: *const u8,
+    what_len: usize,
+    type_name: *const u8,
+    type_name_len: usize,
+    source: *mut c_void,
+) -> *mut c_void {
+    let what = unsafe { lossy_boxed_str(what, what_len) };
+    let type_name = if type_name.is_null() {
+        None
+    } else {
+        Some(unsafe { lossy_boxed_str(type_name, type_name_len) })
+    };
+    let source = if source.is_null() {
+        None
+    } else {
+        Some(unsafe { Box::from_raw(source.cast::<Exception>()) })
+    };
+    let exception = Exception {
+        what,
+        type_name,
+        source,
+    };
+    Box::into_raw(Box::new(exception)).cast()
+}
+
+unsafe fn lossy_boxed_str(ptr: *const u8, len: usize) -> Box<str> {
     let slice = unsafe { slice::from_raw_parts(ptr, len) };
-    let string = String::from_utf8_lossy(slice);
-    let len = string.len();
-    let raw_str = Box::into_raw(string.into_owned().into_boxed_str());
-    let raw_u8 = raw_str.cast::<u8>();
-    let nonnull = unsafe { NonNull::new_unchecked(raw_u8) };
-    PtrLen { ptr: nonnull, len }
+    String::from_utf8_lossy(slice).into_owned().into_boxed_str()
 }
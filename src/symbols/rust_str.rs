@@ -1,6 +1,8 @@
+use crate::cxx_unicode_string::CxxU16String;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
 use core::mem::MaybeUninit;
+use core::pin::Pin;
 use core::ptr;
 use core::slice;
 use core::str;
@@ -32,6 +34,13 @@ unsafe extern "C" fn str_from(this: &mut MaybeUninit<&str>, ptr: *const u8, len:
     }
 }
 
+#[unsafe(export_name = "cxxbridge1$str$to_utf16")]
+unsafe extern "C" fn str_to_utf16(ptr: *const u8, len: usize, out: Pin<&mut CxxU16String>) {
+    let slice = unsafe { slice::from_raw_parts(ptr, len) };
+    let s = unsafe { str::from_utf8_unchecked(slice) };
+    out.push_str(s);
+}
+
 #[unsafe(export_name = "cxxbridge1$str$ptr")]
 unsafe extern "C" fn str_ptr(this: &&str) -> *const u8 {
     this.as_ptr()
@@ -2,6 +2,8 @@
 
 use crate::rust_string::RustString;
 use crate::rust_vec::RustVec;
+use crate::shared_ptr::SharedPtr;
+use crate::string::CxxString;
 use alloc::vec::Vec;
 use core::ffi::c_char;
 use core::mem;
@@ -53,6 +55,8 @@ macro_rules! rust_vec_shims {
 macro_rules! rust_vec_shims_for_primitive {
     ($ty:ident) => {
         rust_vec_shims!(stringify!($ty), $ty);
+        rust_vec_shims!(concat!("rust_vec$", stringify!($ty)), Vec<$ty>);
+        rust_vec_shims!(concat!("std$shared_ptr$", stringify!($ty)), SharedPtr<$ty>);
     };
 }
 
@@ -73,3 +77,8 @@ rust_vec_shims_for_primitive!(f64);
 rust_vec_shims!("char", c_char);
 rust_vec_shims!("string", RustString);
 rust_vec_shims!("str", &str);
+
+rust_vec_shims!("rust_vec$char", Vec<c_char>);
+rust_vec_shims!("rust_vec$string", Vec<RustString>);
+rust_vec_shims!("rust_vec$str", Vec<&str>);
+rust_vec_shims!("std$shared_ptr$string", SharedPtr<CxxString>);
@@ -1,8 +1,14 @@
+use crate::cxx_deque::{CxxDeque, DequeElement};
+use crate::cxx_list::{CxxList, ListElement};
+use crate::cxx_set::{CxxSet, SetElement};
+use crate::cxx_unicode_string::{CxxU16String, CxxU32String};
 use crate::cxx_vector::{CxxVector, VectorElement};
 use crate::extern_type::ExternType;
 use crate::fmt::display;
 use crate::kind::Trivial;
 use crate::string::CxxString;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 #[cfg(feature = "std")]
 use alloc::string::String;
 #[cfg(feature = "std")]
@@ -15,14 +21,21 @@ use core::marker::PhantomData;
 use core::mem::{self, MaybeUninit};
 use core::ops::{Deref, DerefMut};
 use core::pin::Pin;
+#[cfg(feature = "alloc")]
+use core::ptr;
 #[cfg(feature = "std")]
 use std::io::{self, IoSlice, Read, Seek, SeekFrom, Write};
 
 /// Binding to C++ `std::unique_ptr<T, std::default_delete<T>>`.
+///
+/// `UniquePtr<[T]>` owns an array allocated by C++ `new T[len]` and frees it
+/// with `delete[]`. It is not a binding of `std::unique_ptr<T[]>`: it also
+/// keeps track of the length, is not layout compatible with the C++ type, and
+/// cannot appear in bridge signatures. See [`UniquePtr::new_slice`].
 #[repr(C)]
 pub struct UniquePtr<T>
 where
-    T: UniquePtrTarget,
+    T: ?Sized + UniquePtrTarget,
 {
     repr: MaybeUninit<*mut c_void>,
     ty: PhantomData<T>,
@@ -135,16 +148,16 @@ where
     }
 }
 
-unsafe impl<T> Send for UniquePtr<T> where T: Send + UniquePtrTarget {}
-unsafe impl<T> Sync for UniquePtr<T> where T: Sync + UniquePtrTarget {}
+unsafe impl<T> Send for UniquePtr<T> where T: ?Sized + Send + UniquePtrTarget {}
+unsafe impl<T> Sync for UniquePtr<T> where T: ?Sized + Sync + UniquePtrTarget {}
 
 // UniquePtr is not a self-referential type and is safe to move out of a Pin,
 // regardless whether the pointer's target is Unpin.
-impl<T> Unpin for UniquePtr<T> where T: UniquePtrTarget {}
+impl<T> Unpin for UniquePtr<T> where T: ?Sized + UniquePtrTarget {}
 
 impl<T> Drop for UniquePtr<T>
 where
-    T: UniquePtrTarget,
+    T: ?Sized + UniquePtrTarget,
 {
     fn drop(&mut self) {
         unsafe { T::__drop(self.repr) }
@@ -248,6 +261,155 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T> UniquePtr<[T]>
+where
+    T: VectorElement + ExternType<Kind = Trivial>,
+{
+    /// Allocates an array of `len` value-initialized elements using C++
+    /// `new T[len]()`, so primitive elements start out as zero.
+    ///
+    /// The array is freed with `delete[]`, either when this UniquePtr is
+    /// dropped or by a C++ std::unique\_ptr\<T\[\]\> that has taken over the
+    /// pointer from [`into_raw_parts`][Self::into_raw_parts].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the C++ element type is not default constructible.
+    pub fn new_slice(len: usize) -> Self {
+        let data = T::__unique_ptr_array_new(len);
+        unsafe { Self::from_raw_parts(data, len) }
+    }
+
+    /// Allocates an array with `new T[len]()` and clones the elements of
+    /// `slice` into it.
+    pub fn from_slice(slice: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut array = Self::new_slice(slice.len());
+        array.clone_from_slice(slice);
+        array
+    }
+
+    /// Checks whether the UniquePtr does not own an array.
+    pub fn is_null(&self) -> bool {
+        self.as_ptr().is_null()
+    }
+
+    /// Returns the number of elements in the owned array, or zero if null.
+    pub fn len(&self) -> usize {
+        self.as_ptr().len()
+    }
+
+    /// Returns true if the UniquePtr is null or owns an array of length zero.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a slice of the array owned by this UniquePtr if any, otherwise
+    /// None.
+    pub fn as_ref(&self) -> Option<&[T]> {
+        let ptr = self.as_ptr();
+        unsafe { ptr.as_ref() }
+    }
+
+    /// Returns a mutable slice of the array owned by this UniquePtr if any,
+    /// otherwise None.
+    pub fn as_mut(&mut self) -> Option<&mut [T]> {
+        let ptr = self.as_mut_ptr();
+        unsafe { ptr.as_mut() }
+    }
+
+    /// Returns a raw const pointer to the array owned by this UniquePtr, or a
+    /// null pointer of length zero.
+    pub fn as_ptr(&self) -> *const [T] {
+        unsafe { <[T]>::__get(self.repr) }
+    }
+
+    /// Returns a raw mutable pointer to the array owned by this UniquePtr, or
+    /// a null pointer of length zero.
+    pub fn as_mut_ptr(&self) -> *mut [T] {
+        self.as_ptr().cast_mut()
+    }
+
+    /// Consumes the UniquePtr, releasing its ownership of the heap-allocated
+    /// array and returning its data pointer and length.
+    ///
+    /// The data pointer is suitable for adopting into a C++
+    /// std::unique\_ptr\<T\[\]\>, which will free it with `delete[]`.
+    pub fn into_raw_parts(self) -> (*mut T, usize) {
+        let raw = unsafe { <[T]>::__release(self.repr) };
+        mem::forget(self);
+        (raw.cast::<T>(), raw.len())
+    }
+
+    /// Constructs a UniquePtr retaking ownership of an array previously
+    /// obtained from `into_raw_parts`, or released from a C++
+    /// std::unique\_ptr\<T\[\]\>.
+    ///
+    /// # Safety
+    ///
+    /// `data` must be null or have come from an array new-expression `new
+    /// T[len]`. As with [`UniquePtr::from_raw`], a double-free may occur if the
+    /// function is called twice on the same pointer.
+    pub unsafe fn from_raw_parts(data: *mut T, len: usize) -> Self {
+        let raw = ptr::slice_from_raw_parts_mut(data, len);
+        UniquePtr {
+            repr: unsafe { <[T]>::__raw(raw) },
+            ty: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Deref for UniquePtr<[T]>
+where
+    T: VectorElement + ExternType<Kind = Trivial>,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        match self.as_ref() {
+            Some(target) => target,
+            None => panic!(
+                "called deref on a null UniquePtr<{}>",
+                display(<[T]>::__typename),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> DerefMut for UniquePtr<[T]>
+where
+    T: VectorElement + ExternType<Kind = Trivial>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self.as_mut() {
+            Some(target) => target,
+            None => panic!(
+                "called deref_mut on a null UniquePtr<{}>",
+                display(<[T]>::__typename),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Debug for UniquePtr<[T]>
+where
+    T: Debug + VectorElement + ExternType<Kind = Trivial>,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.as_ref() {
+            None => formatter.write_str("nullptr"),
+            Some(value) => Debug::fmt(value, formatter),
+        }
+    }
+}
+
 /// Forwarding `Read` trait implementation in a manner similar to `Box<T>`.
 ///
 /// Note that the implementation will panic for null `UniquePtr<T>`.
@@ -400,6 +562,36 @@ pub unsafe trait UniquePtrTarget {
     unsafe fn __release(repr: MaybeUninit<*mut c_void>) -> *mut Self;
     #[doc(hidden)]
     unsafe fn __drop(repr: MaybeUninit<*mut c_void>);
+    #[doc(hidden)]
+    const __DELETER: Option<&'static str> = None;
+}
+
+// Checks that a type alias in one bridge names the same #[cxx_deleter] as the
+// bridge which declared the type, since the C++ side of each bridge spells
+// std::unique_ptr<T, D> independently.
+#[doc(hidden)]
+pub const fn verify_unique_ptr_deleter<T>(deleter: Option<&str>)
+where
+    T: UniquePtrTarget + ?Sized,
+{
+    let same = match (T::__DELETER, deleter) {
+        (None, None) => true,
+        (Some(expected), Some(actual)) => {
+            let expected = expected.as_bytes();
+            let actual = actual.as_bytes();
+            let mut same = expected.len() == actual.len();
+            let mut i = 0;
+            while same && i < expected.len() {
+                same = expected[i] == actual[i];
+                i += 1;
+            }
+            same
+        }
+        _ => false,
+    };
+    if !same {
+        panic!("type alias must have the same #[cxx_deleter] as the type it refers to");
+    }
 }
 
 unsafe extern "C" {
@@ -442,6 +634,104 @@ unsafe impl UniquePtrTarget for CxxString {
     }
 }
 
+// A std::unique_ptr<T[]> does not know the length of its array, so rather than
+// holding one, the repr of UniquePtr<[T]> points to a boxed fat pointer to the
+// array. The array itself is allocated and freed by C++ new[] and delete[].
+#[cfg(feature = "alloc")]
+unsafe impl<T> UniquePtrTarget for [T]
+where
+    T: VectorElement + ExternType<Kind = Trivial>,
+{
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("[")?;
+        T::__typename(f)?;
+        f.write_str("]")
+    }
+    fn __null() -> MaybeUninit<*mut c_void> {
+        MaybeUninit::new(ptr::null_mut())
+    }
+    unsafe fn __raw(raw: *mut Self) -> MaybeUninit<*mut c_void> {
+        if raw.is_null() {
+            return Self::__null();
+        }
+        MaybeUninit::new(Box::into_raw(Box::new(raw)).cast())
+    }
+    unsafe fn __get(repr: MaybeUninit<*mut c_void>) -> *const Self {
+        let boxed = unsafe { repr.assume_init() }.cast::<*mut Self>();
+        if boxed.is_null() {
+            ptr::slice_from_raw_parts(ptr::null(), 0)
+        } else {
+            unsafe { *boxed }
+        }
+    }
+    unsafe fn __release(repr: MaybeUninit<*mut c_void>) -> *mut Self {
+        let boxed = unsafe { repr.assume_init() }.cast::<*mut Self>();
+        if boxed.is_null() {
+            ptr::slice_from_raw_parts_mut(ptr::null_mut(), 0)
+        } else {
+            *unsafe { Box::from_raw(boxed) }
+        }
+    }
+    unsafe fn __drop(repr: MaybeUninit<*mut c_void>) {
+        let raw = unsafe { Self::__release(repr) };
+        if !raw.is_null() {
+            unsafe { T::__unique_ptr_array_delete(raw.cast::<T>()) }
+        }
+    }
+}
+
+macro_rules! impl_unique_ptr_target_for_string {
+    ($segment:literal, $name:literal, $ty:ty) => {
+        unsafe impl UniquePtrTarget for $ty {
+            fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str($name)
+            }
+            fn __null() -> MaybeUninit<*mut c_void> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$", $segment, "$null")]
+                    fn __null(this: *mut MaybeUninit<*mut c_void>);
+                }
+                let mut repr = MaybeUninit::uninit();
+                unsafe { __null(&raw mut repr) }
+                repr
+            }
+            unsafe fn __raw(raw: *mut Self) -> MaybeUninit<*mut c_void> {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$", $segment, "$raw")]
+                    fn __raw(this: *mut MaybeUninit<*mut c_void>, raw: *mut $ty);
+                }
+                let mut repr = MaybeUninit::uninit();
+                unsafe { __raw(&raw mut repr, raw) }
+                repr
+            }
+            unsafe fn __get(repr: MaybeUninit<*mut c_void>) -> *const Self {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$", $segment, "$get")]
+                    fn __get(this: *const MaybeUninit<*mut c_void>) -> *const $ty;
+                }
+                unsafe { __get(&raw const repr) }
+            }
+            unsafe fn __release(mut repr: MaybeUninit<*mut c_void>) -> *mut Self {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$", $segment, "$release")]
+                    fn __release(this: *mut MaybeUninit<*mut c_void>) -> *mut $ty;
+                }
+                unsafe { __release(&raw mut repr) }
+            }
+            unsafe fn __drop(mut repr: MaybeUninit<*mut c_void>) {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$unique_ptr$std$", $segment, "$drop")]
+                    fn __drop(this: *mut MaybeUninit<*mut c_void>);
+                }
+                unsafe { __drop(&raw mut repr) }
+            }
+        }
+    };
+}
+
+impl_unique_ptr_target_for_string!("u16string", "CxxU16String", CxxU16String);
+impl_unique_ptr_target_for_string!("u32string", "CxxU32String", CxxU32String);
+
 unsafe impl<T> UniquePtrTarget for CxxVector<T>
 where
     T: VectorElement,
@@ -465,3 +755,122 @@ where
         unsafe { T::__unique_ptr_drop(repr) }
     }
 }
+
+unsafe extern "C" {
+    #[link_name = "cxxbridge1$unique_ptr$std$vector$bool$null"]
+    fn unique_ptr_std_vector_bool_null(this: *mut MaybeUninit<*mut c_void>);
+    #[link_name = "cxxbridge1$unique_ptr$std$vector$bool$raw"]
+    fn unique_ptr_std_vector_bool_raw(
+        this: *mut MaybeUninit<*mut c_void>,
+        raw: *mut CxxVector<bool>,
+    );
+    #[link_name = "cxxbridge1$unique_ptr$std$vector$bool$get"]
+    fn unique_ptr_std_vector_bool_get(
+        this: *const MaybeUninit<*mut c_void>,
+    ) -> *const CxxVector<bool>;
+    #[link_name = "cxxbridge1$unique_ptr$std$vector$bool$release"]
+    fn unique_ptr_std_vector_bool_release(
+        this: *mut MaybeUninit<*mut c_void>,
+    ) -> *mut CxxVector<bool>;
+    #[link_name = "cxxbridge1$unique_ptr$std$vector$bool$drop"]
+    fn unique_ptr_std_vector_bool_drop(this: *mut MaybeUninit<*mut c_void>);
+}
+
+unsafe impl UniquePtrTarget for CxxVector<bool> {
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CxxVector<bool>")
+    }
+    fn __null() -> MaybeUninit<*mut c_void> {
+        let mut repr = MaybeUninit::uninit();
+        unsafe {
+            unique_ptr_std_vector_bool_null(&raw mut repr);
+        }
+        repr
+    }
+    unsafe fn __raw(raw: *mut Self) -> MaybeUninit<*mut c_void> {
+        let mut repr = MaybeUninit::uninit();
+        unsafe { unique_ptr_std_vector_bool_raw(&raw mut repr, raw) }
+        repr
+    }
+    unsafe fn __get(repr: MaybeUninit<*mut c_void>) -> *const Self {
+        unsafe { unique_ptr_std_vector_bool_get(&raw const repr) }
+    }
+    unsafe fn __release(mut repr: MaybeUninit<*mut c_void>) -> *mut Self {
+        unsafe { unique_ptr_std_vector_bool_release(&raw mut repr) }
+    }
+    unsafe fn __drop(mut repr: MaybeUninit<*mut c_void>) {
+        unsafe { unique_ptr_std_vector_bool_drop(&raw mut repr) }
+    }
+}
+
+unsafe impl<T> UniquePtrTarget for CxxDeque<T>
+where
+    T: DequeElement,
+{
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CxxDeque<{}>", display(T::__typename))
+    }
+    fn __null() -> MaybeUninit<*mut c_void> {
+        T::__unique_ptr_null()
+    }
+    unsafe fn __raw(raw: *mut Self) -> MaybeUninit<*mut c_void> {
+        unsafe { T::__unique_ptr_raw(raw) }
+    }
+    unsafe fn __get(repr: MaybeUninit<*mut c_void>) -> *const Self {
+        unsafe { T::__unique_ptr_get(repr) }
+    }
+    unsafe fn __release(repr: MaybeUninit<*mut c_void>) -> *mut Self {
+        unsafe { T::__unique_ptr_release(repr) }
+    }
+    unsafe fn __drop(repr: MaybeUninit<*mut c_void>) {
+        unsafe { T::__unique_ptr_drop(repr) }
+    }
+}
+
+unsafe impl<T> UniquePtrTarget for CxxList<T>
+where
+    T: ListElement,
+{
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CxxList<{}>", display(T::__typename))
+    }
+    fn __null() -> MaybeUninit<*mut c_void> {
+        T::__unique_ptr_null()
+    }
+    unsafe fn __raw(raw: *mut Self) -> MaybeUninit<*mut c_void> {
+        unsafe { T::__unique_ptr_raw(raw) }
+    }
+    unsafe fn __get(repr: MaybeUninit<*mut c_void>) -> *const Self {
+        unsafe { T::__unique_ptr_get(repr) }
+    }
+    unsafe fn __release(repr: MaybeUninit<*mut c_void>) -> *mut Self {
+        unsafe { T::__unique_ptr_release(repr) }
+    }
+    unsafe fn __drop(repr: MaybeUninit<*mut c_void>) {
+        unsafe { T::__unique_ptr_drop(repr) }
+    }
+}
+
+unsafe impl<T> UniquePtrTarget for CxxSet<T>
+where
+    T: SetElement,
+{
+    fn __typename(f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CxxSet<{}>", display(T::__typename))
+    }
+    fn __null() -> MaybeUninit<*mut c_void> {
+        T::__unique_ptr_null()
+    }
+    unsafe fn __raw(raw: *mut Self) -> MaybeUninit<*mut c_void> {
+        unsafe { T::__unique_ptr_raw(raw) }
+    }
+    unsafe fn __get(repr: MaybeUninit<*mut c_void>) -> *const Self {
+        unsafe { T::__unique_ptr_get(repr) }
+    }
+    unsafe fn __release(repr: MaybeUninit<*mut c_void>) -> *mut Self {
+        unsafe { T::__unique_ptr_release(repr) }
+    }
+    unsafe fn __drop(repr: MaybeUninit<*mut c_void>) {
+        unsafe { T::__unique_ptr_drop(repr) }
+    }
+}
@@ -1,6 +1,10 @@
 #![allow(missing_docs)]
 
+#[cfg(feature = "std")]
+use alloc::string::String;
 use core::mem;
+#[cfg(feature = "std")]
+use std::panic::{self, AssertUnwindSafe};
 
 pub fn prevent_unwind<F, R>(label: &'static str, foreign_call: F) -> R
 where
@@ -37,3 +41,57 @@ impl Drop for Guard {
         panic!("panic in ffi function {}, aborting.", self.label);
     }
 }
+
+#[cfg(feature = "std")]
+pub unsafe fn catch_unwind<F>(
+    label: &'static str,
+    panicked: *mut bool,
+    rust_call: F,
+) -> crate::result::Result
+where
+    F: FnOnce() -> crate::result::Result,
+{
+    // Used by extern "Rust" functions marked #[catch_unwind]. Instead of
+    // aborting, a panic is caught here and its message handed to C++ in the
+    // same representation as an Err returned by a Result-returning function.
+    // The C++ side distinguishes the two through `panicked` and rethrows the
+    // panic as rust::Panic.
+    match panic::catch_unwind(AssertUnwindSafe(rust_call)) {
+        Ok(ret) => ret,
+        Err(payload) => {
+            let msg = if let Some(msg) = payload.downcast_ref::<&'static str>() {
+                String::from(*msg)
+            } else if let Some(msg) = payload.downcast_ref::<String>() {
+                msg.clone()
+            } else {
+                String::from("Box<dyn Any>")
+            };
+            // Dropping the payload runs arbitrary user code, which must not be
+            // allowed to unwind out of here.
+            prevent_unwind(label, || mem::drop(payload));
+            unsafe {
+                *panicked = true;
+                crate::result::to_c_error(msg)
+            }
+        }
+    }
+}
+
+// Without std there is no way to catch a panic. Any use of #[catch_unwind]
+// resolves to this signature instead, whose unsatisfiable bound produces a
+// targeted error message rather than an unresolved path.
+#[cfg(not(feature = "std"))]
+#[diagnostic::on_unimplemented(
+    message = "#[catch_unwind] requires the \"std\" feature of the cxx crate",
+    label = "panics can only be caught with std"
+)]
+pub trait CatchUnwindRequiresStd {}
+
+#[cfg(not(feature = "std"))]
+pub unsafe fn catch_unwind<F, R>(label: &'static str, panicked: *mut bool, rust_call: F) -> R
+where
+    F: FnOnce() -> R + CatchUnwindRequiresStd,
+{
+    let _ = panicked;
+    prevent_unwind(label, rust_call)
+}
@@ -4,6 +4,6 @@
 
 #[doc(inline)]
 pub use crate::Vector;
-pub use crate::cxx_vector::{Iter, IterMut, VectorElement};
+pub use crate::cxx_vector::{BoolIter, Iter, IterMut, VectorElement};
 #[doc(no_inline)]
 pub use cxx::CxxVector;
@@ -53,6 +53,23 @@ where
             shared_ptr.assume_init()
         }
     }
+
+    /// Returns the number of SharedPtr and C++ std::shared\_ptr instances
+    /// sharing ownership of the managed object, or 0 if none remain.
+    ///
+    /// Matches the behavior of [std::weak_ptr\<T\>::use_count](https://en.cppreference.com/w/cpp/memory/weak_ptr/use_count).
+    pub fn use_count(&self) -> usize {
+        let this = ptr::from_ref::<Self>(self).cast::<c_void>();
+        unsafe { T::__use_count(this) }
+    }
+
+    /// Checks whether the managed object has already been deleted, in which
+    /// case [`upgrade`][Self::upgrade] would produce a null SharedPtr.
+    ///
+    /// Matches the behavior of [std::weak_ptr\<T\>::expired](https://en.cppreference.com/w/cpp/memory/weak_ptr/expired).
+    pub fn expired(&self) -> bool {
+        self.use_count() == 0
+    }
 }
 
 unsafe impl<T> Send for WeakPtr<T> where T: Send + Sync + WeakPtrTarget {}
@@ -110,6 +127,8 @@ pub unsafe trait WeakPtrTarget {
     unsafe fn __upgrade(weak: *const c_void, shared: *mut c_void);
     #[doc(hidden)]
     unsafe fn __drop(this: *mut c_void);
+    #[doc(hidden)]
+    unsafe fn __use_count(this: *const c_void) -> usize;
 }
 
 macro_rules! impl_weak_ptr_target {
@@ -153,6 +172,13 @@ macro_rules! impl_weak_ptr_target {
                 }
                 unsafe { __drop(this) }
             }
+            unsafe fn __use_count(this: *const c_void) -> usize {
+                unsafe extern "C" {
+                    #[link_name = concat!("cxxbridge1$std$weak_ptr$", $segment, "$use_count")]
+                    fn __use_count(this: *const c_void) -> usize;
+                }
+                unsafe { __use_count(this) }
+            }
         }
     };
 }
@@ -19,6 +19,8 @@ pub(crate) enum Atom {
     F32,
     F64,
     CxxString,
+    CxxU16String,
+    CxxU32String,
     RustString,
 }
 
@@ -45,6 +47,8 @@ impl Atom {
             "f32" => Some(F32),
             "f64" => Some(F64),
             "CxxString" => Some(CxxString),
+            "CxxU16String" => Some(CxxU16String),
+            "CxxU32String" => Some(CxxU32String),
             "String" => Some(RustString),
             _ => None,
         }
@@ -76,6 +80,8 @@ impl AsRef<str> for Atom {
             F32 => "f32",
             F64 => "f64",
             CxxString => "CxxString",
+            CxxU16String => "CxxU16String",
+            CxxU32String => "CxxU32String",
             RustString => "String",
         }
     }
@@ -3,9 +3,11 @@ use crate::syntax::namespace::Namespace;
 use crate::syntax::report::Errors;
 use crate::syntax::repr::Repr;
 use crate::syntax::{Derive, Doc, ForeignName, cfg};
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
 use syn::parse::ParseStream;
-use syn::{Attribute, Error, Expr, Lit, LitStr, Meta, Path, Result, Token};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned as _;
+use syn::{Attribute, Error, Expr, Lit, LitStr, Meta, Path, Result, Token, Type};
 
 // Intended usage:
 //
@@ -36,6 +38,14 @@ pub(crate) struct Parser<'a> {
     pub cxx_name: Option<&'a mut Option<ForeignName>>,
     pub rust_name: Option<&'a mut Option<Ident>>,
     pub self_type: Option<&'a mut Option<Ident>>,
+    pub cxx_default: Option<&'a mut Option<LitStr>>,
+    pub cxx_char16: Option<&'a mut bool>,
+    pub cxx_deleter: Option<&'a mut Option<LitStr>>,
+    pub enable_shared_from_this: Option<&'a mut Option<Span>>,
+    pub catch_unwind: Option<&'a mut bool>,
+    pub error_details: Option<&'a mut bool>,
+    pub instantiate: Option<&'a mut Vec<Ident>>,
+    pub cxx_template: Option<&'a mut Option<Vec<Type>>>,
     pub ignore_unrecognized: bool,
 
     // Suppress clippy needless_update lint ("struct update has no effect, all
@@ -156,6 +166,110 @@ pub(crate) fn parse(cx: &mut Errors, attrs: Vec<Attribute>, mut parser: Parser)
                     break;
                 }
             }
+        } else if attr_path.is_ident("cxx_default") {
+            match parse_cxx_default_attribute(&attr.meta) {
+                Ok(attr) => {
+                    if let Some(cxx_default) = &mut parser.cxx_default {
+                        **cxx_default = Some(attr);
+                        continue;
+                    }
+                }
+                Err(err) => {
+                    cx.push(err);
+                    break;
+                }
+            }
+        } else if attr_path.is_ident("cxx_char16") {
+            match parse_cxx_char16_attribute(&attr.meta) {
+                Ok(()) => {
+                    if let Some(cxx_char16) = &mut parser.cxx_char16 {
+                        **cxx_char16 = true;
+                        continue;
+                    }
+                }
+                Err(err) => {
+                    cx.push(err);
+                    break;
+                }
+            }
+        } else if attr_path.is_ident("cxx_deleter") {
+            match parse_cxx_deleter_attribute(&attr.meta) {
+                Ok(attr) => {
+                    if let Some(cxx_deleter) = &mut parser.cxx_deleter {
+                        **cxx_deleter = Some(attr);
+                        continue;
+                    }
+                }
+                Err(err) => {
+                    cx.push(err);
+                    break;
+                }
+            }
+        } else if attr_path.is_ident("enable_shared_from_this") {
+            match parse_enable_shared_from_this_attribute(&attr.meta) {
+                Ok(span) => {
+                    if let Some(enable_shared_from_this) = &mut parser.enable_shared_from_this {
+                        **enable_shared_from_this = Some(span);
+                        continue;
+                    }
+                }
+                Err(err) => {
+                    cx.push(err);
+                    break;
+                }
+            }
+        } else if attr_path.is_ident("catch_unwind") {
+            match parse_catch_unwind_attribute(&attr.meta) {
+                Ok(()) => {
+                    if let Some(catch_unwind) = &mut parser.catch_unwind {
+                        **catch_unwind = true;
+                        continue;
+                    }
+                }
+                Err(err) => {
+                    cx.push(err);
+                    break;
+                }
+            }
+        } else if attr_path.is_ident("error_details") {
+            match parse_error_details_attribute(&attr.meta) {
+                Ok(()) => {
+                    if let Some(error_details) = &mut parser.error_details {
+                        **error_details = true;
+                        continue;
+                    }
+                }
+                Err(err) => {
+                    cx.push(err);
+                    break;
+                }
+            }
+        } else if attr_path.is_ident("instantiate") {
+            match parse_instantiate_attribute(&attr) {
+                Ok(attr) => {
+                    if let Some(instantiate) = &mut parser.instantiate {
+                        instantiate.extend(attr);
+                        continue;
+                    }
+                }
+                Err(err) => {
+                    cx.push(err);
+                    break;
+                }
+            }
+        } else if attr_path.is_ident("cxx_template") {
+            match attr.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated) {
+                Ok(attr) => {
+                    if let Some(cxx_template) = &mut parser.cxx_template {
+                        **cxx_template = Some(attr.into_iter().collect());
+                        continue;
+                    }
+                }
+                Err(err) => {
+                    cx.push(err);
+                    break;
+                }
+            }
         } else if attr_path.is_ident("cfg") {
             match cfg::parse_attribute(&attr) {
                 Ok(cfg_expr) => {
@@ -256,6 +370,53 @@ fn parse_default_attribute(meta: &Meta) -> Result<()> {
     ))
 }
 
+fn parse_catch_unwind_attribute(meta: &Meta) -> Result<()> {
+    let error_span = match meta {
+        Meta::Path(_) => return Ok(()),
+        Meta::List(meta) => meta.delimiter.span().open(),
+        Meta::NameValue(meta) => meta.eq_token.span,
+    };
+    Err(Error::new(
+        error_span,
+        "#[catch_unwind] attribute does not accept an argument",
+    ))
+}
+
+fn parse_error_details_attribute(meta: &Meta) -> Result<()> {
+    let error_span = match meta {
+        Meta::Path(_) => return Ok(()),
+        Meta::List(meta) => meta.delimiter.span().open(),
+        Meta::NameValue(meta) => meta.eq_token.span,
+    };
+    Err(Error::new(
+        error_span,
+        "#[error_details] attribute does not accept an argument",
+    ))
+}
+
+fn parse_enable_shared_from_this_attribute(meta: &Meta) -> Result<Span> {
+    let error_span = match meta {
+        Meta::Path(path) => return Ok(path.span()),
+        Meta::List(meta) => meta.delimiter.span().open(),
+        Meta::NameValue(meta) => meta.eq_token.span,
+    };
+    Err(Error::new(
+        error_span,
+        "#[enable_shared_from_this] attribute does not accept an argument",
+    ))
+}
+
+fn parse_instantiate_attribute(attr: &Attribute) -> Result<Vec<Ident>> {
+    let types = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+    if types.is_empty() {
+        return Err(Error::new_spanned(
+            attr,
+            "#[instantiate] requires at least one type",
+        ));
+    }
+    Ok(types.into_iter().collect())
+}
+
 fn parse_cxx_name_attribute(meta: &Meta) -> Result<ForeignName> {
     if let Meta::NameValue(meta) = meta {
         match &meta.value {
@@ -275,6 +436,46 @@ fn parse_cxx_name_attribute(meta: &Meta) -> Result<ForeignName> {
     Err(Error::new_spanned(meta, "unsupported cxx_name attribute"))
 }
 
+fn parse_cxx_default_attribute(meta: &Meta) -> Result<LitStr> {
+    if let Meta::NameValue(meta) = meta {
+        if let Expr::Lit(expr) = &meta.value {
+            if let Lit::Str(lit) = &expr.lit {
+                return Ok(lit.clone());
+            }
+        }
+    }
+    Err(Error::new_spanned(
+        meta,
+        "unsupported cxx_default attribute",
+    ))
+}
+
+fn parse_cxx_char16_attribute(meta: &Meta) -> Result<()> {
+    let error_span = match meta {
+        Meta::Path(_) => return Ok(()),
+        Meta::List(meta) => meta.delimiter.span().open(),
+        Meta::NameValue(meta) => meta.eq_token.span,
+    };
+    Err(Error::new(
+        error_span,
+        "#[cxx_char16] attribute does not accept an argument",
+    ))
+}
+
+fn parse_cxx_deleter_attribute(meta: &Meta) -> Result<LitStr> {
+    if let Meta::NameValue(meta) = meta {
+        if let Expr::Lit(expr) = &meta.value {
+            if let Lit::Str(lit) = &expr.lit {
+                return Ok(lit.clone());
+            }
+        }
+    }
+    Err(Error::new_spanned(
+        meta,
+        "unsupported cxx_deleter attribute",
+    ))
+}
+
 fn parse_rust_ident_attribute(meta: &Meta) -> Result<Ident> {
     if let Meta::NameValue(meta) = meta {
         match &meta.value {
@@ -1,16 +1,17 @@
 use crate::syntax::atom::Atom::{self, *};
 use crate::syntax::message::Message;
 use crate::syntax::report::Errors;
+use crate::syntax::set::UnorderedSet;
 use crate::syntax::visit::{self, Visit};
 use crate::syntax::{
-    Api, Array, Enum, ExternFn, ExternType, FnKind, Impl, Lang, Lifetimes, NamedType, Ptr,
-    Receiver, Ref, Signature, SliceRef, Struct, Trait, Ty1, Type, TypeAlias, Types, error, ident,
-    trivial,
+    Api, Array, Const, Enum, ExternFn, ExternStatic, ExternType, FnKind, Impl, IterImpl, Lang,
+    Lifetimes, NamedType, Ptr, Receiver, Ref, Signature, SliceRef, Struct, Trait, Ty1, Type,
+    TypeAlias, Types, error, ident, trivial,
 };
 use proc_macro2::{Delimiter, Group, Ident, TokenStream};
 use quote::{ToTokens, quote};
 use std::fmt::Display;
-use syn::{GenericParam, Generics, Lifetime};
+use syn::{Expr, GenericParam, Generics, Lifetime, Lit, UnOp};
 
 pub(crate) struct Check<'a> {
     apis: &'a [Api],
@@ -48,11 +49,15 @@ fn do_typecheck(cx: &mut Check) {
         match ty {
             Type::Ident(ident) => check_type_ident(cx, ident),
             Type::RustBox(ptr) => check_type_box(cx, ptr),
+            Type::RustArc(ptr) => check_type_arc(cx, ptr),
             Type::RustVec(ty) => check_type_rust_vec(cx, ty),
             Type::UniquePtr(ptr) => check_type_unique_ptr(cx, ptr),
             Type::SharedPtr(ptr) => check_type_shared_ptr(cx, ptr),
             Type::WeakPtr(ptr) => check_type_weak_ptr(cx, ptr),
             Type::CxxVector(ptr) => check_type_cxx_vector(cx, ptr),
+            Type::CxxDeque(ptr) => check_type_cxx_container(cx, ptr, "deque"),
+            Type::CxxList(ptr) => check_type_cxx_container(cx, ptr, "list"),
+            Type::CxxSet(ptr) => check_type_cxx_container(cx, ptr, "set"),
             Type::Ref(ty) => check_type_ref(cx, ty),
             Type::Ptr(ty) => check_type_ptr(cx, ty),
             Type::Array(array) => check_type_array(cx, array),
@@ -71,8 +76,16 @@ fn do_typecheck(cx: &mut Check) {
             Api::CxxFunction(efn) | Api::RustFunction(efn) => check_api_fn(cx, efn),
             Api::TypeAlias(alias) => check_api_type_alias(cx, alias),
             Api::Impl(imp) => check_api_impl(cx, imp),
+            Api::Const(konst) => check_api_const(cx, konst),
+            Api::CxxStatic(stc) | Api::RustStatic(stc) => check_api_static(cx, stc),
+            Api::CxxIterator(iter) => check_api_cxx_iterator(cx, iter),
+            Api::RustIterator(iter) => check_api_rust_iterator(cx, iter),
         }
     }
+
+    check_iterator_duplicates(cx);
+
+    check_rust_fn_overloads(cx);
 }
 
 impl Check<'_> {
@@ -112,6 +125,23 @@ fn check_type_box(cx: &mut Check, ptr: &Ty1) {
     cx.error(ptr, "unsupported target type of Box");
 }
 
+fn check_type_arc(cx: &mut Check, ptr: &Ty1) {
+    if let Type::Ident(ident) = &ptr.inner {
+        if cx.types.cxx.contains(&ident.rust)
+            && !cx.types.structs.contains_key(&ident.rust)
+            && !cx.types.enums.contains_key(&ident.rust)
+        {
+            cx.error(ptr, error::ARC_CXX_TYPE.msg);
+        }
+
+        if Atom::from(&ident.rust).is_none() {
+            return;
+        }
+    }
+
+    cx.error(ptr, "unsupported target type of Arc");
+}
+
 fn check_type_rust_vec(cx: &mut Check, ty: &Ty1) {
     match &ty.inner {
         Type::Ident(ident) => {
@@ -130,7 +160,7 @@ fn check_type_rust_vec(cx: &mut Check, ty: &Ty1) {
                     Bool | Char | U8 | U16 | U32 | U64 | Usize | I8 | I16 | I32 | I64 | Isize | F32
                     | F64 | RustString,
                 ) => return,
-                Some(CxxString) => {}
+                Some(CxxString | CxxU16String | CxxU32String) => {}
             }
         }
         Type::Str(_) => return,
@@ -138,6 +168,13 @@ fn check_type_rust_vec(cx: &mut Check, ty: &Ty1) {
             check_type_box(cx, ty1);
             return;
         }
+        // The element type is validated on its own when the visitor reaches
+        // it. Only one level of nesting is supported.
+        Type::RustVec(ty1) | Type::SharedPtr(ty1) => {
+            if let Type::Ident(_) | Type::Str(_) = &ty1.inner {
+                return;
+            }
+        }
         _ => {}
     }
 
@@ -152,10 +189,12 @@ fn check_type_unique_ptr(cx: &mut Check, ptr: &Ty1) {
         }
 
         match Atom::from(&ident.rust) {
-            None | Some(CxxString) => return,
+            None | Some(CxxString | CxxU16String | CxxU32String) => return,
             _ => {}
         }
-    } else if let Type::CxxVector(_) = &ptr.inner {
+    } else if let Type::CxxVector(_) | Type::CxxDeque(_) | Type::CxxList(_) | Type::CxxSet(_) =
+        &ptr.inner
+    {
         return;
     }
 
@@ -175,7 +214,7 @@ fn check_type_shared_ptr(cx: &mut Check, ptr: &Ty1) {
                 Bool | U8 | U16 | U32 | U64 | Usize | I8 | I16 | I32 | I64 | Isize | F32 | F64
                 | CxxString,
             ) => return,
-            Some(Char | RustString) => {}
+            Some(Char | CxxU16String | CxxU32String | RustString) => {}
         }
     } else if let Type::CxxVector(_) = &ptr.inner {
         cx.error(ptr, "std::shared_ptr<std::vector> is not supported yet");
@@ -198,7 +237,7 @@ fn check_type_weak_ptr(cx: &mut Check, ptr: &Ty1) {
                 Bool | U8 | U16 | U32 | U64 | Usize | I8 | I16 | I32 | I64 | Isize | F32 | F64
                 | CxxString,
             ) => return,
-            Some(Char | RustString) => {}
+            Some(Char | CxxU16String | CxxU32String | RustString) => {}
         }
     } else if let Type::CxxVector(_) = &ptr.inner {
         cx.error(ptr, "std::weak_ptr<std::vector> is not supported yet");
@@ -209,12 +248,47 @@ fn check_type_weak_ptr(cx: &mut Check, ptr: &Ty1) {
 }
 
 fn check_type_cxx_vector(cx: &mut Check, ptr: &Ty1) {
+    match &ptr.inner {
+        Type::Ident(ident) => {
+            if cx.types.rust.contains(&ident.rust) {
+                cx.error(
+                    ptr,
+                    "C++ vector containing a Rust type is not supported yet",
+                );
+                return;
+            }
+
+            match Atom::from(&ident.rust) {
+                None
+                | Some(
+                    Bool | U8 | U16 | U32 | U64 | Usize | I8 | I16 | I32 | I64 | Isize | F32 | F64
+                    | CxxString | CxxU16String | CxxU32String,
+                ) => return,
+                Some(Char) => { /* todo */ }
+                Some(RustString) => {}
+            }
+        }
+        // The element type is validated on its own when the visitor reaches
+        // it. Only one level of nesting is supported.
+        Type::UniquePtr(ty1) | Type::SharedPtr(ty1) | Type::CxxVector(ty1) => {
+            if let Type::Ident(_) = &ty1.inner {
+                return;
+            }
+        }
+        _ => {}
+    }
+
+    cx.error(ptr, "unsupported vector element type");
+}
+
+fn check_type_cxx_container(cx: &mut Check, ptr: &Ty1, container: &str) {
     if let Type::Ident(ident) = &ptr.inner {
         if cx.types.rust.contains(&ident.rust) {
-            cx.error(
-                ptr,
-                "C++ vector containing a Rust type is not supported yet",
+            let msg = format!(
+                "C++ {} containing a Rust type is not supported yet",
+                container,
             );
+            cx.error(ptr, msg);
             return;
         }
 
@@ -223,12 +297,12 @@ fn check_type_cxx_vector(cx: &mut Check, ptr: &Ty1) {
             | Some(
                 U8 | U16 | U32 | U64 | Usize | I8 | I16 | I32 | I64 | Isize | F32 | F64 | CxxString,
             ) => return,
-            Some(Char) => { /* todo */ }
-            Some(Bool | RustString) => {}
+            Some(Bool | Char | CxxU16String | CxxU32String | RustString) => {}
         }
     }
 
-    cx.error(ptr, "unsupported vector element type");
+    let msg = format!("unsupported {} element type", container);
+    cx.error(ptr, msg);
 }
 
 fn check_type_ref(cx: &mut Check, ty: &Ref) {
@@ -236,6 +310,8 @@ fn check_type_ref(cx: &mut Check, ty: &Ref) {
         if let Some(requires_pin) = match &ty.inner {
             Type::Ident(ident)
                 if ident.rust == CxxString
+                    || ident.rust == CxxU16String
+                    || ident.rust == CxxU32String
                     || (cx.types.cxx.contains(&ident.rust)
                         && !cx.types.structs.contains_key(&ident.rust)
                         && !cx.types.enums.contains_key(&ident.rust)
@@ -244,6 +320,9 @@ fn check_type_ref(cx: &mut Check, ty: &Ref) {
                 Some(ident.rust.to_string())
             }
             Type::CxxVector(_) => Some("CxxVector<...>".to_owned()),
+            Type::CxxDeque(_) => Some("CxxDeque<...>".to_owned()),
+            Type::CxxList(_) => Some("CxxList<...>".to_owned()),
+            Type::CxxSet(_) => Some("CxxSet<...>".to_owned()),
             _ => None,
         } {
             cx.error(
@@ -462,6 +541,26 @@ fn check_api_type(cx: &mut Check, ety: &ExternType) {
         cx.error(span, "extern type bounds are not implemented yet");
     }
 
+    if let Some(cxx_deleter) = &ety.cxx_deleter {
+        if ety.lang == Lang::Rust {
+            cx.error(
+                cxx_deleter,
+                "cxx_deleter is only supported on extern C++ types",
+            );
+        } else if cxx_deleter.value().trim().is_empty() {
+            cx.error(cxx_deleter, "cxx_deleter value must not be empty");
+        }
+    }
+
+    if let Some(shared_from_this) = &ety.shared_from_this {
+        if ety.lang == Lang::Rust {
+            cx.error(
+                &shared_from_this.shared_ptr,
+                "enable_shared_from_this is only supported on extern C++ types",
+            );
+        }
+    }
+
     if let Some(reasons) = cx.types.required_trivial.get(&ety.name.rust) {
         let msg = format!(
             "needs a cxx::ExternType impl in order to be used as {}",
@@ -478,6 +577,18 @@ fn check_api_fn(cx: &mut Check, efn: &ExternFn) {
                 let ref span = span_for_generics_error(efn);
                 cx.error(span, "extern C++ function with lifetimes must be declared in `unsafe extern \"C++\"` block");
             }
+            if efn.sig.catch_unwind {
+                cx.error(
+                    efn,
+                    "#[catch_unwind] is only supported on extern Rust functions",
+                );
+            }
+            if efn.sig.error_details {
+                cx.error(
+                    efn,
+                    "#[error_details] is only supported on extern Rust functions",
+                );
+            }
         }
         Lang::Rust => {
             if !efn.generics.params.is_empty() && efn.unsafety.is_none() {
@@ -488,6 +599,14 @@ fn check_api_fn(cx: &mut Check, efn: &ExternFn) {
                 );
                 cx.error(span, message);
             }
+            if efn.sig.error_details && !efn.sig.throws {
+                cx.error(efn, "#[error_details] requires a function returning Result");
+            }
+            if let Some(instance) = &efn.instance {
+                if let Some(template) = &instance.template {
+                    check_fn_template(cx, efn, &instance.param, template);
+                }
+            }
         }
     }
 
@@ -548,7 +667,32 @@ fn check_api_fn(cx: &mut Check, efn: &ExternFn) {
         FnKind::Free => {}
     }
 
+    let mut has_default = false;
     for arg in &efn.args {
+        if let Some(cxx_default) = &arg.cxx_default {
+            if efn.lang != Lang::Rust {
+                cx.error(
+                    cxx_default,
+                    "cxx_default is only supported on arguments of extern Rust functions",
+                );
+            } else if cxx_default.value().trim().is_empty() {
+                cx.error(cxx_default, "cxx_default value must not be empty");
+            }
+            has_default = true;
+        } else if has_default {
+            cx.error(
+                arg,
+                "argument without cxx_default cannot follow an argument with cxx_default",
+            );
+        }
+
+        if arg.cxx_char16 && !matches!(&arg.ty, Type::SliceRef(slice) if slice.inner == U16) {
+            cx.error(
+                arg,
+                "cxx_char16 is only supported on arguments of type &[u16] or &mut [u16]",
+            );
+        }
+
         if let Type::Fn(_) = arg.ty {
             if efn.lang == Lang::Rust {
                 cx.error(
@@ -585,6 +729,90 @@ fn check_api_fn(cx: &mut Check, efn: &ExternFn) {
     }
 }
 
+fn check_api_const(cx: &mut Check, konst: &Const) {
+    if let Some(self_type) = &konst.self_type {
+        if cx.types.enums.contains_key(self_type) {
+            cx.error(
+                self_type,
+                "unsupported self type; C++ does not allow member constants on enums",
+            );
+        } else if !cx.types.structs.contains_key(self_type) {
+            cx.error(
+                self_type,
+                "unsupported self type; associated const requires a shared struct",
+            );
+        } else if cx.types.cxx.contains(self_type) {
+            cx.error(
+                self_type,
+                "associated const on a shared struct defined in C++ is not supported",
+            );
+        }
+    }
+
+    let atom = match &konst.ty {
+        Type::Ident(ident) => Atom::from(&ident.rust),
+        _ => None,
+    };
+    let Some(atom) =
+        atom.filter(|atom| !matches!(atom, CxxString | CxxU16String | CxxU32String | RustString))
+    else {
+        cx.error(
+            &konst.ty,
+            "unsupported type of const; only primitive types are supported",
+        );
+        return;
+    };
+
+    let lit = match &konst.expr {
+        Expr::Lit(expr) => Some(&expr.lit),
+        Expr::Unary(expr) if matches!(expr.op, UnOp::Neg(_)) => match &*expr.expr {
+            Expr::Lit(expr) if !matches!(atom, Bool) => Some(&expr.lit),
+            _ => None,
+        },
+        _ => None,
+    };
+    let expected = match atom {
+        Bool => "bool",
+        Char | U8 | U16 | U32 | U64 | Usize | I8 | I16 | I32 | I64 | Isize => "integer",
+        F32 | F64 => "float",
+        CxxString | CxxU16String | CxxU32String | RustString => unreachable!(),
+    };
+    let valid = match lit {
+        Some(Lit::Bool(_)) => expected == "bool",
+        Some(Lit::Int(_)) => expected == "integer",
+        Some(Lit::Float(_)) => expected == "float",
+        _ => false,
+    };
+    if !valid {
+        let msg = format!("unsupported const value; expected {} literal", expected);
+        cx.error(&konst.expr, msg);
+    }
+}
+
+fn check_api_static(cx: &mut Check, stc: &ExternStatic) {
+    let supported = match &stc.ty {
+        Type::Ident(ident) => match Atom::from(&ident.rust) {
+            Some(CxxString | CxxU16String | CxxU32String) => stc.lang != Lang::Rust,
+            Some(RustString) => false,
+            Some(_) => true,
+            None => {
+                cx.types.structs.contains_key(&ident.rust)
+                    || cx.types.enums.contains_key(&ident.rust)
+                    || match stc.lang {
+                        Lang::Cxx | Lang::CxxUnwind => cx.types.cxx.contains(&ident.rust),
+                        Lang::Rust => cx.types.rust.contains(&ident.rust),
+                    }
+            }
+        },
+        _ => false,
+    };
+    if !supported {
+        let desc = describe(cx.types, &stc.ty);
+        let msg = format!("unsupported type of static: {}", desc);
+        cx.error(&stc.ty, msg);
+    }
+}
+
 fn check_api_type_alias(cx: &mut Check, alias: &TypeAlias) {
     check_lifetimes(cx, &alias.generics);
 
@@ -592,6 +820,12 @@ fn check_api_type_alias(cx: &mut Check, alias: &TypeAlias) {
         let msg = format!("derive({}) on extern type alias is not supported", derive);
         cx.error(derive, msg);
     }
+
+    if let Some(cxx_deleter) = &alias.cxx_deleter {
+        if cxx_deleter.value().trim().is_empty() {
+            cx.error(cxx_deleter, "cxx_deleter value must not be empty");
+        }
+    }
 }
 
 fn check_api_impl(cx: &mut Check, imp: &Impl) {
@@ -607,11 +841,15 @@ fn check_api_impl(cx: &mut Check, imp: &Impl) {
 
     match ty {
         Type::RustBox(ty)
+        | Type::RustArc(ty)
         | Type::RustVec(ty)
         | Type::UniquePtr(ty)
         | Type::SharedPtr(ty)
         | Type::WeakPtr(ty)
-        | Type::CxxVector(ty) => {
+        | Type::CxxVector(ty)
+        | Type::CxxDeque(ty)
+        | Type::CxxList(ty)
+        | Type::CxxSet(ty) => {
             if let Type::Ident(inner) = &ty.inner {
                 // Reject `impl Vec<u8>` and other built-in impls.
                 if Atom::from(&inner.rust).is_some() {
@@ -624,6 +862,247 @@ fn check_api_impl(cx: &mut Check, imp: &Impl) {
     }
 }
 
+fn check_api_cxx_iterator(cx: &mut Check, iter: &IterImpl) {
+    let ident = &iter.self_ty.rust;
+    if !cx.types.cxx.contains(ident)
+        || cx.types.structs.contains_key(ident)
+        || cx.types.enums.contains_key(ident)
+    {
+        let msg = format!(
+            "impl IntoIterator is only supported on opaque C++ types, but `{}` is not one",
+            ident,
+        );
+        cx.error(&iter.self_ty, msg);
+    }
+
+    match &iter.item {
+        Type::Ref(ty) if ty.mutable => {
+            cx.error(
+                &iter.item,
+                "iterating a C++ range by mutable reference is not supported",
+            );
+        }
+        Type::Ref(ty) => match &ty.inner {
+            Type::Ref(_)
+            | Type::Ptr(_)
+            | Type::Str(_)
+            | Type::SliceRef(_)
+            | Type::Fn(_)
+            | Type::Void(_) => {
+                cx.error(&iter.item, "unsupported Item type for iterator");
+            }
+            _ => {}
+        },
+        _ => {
+            cx.error(
+                &iter.item,
+                "expected a shared reference as the Item type of a C++ range",
+            );
+        }
+    }
+}
+
+fn check_api_rust_iterator(cx: &mut Check, iter: &IterImpl) {
+    let ident = &iter.self_ty.rust;
+    if !cx.types.rust.contains(ident) {
+        let msg = format!(
+            "impl Iterator is only supported on opaque Rust types, but `{}` is not one",
+            ident,
+        );
+        cx.error(&iter.self_ty, msg);
+    }
+
+    match &iter.item {
+        Type::Ref(_)
+        | Type::Ptr(_)
+        | Type::Str(_)
+        | Type::SliceRef(_)
+        | Type::Fn(_)
+        | Type::Void(_)
+        | Type::Array(_) => {
+            cx.error(&iter.item, "unsupported Item type for iterator");
+        }
+        item if is_unsized(cx.types, item) => {
+            let desc = describe(cx.types, item);
+            let msg = format!(
+                "returning {} by value from an iterator is not supported",
+                desc
+            );
+            cx.error(&iter.item, msg);
+        }
+        _ => {}
+    }
+}
+
+fn check_iterator_duplicates(cx: &mut Check) {
+    let mut seen = UnorderedSet::new();
+    for api in cx.apis {
+        if let Api::CxxIterator(iter) | Api::RustIterator(iter) = api {
+            if !seen.insert(&iter.self_ty.rust) {
+                let msg = format!(
+                    "conflicting impl {} for `{}`",
+                    iter.trait_token, iter.self_ty.rust,
+                );
+                cx.error(iter, msg);
+            }
+        }
+    }
+}
+
+// Checks concerning a generic function as a whole. These are reported once,
+// on its first instantiation. The instantiated signatures themselves are
+// checked like any other.
+fn check_fn_template(cx: &mut Check, efn: &ExternFn, param: &Ident, template: &Signature) {
+    if Atom::from(param).is_some() || cx.types.resolutions.contains_key(param) {
+        let msg = format!("type parameter `{}` shadows a type of the same name", param);
+        cx.error(param, msg);
+    }
+
+    struct FindParam<'a> {
+        param: &'a Ident,
+        found: bool,
+    }
+
+    impl<'t, 'a> Visit<'t> for FindParam<'a> {
+        fn visit_type(&mut self, ty: &'t Type) {
+            self.found |= matches!(ty, Type::Ident(ident) if ident.rust == *self.param);
+            visit::visit_type(self, ty);
+        }
+    }
+
+    let mut visitor = FindParam {
+        param,
+        found: false,
+    };
+    for arg in &template.args {
+        visitor.visit_type(&arg.ty);
+    }
+    if let Some(ret) = &template.ret {
+        visitor.visit_type(ret);
+    }
+    if !visitor.found {
+        let msg = format!(
+            "type parameter `{}` must appear in the function signature",
+            param,
+        );
+        cx.error(efn, msg);
+    }
+
+    if !matches!(efn.kind, FnKind::Free) {
+        cx.error(efn, "#[instantiate] is only supported on free functions");
+    }
+
+    if efn.args.iter().any(|arg| arg.cxx_default.is_some()) {
+        cx.error(efn, "cxx_default is not supported on generic functions");
+    }
+}
+
+// Extern Rust functions get a C++ declaration emitted by us, so multiple Rust
+// functions sharing one C++ name must form a valid C++ overload set.
+fn check_rust_fn_overloads(cx: &mut Check) {
+    let mut rust_fns: Vec<&ExternFn> = Vec::new();
+    for api in cx.apis {
+        let Api::RustFunction(efn) = api else {
+            continue;
+        };
+        if efn.instance.is_some() {
+            // Explicit specializations of a function template, not overloads.
+            continue;
+        }
+        for prev in &rust_fns {
+            if is_indistinguishable_overload(prev, efn) {
+                let msg = format!(
+                    "C++ overload `{}` has the same parameter types as `{}`",
+                    efn.name.cxx, prev.name.rust,
+                );
+                cx.error(efn, msg);
+                break;
+            }
+        }
+        rust_fns.push(efn);
+    }
+}
+
+fn is_indistinguishable_overload(a: &ExternFn, b: &ExternFn) -> bool {
+    if a.cfg != b.cfg
+        || a.name.namespace != b.name.namespace
+        || a.name.cxx.to_string() != b.name.cxx.to_string()
+        || a.self_type() != b.self_type()
+        || a.args.len() != b.args.len()
+    {
+        return false;
+    }
+
+    if let (FnKind::Method(a), FnKind::Method(b)) = (&a.kind, &b.kind) {
+        // C++ permits overloading a member function on const-ness.
+        if a.mutable != b.mutable {
+            return false;
+        }
+    }
+
+    a.args
+        .iter()
+        .zip(&b.args)
+        .all(|(a, b)| is_same_cxx_type(&a.ty, &b.ty))
+}
+
+// Whether std::size_t or rust::isize is the same C++ type as a fixed width
+// integer on some supported platform, e.g. `unsigned long` for both size_t and
+// uint64_t on LP64 Linux, so that overloading on the pair would be a
+// redefinition there.
+fn is_platform_alias(a: Atom, b: Atom) -> bool {
+    matches!((a, b), (Usize, U32 | U64) | (Isize, I32 | I64))
+}
+
+// Equality of the C++ spelling of two types, which unlike the PartialEq impl
+// of Type disregards lifetimes.
+fn is_same_cxx_type(a: &Type, b: &Type) -> bool {
+    match (a, b) {
+        (Type::Ident(a), Type::Ident(b)) => {
+            a.rust == b.rust
+                || match (Atom::from(&a.rust), Atom::from(&b.rust)) {
+                    (Some(a), Some(b)) => is_platform_alias(a, b) || is_platform_alias(b, a),
+                    _ => false,
+                }
+        }
+        (Type::RustBox(a), Type::RustBox(b))
+        | (Type::RustArc(a), Type::RustArc(b))
+        | (Type::RustVec(a), Type::RustVec(b))
+        | (Type::UniquePtr(a), Type::UniquePtr(b))
+        | (Type::SharedPtr(a), Type::SharedPtr(b))
+        | (Type::WeakPtr(a), Type::WeakPtr(b))
+        | (Type::CxxVector(a), Type::CxxVector(b))
+        | (Type::CxxDeque(a), Type::CxxDeque(b))
+        | (Type::CxxList(a), Type::CxxList(b))
+        | (Type::CxxSet(a), Type::CxxSet(b)) => is_same_cxx_type(&a.inner, &b.inner),
+        (Type::Ref(a), Type::Ref(b)) => {
+            a.mutable == b.mutable && is_same_cxx_type(&a.inner, &b.inner)
+        }
+        (Type::Ptr(a), Type::Ptr(b)) => {
+            a.mutable == b.mutable && is_same_cxx_type(&a.inner, &b.inner)
+        }
+        (Type::SliceRef(a), Type::SliceRef(b)) => {
+            a.mutable == b.mutable && is_same_cxx_type(&a.inner, &b.inner)
+        }
+        (Type::Array(a), Type::Array(b)) => a.len == b.len && is_same_cxx_type(&a.inner, &b.inner),
+        (Type::Fn(a), Type::Fn(b)) => {
+            a.throws == b.throws
+                && match (&a.ret, &b.ret) {
+                    (Some(a), Some(b)) => is_same_cxx_type(a, b),
+                    (None, None) => true,
+                    _ => false,
+                }
+                && a.args.len() == b.args.len()
+                && a.args
+                    .iter()
+                    .zip(&b.args)
+                    .all(|(a, b)| is_same_cxx_type(&a.ty, &b.ty))
+        }
+        (Type::Str(_), Type::Str(_)) | (Type::Void(_), Type::Void(_)) => true,
+        _ => false,
+    }
+}
+
 fn check_mut_return_restriction(cx: &mut Check, efn: &ExternFn) {
     if efn.unsafety.is_some() {
         // Unrestricted as long as the function is made unsafe-to-call.
@@ -693,6 +1172,9 @@ fn check_reserved_name(cx: &mut Check, ident: &Ident) {
         || ident == "WeakPtr"
         || ident == "Vec"
         || ident == "CxxVector"
+        || ident == "CxxDeque"
+        || ident == "CxxList"
+        || ident == "CxxSet"
         || ident == "str"
         || Atom::from(ident).is_some()
     {
@@ -730,6 +1212,8 @@ fn is_unsized(types: &Types, ty: &Type) -> bool {
         Type::Ident(ident) => {
             let ident = &ident.rust;
             ident == CxxString
+                || ident == CxxU16String
+                || ident == CxxU32String
                 || (types.cxx.contains(ident)
                     && !types.structs.contains_key(ident)
                     && !types.enums.contains_key(ident)
@@ -738,8 +1222,14 @@ fn is_unsized(types: &Types, ty: &Type) -> bool {
                 || types.rust.contains(ident)
         }
         Type::Array(array) => is_unsized(types, &array.inner),
-        Type::CxxVector(_) | Type::Fn(_) | Type::Void(_) => true,
+        Type::CxxVector(_)
+        | Type::CxxDeque(_)
+        | Type::CxxList(_)
+        | Type::CxxSet(_)
+        | Type::Fn(_)
+        | Type::Void(_) => true,
         Type::RustBox(_)
+        | Type::RustArc(_)
         | Type::RustVec(_)
         | Type::UniquePtr(_)
         | Type::SharedPtr(_)
@@ -798,7 +1288,7 @@ fn describe(types: &Types, ty: &Type) -> String {
                 "opaque C++ type".to_owned()
             } else if types.rust.contains(&ident.rust) {
                 "opaque Rust type".to_owned()
-            } else if Atom::from(&ident.rust) == Some(CxxString) {
+            } else if let Some(CxxString | CxxU16String | CxxU32String) = Atom::from(&ident.rust) {
                 "C++ string".to_owned()
             } else if Atom::from(&ident.rust) == Some(Char) {
                 "C char".to_owned()
@@ -807,6 +1297,7 @@ fn describe(types: &Types, ty: &Type) -> String {
             }
         }
         Type::RustBox(_) => "Box".to_owned(),
+        Type::RustArc(_) => "Arc".to_owned(),
         Type::RustVec(_) => "Vec".to_owned(),
         Type::UniquePtr(_) => "unique_ptr".to_owned(),
         Type::SharedPtr(_) => "shared_ptr".to_owned(),
@@ -815,6 +1306,9 @@ fn describe(types: &Types, ty: &Type) -> String {
         Type::Ptr(_) => "raw pointer".to_owned(),
         Type::Str(_) => "&str".to_owned(),
         Type::CxxVector(_) => "C++ vector".to_owned(),
+        Type::CxxDeque(_) => "C++ deque".to_owned(),
+        Type::CxxList(_) => "C++ list".to_owned(),
+        Type::CxxSet(_) => "C++ set".to_owned(),
         Type::SliceRef(_) => "slice".to_owned(),
         Type::Fn(_) => "function pointer".to_owned(),
         Type::Void(_) => "()".to_owned(),
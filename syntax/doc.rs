@@ -2,6 +2,7 @@ use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::LitStr;
 
+#[derive(Clone)]
 pub(crate) struct Doc {
     pub hidden: bool,
     fragments: Vec<LitStr>,
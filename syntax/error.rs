@@ -16,6 +16,7 @@ impl Display for Error {
 }
 
 pub(crate) static ERRORS: &[Error] = &[
+    ARC_CXX_TYPE,
     BOX_CXX_TYPE,
     CXXBRIDGE_RESERVED,
     CXX_STRING_BY_VALUE,
@@ -29,6 +30,12 @@ pub(crate) static ERRORS: &[Error] = &[
     USE_NOT_ALLOWED,
 ];
 
+pub(crate) static ARC_CXX_TYPE: Error = Error {
+    msg: "Arc of a C++ type is not supported",
+    label: None,
+    note: Some("hint: use SharedPtr<>"),
+};
+
 pub(crate) static BOX_CXX_TYPE: Error = Error {
     msg: "Box of a C++ type is not supported yet",
     label: None,
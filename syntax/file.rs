@@ -27,6 +27,7 @@ pub(crate) struct Module {
 pub(crate) enum Item {
     Struct(ItemStruct),
     Enum(ItemEnum),
+    Const(syn::ItemConst),
     ForeignMod(ItemForeignMod),
     Use(ItemUse),
     Impl(ItemImpl),
@@ -109,6 +110,10 @@ impl Parse for Item {
                 item.attrs.splice(..0, attrs);
                 Ok(Item::Enum(item))
             }
+            RustItem::Const(mut item) => {
+                item.attrs.splice(..0, attrs);
+                Ok(Item::Const(item))
+            }
             RustItem::ForeignMod(mut item) => {
                 item.attrs.splice(..0, attrs);
                 Ok(Item::ForeignMod(ItemForeignMod {
@@ -52,6 +52,13 @@ pub(crate) fn check_all(cx: &mut Check, apis: &[Api]) {
             Api::TypeAlias(alias) => {
                 check(cx, &alias.name);
             }
+            Api::Const(konst) => {
+                check(cx, &konst.name);
+            }
+            Api::CxxStatic(stc) | Api::RustStatic(stc) => {
+                check(cx, &stc.name);
+            }
+            Api::CxxIterator(_) | Api::RustIterator(_) => {}
         }
     }
 }
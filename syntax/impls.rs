@@ -45,6 +45,7 @@ impl Hash for Type {
         match self {
             Type::Ident(t) => t.hash(state),
             Type::RustBox(t) => t.hash(state),
+            Type::RustArc(t) => t.hash(state),
             Type::UniquePtr(t) => t.hash(state),
             Type::SharedPtr(t) => t.hash(state),
             Type::WeakPtr(t) => t.hash(state),
@@ -53,6 +54,9 @@ impl Hash for Type {
             Type::Str(t) => t.hash(state),
             Type::RustVec(t) => t.hash(state),
             Type::CxxVector(t) => t.hash(state),
+            Type::CxxDeque(t) => t.hash(state),
+            Type::CxxList(t) => t.hash(state),
+            Type::CxxSet(t) => t.hash(state),
             Type::Fn(t) => t.hash(state),
             Type::SliceRef(t) => t.hash(state),
             Type::Array(t) => t.hash(state),
@@ -68,6 +72,7 @@ impl PartialEq for Type {
         match (self, other) {
             (Type::Ident(lhs), Type::Ident(rhs)) => lhs == rhs,
             (Type::RustBox(lhs), Type::RustBox(rhs)) => lhs == rhs,
+            (Type::RustArc(lhs), Type::RustArc(rhs)) => lhs == rhs,
             (Type::UniquePtr(lhs), Type::UniquePtr(rhs)) => lhs == rhs,
             (Type::SharedPtr(lhs), Type::SharedPtr(rhs)) => lhs == rhs,
             (Type::WeakPtr(lhs), Type::WeakPtr(rhs)) => lhs == rhs,
@@ -75,6 +80,9 @@ impl PartialEq for Type {
             (Type::Str(lhs), Type::Str(rhs)) => lhs == rhs,
             (Type::RustVec(lhs), Type::RustVec(rhs)) => lhs == rhs,
             (Type::CxxVector(lhs), Type::CxxVector(rhs)) => lhs == rhs,
+            (Type::CxxDeque(lhs), Type::CxxDeque(rhs)) => lhs == rhs,
+            (Type::CxxList(lhs), Type::CxxList(rhs)) => lhs == rhs,
+            (Type::CxxSet(lhs), Type::CxxSet(rhs)) => lhs == rhs,
             (Type::Fn(lhs), Type::Fn(rhs)) => lhs == rhs,
             (Type::SliceRef(lhs), Type::SliceRef(rhs)) => lhs == rhs,
             (Type::Void(_), Type::Void(_)) => true,
@@ -314,6 +322,8 @@ impl PartialEq for Signature {
             args,
             ret,
             throws,
+            catch_unwind,
+            error_details,
             paren_token: _,
             throws_tokens: _,
         } = self;
@@ -326,6 +336,8 @@ impl PartialEq for Signature {
             args: args2,
             ret: ret2,
             throws: throws2,
+            catch_unwind: catch_unwind2,
+            error_details: error_details2,
             paren_token: _,
             throws_tokens: _,
         } = other;
@@ -334,6 +346,8 @@ impl PartialEq for Signature {
             && kind == kind2
             && ret == ret2
             && throws == throws2
+            && catch_unwind == catch_unwind2
+            && error_details == error_details2
             && args.len() == args2.len()
             && args.iter().zip(args2).all(|(arg, arg2)| {
                 let Var {
@@ -344,6 +358,8 @@ impl PartialEq for Signature {
                     name: _,
                     colon_token: _,
                     ty,
+                    cxx_default: _,
+                    cxx_char16,
                 } = arg;
                 let Var {
                     cfg: _,
@@ -353,8 +369,10 @@ impl PartialEq for Signature {
                     name: _,
                     colon_token: _,
                     ty: ty2,
+                    cxx_default: _,
+                    cxx_char16: cxx_char16_2,
                 } = arg2;
-                ty == ty2
+                ty == ty2 && cxx_char16 == cxx_char16_2
             })
     }
 }
@@ -370,6 +388,8 @@ impl Hash for Signature {
             args,
             ret,
             throws,
+            catch_unwind,
+            error_details,
             paren_token: _,
             throws_tokens: _,
         } = self;
@@ -385,11 +405,16 @@ impl Hash for Signature {
                 name: _,
                 colon_token: _,
                 ty,
+                cxx_default: _,
+                cxx_char16,
             } = arg;
             ty.hash(state);
+            cxx_char16.hash(state);
         }
         ret.hash(state);
         throws.hash(state);
+        catch_unwind.hash(state);
+        error_details.hash(state);
     }
 }
 
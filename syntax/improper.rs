@@ -27,6 +27,7 @@ impl<'a> Types<'a> {
                 }
             }
             TypeQuery::RustBox
+            | TypeQuery::RustArc
             | TypeQuery::RustVec
             | TypeQuery::Str
             | TypeQuery::Fn
@@ -35,7 +36,10 @@ impl<'a> Types<'a> {
             TypeQuery::UniquePtr
             | TypeQuery::SharedPtr
             | TypeQuery::WeakPtr
-            | TypeQuery::CxxVector => Definite(false),
+            | TypeQuery::CxxVector
+            | TypeQuery::CxxDeque
+            | TypeQuery::CxxList
+            | TypeQuery::CxxSet => Definite(false),
             TypeQuery::Ref(ty) => self.determine_improper_ctype(&ty.inner),
             TypeQuery::Ptr(ty) => self.determine_improper_ctype(&ty.inner),
             TypeQuery::Array(ty) => self.determine_improper_ctype(&ty.inner),
@@ -8,11 +8,15 @@ use std::hash::{Hash, Hasher};
 #[derive(PartialEq, Eq, Hash)]
 pub(crate) enum ImplKey<'a> {
     RustBox(NamedImplKey<'a>),
+    RustArc(NamedImplKey<'a>),
     RustVec(NamedImplKey<'a>),
     UniquePtr(NamedImplKey<'a>),
     SharedPtr(NamedImplKey<'a>),
     WeakPtr(NamedImplKey<'a>),
     CxxVector(NamedImplKey<'a>),
+    CxxDeque(NamedImplKey<'a>),
+    CxxList(NamedImplKey<'a>),
+    CxxSet(NamedImplKey<'a>),
 }
 
 impl<'a> ImplKey<'a> {
@@ -26,8 +30,21 @@ impl<'a> ImplKey<'a> {
     /// traits defined by the `cxx` crate for some local type or for a
     /// fundamental type like `Box<LocalType>`.
     pub(crate) fn is_implicit_impl_ok(&self, types: &Types) -> bool {
-        // TODO: relax this for Rust generics to allow Vec<Vec<T>> etc.
-        types.is_local(self.inner())
+        match self {
+            // The element of a vector of smart pointers or of vectors is not
+            // local, so the impl goes on the type inside of the element.
+            ImplKey::CxxVector(key) => match key.inner {
+                Type::UniquePtr(ty1) | Type::SharedPtr(ty1) | Type::CxxVector(ty1) => {
+                    types.is_local(&ty1.inner)
+                }
+                _ => types.is_local(key.inner),
+            },
+            ImplKey::RustVec(key) => match key.inner {
+                Type::RustVec(ty1) | Type::SharedPtr(ty1) => types.is_local(&ty1.inner),
+                _ => types.is_local(key.inner),
+            },
+            _ => types.is_local(self.inner()),
+        }
     }
 
     /// Returns the type argument in the generic instantiation described by
@@ -36,11 +53,15 @@ impl<'a> ImplKey<'a> {
     fn inner(&self) -> &'a Type {
         let named_impl_key = match self {
             ImplKey::RustBox(key)
+            | ImplKey::RustArc(key)
             | ImplKey::RustVec(key)
             | ImplKey::UniquePtr(key)
             | ImplKey::SharedPtr(key)
             | ImplKey::WeakPtr(key)
-            | ImplKey::CxxVector(key) => key,
+            | ImplKey::CxxVector(key)
+            | ImplKey::CxxDeque(key)
+            | ImplKey::CxxList(key)
+            | ImplKey::CxxSet(key) => key,
         };
         named_impl_key.inner
     }
@@ -64,11 +85,15 @@ impl Type {
     pub(crate) fn impl_key(&self, res: &UnorderedMap<&Ident, Resolution>) -> Option<ImplKey> {
         match self {
             Type::RustBox(ty) => Some(ImplKey::RustBox(NamedImplKey::new(self, ty, res)?)),
+            Type::RustArc(ty) => Some(ImplKey::RustArc(NamedImplKey::new(self, ty, res)?)),
             Type::RustVec(ty) => Some(ImplKey::RustVec(NamedImplKey::new(self, ty, res)?)),
             Type::UniquePtr(ty) => Some(ImplKey::UniquePtr(NamedImplKey::new(self, ty, res)?)),
             Type::SharedPtr(ty) => Some(ImplKey::SharedPtr(NamedImplKey::new(self, ty, res)?)),
             Type::WeakPtr(ty) => Some(ImplKey::WeakPtr(NamedImplKey::new(self, ty, res)?)),
             Type::CxxVector(ty) => Some(ImplKey::CxxVector(NamedImplKey::new(self, ty, res)?)),
+            Type::CxxDeque(ty) => Some(ImplKey::CxxDeque(NamedImplKey::new(self, ty, res)?)),
+            Type::CxxList(ty) => Some(ImplKey::CxxList(NamedImplKey::new(self, ty, res)?)),
+            Type::CxxSet(ty) => Some(ImplKey::CxxSet(NamedImplKey::new(self, ty, res)?)),
             _ => None,
         }
     }
@@ -22,7 +22,7 @@
 //          defining characteristics:
 //             - 4+ segments, none an integer
 //
-//   (d) User-defined extern function.
+//   (d) User-defined extern function or static.
 //          pattern:  {NAMESPACE...} $ {CXXBRIDGE} $ {CXXVERSION} $ {NAME}
 //          examples:
 //             - cxxbridge1$189$new_client
@@ -51,6 +51,20 @@
 //          defining characteristics:
 //             - last symbol is `0` (C half) or `1` (Rust half) which are not legal identifiers on their own
 //
+//   (h) Iterator protocol of a user-defined type.
+//          pattern:  {NAMESPACE...} $ {CXXBRIDGE} $ {CXXVERSION} $ {TYPE} $ iterator $ {NAME}
+//          examples:
+//             - org$rust$cxxbridge1$189$Lines$iterator$next
+//          defining characteristics:
+//             - fourth segment from end is an integer and second is `iterator`
+//
+//   (i) Accessor of a user-defined type declared #[enable_shared_from_this].
+//          pattern:  {NAMESPACE...} $ {CXXBRIDGE} $ {CXXVERSION} $ {TYPE} $ enable_shared_from_this $ {NAME}
+//          examples:
+//             - org$rust$cxxbridge1$189$Node$enable_shared_from_this$weak
+//          defining characteristics:
+//             - fourth segment from end is an integer and second is `enable_shared_from_this`
+//
 //
 // Mangled preprocessor variable arrangements:
 //
@@ -60,10 +74,10 @@
 //             - CXXBRIDGE1_PANIC
 //             - CXXBRIDGE1_RUST_STRING
 //          defining characteristics:
-//             - NAME does not begin with STRUCT or ENUM
+//             - NAME does not begin with STRUCT, ENUM, or CONST
 //
-//   (B) Guard around user-defined type.
-//          pattern:  {CXXBRIDGE} _ {STRUCT or ENUM} _ {NAMESPACE...} $ {TYPE}
+//   (B) Guard around user-defined type or constant.
+//          pattern:  {CXXBRIDGE} _ {STRUCT or ENUM or CONST} _ {NAMESPACE...} $ {NAME}
 //          examples:
 //             - CXXBRIDGE1_STRUCT_org$rust$Struct
 //             - CXXBRIDGE1_ENUM_Enabled
@@ -71,7 +85,7 @@
 use crate::syntax::map::UnorderedMap;
 use crate::syntax::resolve::Resolution;
 use crate::syntax::symbol::{self, Symbol};
-use crate::syntax::{ExternFn, Pair, Type, Types};
+use crate::syntax::{ExternFn, ExternStatic, Pair, Type, Types};
 use proc_macro2::Ident;
 
 const CXXBRIDGE: &str = "cxxbridge1";
@@ -95,10 +109,23 @@ pub(crate) fn extern_fn(efn: &ExternFn, types: &Types) -> Symbol {
                 efn.name.rust,
             )
         }
-        None => join!(efn.name.namespace, CXXBRIDGE, CXXVERSION, efn.name.rust),
+        None => match &efn.instance {
+            Some(instance) => join!(
+                efn.name.namespace,
+                CXXBRIDGE,
+                CXXVERSION,
+                efn.name.rust,
+                instance.arg.rust,
+            ),
+            None => join!(efn.name.namespace, CXXBRIDGE, CXXVERSION, efn.name.rust),
+        },
     }
 }
 
+pub(crate) fn extern_static(stc: &ExternStatic) -> Symbol {
+    join!(stc.name.namespace, CXXBRIDGE, CXXVERSION, stc.name.rust)
+}
+
 pub(crate) fn operator(receiver: &Pair, operator: &'static str) -> Symbol {
     join!(
         receiver.namespace,
@@ -110,6 +137,28 @@ pub(crate) fn operator(receiver: &Pair, operator: &'static str) -> Symbol {
     )
 }
 
+pub(crate) fn iterator(receiver: &Pair, name: &'static str) -> Symbol {
+    join!(
+        receiver.namespace,
+        CXXBRIDGE,
+        CXXVERSION,
+        receiver.cxx,
+        "iterator",
+        name,
+    )
+}
+
+pub(crate) fn enable_shared_from_this(receiver: &Pair, name: &'static str) -> Symbol {
+    join!(
+        receiver.namespace,
+        CXXBRIDGE,
+        CXXVERSION,
+        receiver.cxx,
+        "enable_shared_from_this",
+        name,
+    )
+}
+
 // The C half of a function pointer trampoline.
 pub(crate) fn c_trampoline(efn: &ExternFn, var: &Pair, types: &Types) -> Symbol {
     join!(extern_fn(efn, types), var.rust, 0)
@@ -134,7 +183,14 @@ pub(crate) fn typename(t: &Type, res: &UnorderedMap<&Ident, Resolution>) -> Opti
     match t {
         Type::Ident(named_type) => res.get(&named_type.rust).map(|res| res.name.to_symbol()),
         Type::CxxVector(ty1) => typename(&ty1.inner, res).map(|s| join!("std", "vector", s)),
+        Type::CxxDeque(ty1) => typename(&ty1.inner, res).map(|s| join!("std", "deque", s)),
+        Type::CxxList(ty1) => typename(&ty1.inner, res).map(|s| join!("std", "list", s)),
+        Type::CxxSet(ty1) => typename(&ty1.inner, res).map(|s| join!("std", "set", s)),
         Type::RustBox(ty1) => typename(&ty1.inner, res).map(|s| join!("box", s)),
+        Type::RustArc(ty1) => typename(&ty1.inner, res).map(|s| join!("arc", s)),
+        Type::RustVec(ty1) => typename(&ty1.inner, res).map(|s| join!("rust_vec", s)),
+        Type::UniquePtr(ty1) => typename(&ty1.inner, res).map(|s| join!("std", "unique_ptr", s)),
+        Type::SharedPtr(ty1) => typename(&ty1.inner, res).map(|s| join!("std", "shared_ptr", s)),
         _ => None,
     }
 }
@@ -44,7 +44,7 @@ use self::symbol::Symbol;
 use proc_macro2::{Ident, Span};
 use syn::punctuated::Punctuated;
 use syn::token::{Brace, Bracket, Paren};
-use syn::{Expr, Generics, Lifetime, LitInt, PointerMutability, Token, Type as RustType};
+use syn::{Expr, Generics, Lifetime, LitInt, LitStr, PointerMutability, Token, Type as RustType};
 
 pub(crate) use self::atom::Atom;
 pub(crate) use self::derive::{Derive, Trait};
@@ -65,6 +65,11 @@ pub(crate) enum Api {
     RustFunction(ExternFn),
     TypeAlias(TypeAlias),
     Impl(Impl),
+    Const(Const),
+    CxxStatic(ExternStatic),
+    RustStatic(ExternStatic),
+    CxxIterator(IterImpl),
+    RustIterator(IterImpl),
 }
 
 pub(crate) struct Include {
@@ -87,7 +92,6 @@ pub enum IncludeKind {
 }
 
 pub(crate) struct ExternType {
-    #[cfg_attr(proc_macro, expect(dead_code))]
     pub cfg: CfgExpr,
     pub lang: Lang,
     pub doc: Doc,
@@ -104,6 +108,15 @@ pub(crate) struct ExternType {
     #[cfg_attr(not(proc_macro), expect(dead_code))]
     pub semi_token: Token![;],
     pub trusted: bool,
+    pub cxx_deleter: Option<LitStr>,
+    pub shared_from_this: Option<SharedFromThis>,
+}
+
+// An extern C++ type declared #[enable_shared_from_this], along with the
+// SharedPtr<Self> and WeakPtr<Self> types that its generated methods return.
+pub(crate) struct SharedFromThis {
+    pub shared_ptr: Type,
+    pub weak_ptr: Type,
 }
 
 pub(crate) struct Struct {
@@ -154,6 +167,56 @@ pub(crate) struct ExternFn {
     pub sig: Signature,
     pub semi_token: Token![;],
     pub trusted: bool,
+    pub instance: Option<FnInstance>,
+    pub cxx_template: Option<Vec<Type>>,
+}
+
+// One explicit instantiation of a generic extern Rust function, written as
+// `#[instantiate(i32, f64)] fn f<T>(...);`. Each instantiation is its own
+// ExternFn with the type parameter substituted in its signature.
+pub(crate) struct FnInstance {
+    pub param: Ident,
+    pub arg: NamedType,
+    // The generic signature, kept on the first instantiation only, from which
+    // the C++ primary template is declared.
+    pub template: Option<Box<Signature>>,
+}
+
+pub(crate) struct Const {
+    pub cfg: CfgExpr,
+    pub doc: Doc,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub attrs: OtherAttrs,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub visibility: Token![pub],
+    pub const_token: Token![const],
+    pub name: Pair,
+    pub self_type: Option<Ident>,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub colon_token: Token![:],
+    pub ty: Type,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub eq_token: Token![=],
+    pub expr: Expr,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub semi_token: Token![;],
+}
+
+pub(crate) struct ExternStatic {
+    pub cfg: CfgExpr,
+    pub lang: Lang,
+    pub doc: Doc,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub attrs: OtherAttrs,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub visibility: Token![pub],
+    pub static_token: Token![static],
+    pub name: Pair,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub colon_token: Token![:],
+    pub ty: Type,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub semi_token: Token![;],
 }
 
 pub(crate) struct TypeAlias {
@@ -174,6 +237,7 @@ pub(crate) struct TypeAlias {
     pub ty: RustType,
     #[cfg_attr(not(proc_macro), expect(dead_code))]
     pub semi_token: Token![;],
+    pub cxx_deleter: Option<LitStr>,
 }
 
 pub(crate) struct Impl {
@@ -189,6 +253,22 @@ pub(crate) struct Impl {
     pub negative_token: Option<Token![!]>,
 }
 
+// Either `impl IntoIterator for &T { type Item = &U; }` on an opaque C++
+// range, exposing it to Rust as an Iterator, or `impl Iterator for T { type
+// Item = U; }` on an opaque Rust type, exposing it to C++ as an input range.
+pub(crate) struct IterImpl {
+    pub cfg: CfgExpr,
+    #[cfg_attr(not(proc_macro), expect(dead_code))]
+    pub attrs: OtherAttrs,
+    pub impl_token: Token![impl],
+    pub trait_token: Ident,
+    pub for_token: Token![for],
+    pub ampersand: Option<Token![&]>,
+    pub self_ty: NamedType,
+    pub brace_token: Brace,
+    pub item: Type,
+}
+
 #[derive(Clone, Default)]
 pub(crate) struct Lifetimes {
     pub lt_token: Option<Token![<]>,
@@ -205,6 +285,8 @@ pub(crate) struct Signature {
     pub args: Punctuated<Var, Token![,]>,
     pub ret: Option<Type>,
     pub throws: bool,
+    pub catch_unwind: bool,
+    pub error_details: bool,
     pub paren_token: Paren,
     pub throws_tokens: Option<(kw::Result, Token![<], Token![>])>,
 }
@@ -230,6 +312,8 @@ pub(crate) struct Var {
     #[cfg_attr(not(proc_macro), expect(dead_code))]
     pub colon_token: Token![:],
     pub ty: Type,
+    pub cxx_default: Option<LitStr>,
+    pub cxx_char16: bool,
 }
 
 pub(crate) struct Receiver {
@@ -263,6 +347,7 @@ pub(crate) struct Variant {
 pub(crate) enum Type {
     Ident(NamedType),
     RustBox(Box<Ty1>),
+    RustArc(Box<Ty1>),
     RustVec(Box<Ty1>),
     UniquePtr(Box<Ty1>),
     SharedPtr(Box<Ty1>),
@@ -271,6 +356,9 @@ pub(crate) enum Type {
     Ptr(Box<Ptr>),
     Str(Box<Ref>),
     CxxVector(Box<Ty1>),
+    CxxDeque(Box<Ty1>),
+    CxxList(Box<Ty1>),
+    CxxSet(Box<Ty1>),
     Fn(Box<Signature>),
     Void(Span),
     SliceRef(Box<SliceRef>),
@@ -6,21 +6,23 @@ use crate::syntax::file::{Item, ItemForeignMod};
 use crate::syntax::report::Errors;
 use crate::syntax::repr::Repr;
 use crate::syntax::{
-    Api, Array, Derive, Doc, Enum, EnumRepr, ExternFn, ExternType, FnKind, ForeignName, Impl,
-    Include, IncludeKind, Lang, Lifetimes, NamedType, Namespace, Pair, Ptr, Receiver, Ref,
-    Signature, SliceRef, Struct, Ty1, Type, TypeAlias, Var, Variant, attrs, error,
+    Api, Array, Const, Derive, Doc, Enum, EnumRepr, ExternFn, ExternStatic, ExternType, FnInstance,
+    FnKind, ForeignName, Impl, Include, IncludeKind, IterImpl, Lang, Lifetimes, NamedType,
+    Namespace, Pair, Ptr, Receiver, Ref, SharedFromThis, Signature, SliceRef, Struct, Ty1, Type,
+    TypeAlias, Var, Variant, attrs, error,
 };
 use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
-use quote::{format_ident, quote, quote_spanned};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use std::mem;
 use syn::parse::{ParseStream, Parser};
 use syn::punctuated::Punctuated;
 use syn::{
-    Abi, Attribute, Error, Expr, Fields, FnArg, ForeignItem, ForeignItemFn, ForeignItemType,
-    GenericArgument, GenericParam, Generics, Ident, ItemEnum, ItemImpl, ItemStruct, Lit, LitStr,
-    Pat, PathArguments, PointerMutability, ReceiverKind, Result, ReturnType, Safety,
-    Signature as RustSignature, Token, TraitBound, Type as RustType, TypeArray, TypeFnPtr,
-    TypeParamBound, TypePath, TypePtr, TypeReference, Variant as RustVariant, Visibility,
+    Abi, Attribute, Error, Expr, Fields, FnArg, ForeignItem, ForeignItemFn, ForeignItemStatic,
+    ForeignItemType, GenericArgument, GenericParam, Generics, Ident, ImplItem, ItemConst, ItemEnum,
+    ItemImpl, ItemStruct, Lit, LitStr, Pat, PathArguments, PointerMutability, ReceiverKind, Result,
+    ReturnType, Safety, Signature as RustSignature, StaticMutability, Token, TraitBound,
+    Type as RustType, TypeArray, TypeFnPtr, TypeParamBound, TypePath, TypePtr, TypeReference,
+    Variant as RustVariant, Visibility,
 };
 
 pub(crate) mod kw {
@@ -42,6 +44,10 @@ pub(crate) fn parse_items(
                 Err(err) => cx.push(err),
             },
             Item::Enum(item) => apis.push(parse_enum(cx, item, namespace)),
+            Item::Const(item) => match parse_const(cx, item, namespace) {
+                Ok(konst) => apis.push(konst),
+                Err(err) => cx.push(err),
+            },
             Item::ForeignMod(foreign_mod) => {
                 parse_foreign_mod(cx, foreign_mod, &mut apis, trusted, namespace);
             }
@@ -164,6 +170,8 @@ fn parse_struct(cx: &mut Errors, mut item: ItemStruct, namespace: &Namespace) ->
         let visibility = visibility_pub(&field.vis, ident.span());
         let name = pair(Namespace::default(), &ident, cxx_name, rust_name);
         let colon_token = field.colon_token.unwrap();
+        let cxx_default = None;
+        let cxx_char16 = false;
         fields.push(Var {
             cfg,
             doc,
@@ -172,6 +180,8 @@ fn parse_struct(cx: &mut Errors, mut item: ItemStruct, namespace: &Namespace) ->
             name,
             colon_token,
             ty,
+            cxx_default,
+            cxx_char16,
         });
     }
 
@@ -351,6 +361,59 @@ fn parse_variant(
     })
 }
 
+fn parse_const(cx: &mut Errors, mut item: ItemConst, namespace: &Namespace) -> Result<Api> {
+    let mut cfg = CfgExpr::Unconditional;
+    let mut doc = Doc::new();
+    let mut namespace = namespace.clone();
+    let mut cxx_name = None;
+    let mut rust_name = None;
+    let mut self_type = None;
+    let attrs = attrs::parse(
+        cx,
+        mem::take(&mut item.attrs),
+        attrs::Parser {
+            cfg: Some(&mut cfg),
+            doc: Some(&mut doc),
+            namespace: Some(&mut namespace),
+            cxx_name: Some(&mut cxx_name),
+            rust_name: Some(&mut rust_name),
+            self_type: Some(&mut self_type),
+            ..Default::default()
+        },
+    );
+
+    if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
+        return Err(Error::new_spanned(
+            item.generics,
+            "generic const is not supported",
+        ));
+    }
+
+    let const_token = item.const_token;
+    let visibility = visibility_pub(&item.vis, const_token.span);
+    let name = pair(namespace, &item.ident, cxx_name, rust_name);
+    let colon_token = item.colon_token;
+    let ty = parse_type(&item.ty)?;
+    let eq_token = item.eq_token;
+    let expr = *item.expr;
+    let semi_token = item.semi_token;
+
+    Ok(Api::Const(Const {
+        cfg,
+        doc,
+        attrs,
+        visibility,
+        const_token,
+        name,
+        self_type,
+        colon_token,
+        ty,
+        eq_token,
+        expr,
+        semi_token,
+    }))
+}
+
 fn parse_foreign_mod(
     cx: &mut Errors,
     foreign_mod: ItemForeignMod,
@@ -379,16 +442,34 @@ fn parse_foreign_mod(
 
     let mut cfg = CfgExpr::Unconditional;
     let mut namespace = namespace.clone();
+    let mut catch_unwind = false;
+    let mut error_details = false;
     let attrs = attrs::parse(
         cx,
         foreign_mod.attrs,
         attrs::Parser {
             cfg: Some(&mut cfg),
             namespace: Some(&mut namespace),
+            catch_unwind: Some(&mut catch_unwind),
+            error_details: Some(&mut error_details),
             ..Default::default()
         },
     );
 
+    if catch_unwind && lang != Lang::Rust {
+        cx.error(
+            &foreign_mod.abi,
+            "#[catch_unwind] is only supported on extern \"Rust\" blocks",
+        );
+    }
+
+    if error_details && lang != Lang::Rust {
+        cx.error(
+            &foreign_mod.abi,
+            "#[error_details] is only supported on extern \"Rust\" blocks",
+        );
+    }
+
     let mut items = Vec::new();
     for foreign in foreign_mod.items {
         match foreign {
@@ -398,7 +479,13 @@ fn parse_foreign_mod(
             }
             ForeignItem::Fn(foreign) => {
                 match parse_extern_fn(cx, foreign, lang, trusted, &cfg, &namespace, &attrs) {
-                    Ok(efn) => items.push(efn),
+                    Ok(efns) => items.extend(efns),
+                    Err(err) => cx.push(err),
+                }
+            }
+            ForeignItem::Static(foreign) => {
+                match parse_extern_static(cx, foreign, lang, &cfg, &namespace, &attrs) {
+                    Ok(stc) => items.push(stc),
                     Err(err) => cx.push(err),
                 }
             }
@@ -424,6 +511,7 @@ fn parse_foreign_mod(
     if !trusted
         && items.iter().any(|api| match api {
             Api::CxxFunction(efn) => efn.unsafety.is_none(),
+            Api::CxxStatic(_) => true,
             _ => false,
         })
     {
@@ -433,6 +521,22 @@ fn parse_foreign_mod(
         );
     }
 
+    if catch_unwind {
+        for item in &mut items {
+            if let Api::RustFunction(efn) = item {
+                efn.sig.catch_unwind = true;
+            }
+        }
+    }
+
+    if error_details {
+        for item in &mut items {
+            if let Api::RustFunction(efn) = item {
+                efn.sig.error_details = efn.sig.throws;
+            }
+        }
+    }
+
     let mut types = items.iter().filter_map(|item| match item {
         Api::CxxType(ety) | Api::RustType(ety) => Some(&ety.name),
         Api::TypeAlias(alias) => Some(&alias.name),
@@ -488,6 +592,8 @@ fn parse_extern_type(
     let mut namespace = namespace.clone();
     let mut cxx_name = None;
     let mut rust_name = None;
+    let mut cxx_deleter = None;
+    let mut enable_shared_from_this = None;
     let mut attrs = attrs.clone();
     attrs.extend(attrs::parse(
         cx,
@@ -499,6 +605,8 @@ fn parse_extern_type(
             namespace: Some(&mut namespace),
             cxx_name: Some(&mut cxx_name),
             rust_name: Some(&mut rust_name),
+            cxx_deleter: Some(&mut cxx_deleter),
+            enable_shared_from_this: Some(&mut enable_shared_from_this),
             ..Default::default()
         },
     ));
@@ -506,6 +614,7 @@ fn parse_extern_type(
     let type_token = foreign_type.type_token;
     let visibility = visibility_pub(&foreign_type.vis, type_token.span);
     let name = pair(namespace, &foreign_type.ident, cxx_name, rust_name);
+    let shared_from_this = enable_shared_from_this.map(|span| shared_from_this(&name, span));
     let generics = extern_type_lifetimes(cx, foreign_type.generics);
     let colon_token = None;
     let bounds = Vec::new();
@@ -528,9 +637,86 @@ fn parse_extern_type(
         bounds,
         semi_token,
         trusted,
+        cxx_deleter,
+        shared_from_this,
     })
 }
 
+fn shared_from_this(name: &Pair, span: Span) -> SharedFromThis {
+    let ptr = |ptr_name: &str| {
+        Box::new(Ty1 {
+            name: Ident::new(ptr_name, span),
+            langle: Token![<](span),
+            inner: Type::Ident(NamedType {
+                rust: name.rust.clone(),
+                generics: Lifetimes::default(),
+            }),
+            rangle: Token![>](span),
+        })
+    };
+    SharedFromThis {
+        shared_ptr: Type::SharedPtr(ptr("SharedPtr")),
+        weak_ptr: Type::WeakPtr(ptr("WeakPtr")),
+    }
+}
+
+fn parse_extern_static(
+    cx: &mut Errors,
+    foreign_static: ForeignItemStatic,
+    lang: Lang,
+    extern_block_cfg: &CfgExpr,
+    namespace: &Namespace,
+    attrs: &OtherAttrs,
+) -> Result<Api> {
+    let mut cfg = extern_block_cfg.clone();
+    let mut doc = Doc::new();
+    let mut namespace = namespace.clone();
+    let mut cxx_name = None;
+    let mut rust_name = None;
+    let mut attrs = attrs.clone();
+    attrs.extend(attrs::parse(
+        cx,
+        foreign_static.attrs,
+        attrs::Parser {
+            cfg: Some(&mut cfg),
+            doc: Some(&mut doc),
+            namespace: Some(&mut namespace),
+            cxx_name: Some(&mut cxx_name),
+            rust_name: Some(&mut rust_name),
+            ..Default::default()
+        },
+    ));
+
+    if let StaticMutability::Mut(mut_token) = foreign_static.mutability {
+        let static_token = foreign_static.static_token;
+        let span = quote!(#static_token #mut_token);
+        return Err(Error::new_spanned(span, "mutable static is not supported"));
+    }
+
+    let static_token = foreign_static.static_token;
+    let visibility = visibility_pub(&foreign_static.vis, static_token.span);
+    let name = pair(namespace, &foreign_static.ident, cxx_name, rust_name);
+    let colon_token = foreign_static.colon_token;
+    let ty = parse_type(&foreign_static.ty)?;
+    let semi_token = foreign_static.semi_token;
+
+    Ok((match lang {
+        Lang::Cxx | Lang::CxxUnwind => Api::CxxStatic,
+        Lang::Rust => Api::RustStatic,
+    })(ExternStatic {
+        cfg,
+        lang,
+        doc,
+        attrs,
+        visibility,
+        static_token,
+        name,
+        colon_token,
+        ty,
+        semi_token,
+    }))
+}
+
 fn parse_extern_fn(
     cx: &mut Errors,
     mut foreign_fn: ForeignItemFn,
@@ -539,13 +725,17 @@ fn parse_extern_fn(
     extern_block_cfg: &CfgExpr,
     namespace: &Namespace,
     attrs: &OtherAttrs,
-) -> Result<Api> {
+) -> Result<Vec<Api>> {
     let mut cfg = extern_block_cfg.clone();
     let mut doc = Doc::new();
     let mut namespace = namespace.clone();
     let mut cxx_name = None;
     let mut rust_name = None;
     let mut self_type = None;
+    let mut catch_unwind = false;
+    let mut error_details = false;
+    let mut instantiate = Vec::new();
+    let mut cxx_template = None;
     let mut attrs = attrs.clone();
     attrs.extend(attrs::parse(
         cx,
@@ -557,23 +747,73 @@ fn parse_extern_fn(
             cxx_name: Some(&mut cxx_name),
             rust_name: Some(&mut rust_name),
             self_type: Some(&mut self_type),
+            catch_unwind: Some(&mut catch_unwind),
+            error_details: Some(&mut error_details),
+            instantiate: Some(&mut instantiate),
+            cxx_template: Some(&mut cxx_template),
             ..Default::default()
         },
     ));
 
     let generics = &foreign_fn.sig.generics;
-    if generics.where_clause.is_some()
-        || generics.params.iter().any(|param| match param {
-            GenericParam::Lifetime(lifetime) => !lifetime.bounds.is_empty(),
-            GenericParam::Type(_) | GenericParam::Const(_) => true,
-        })
-    {
+    let mut type_param = None;
+    let mut has_unsupported_generic_param = generics.where_clause.is_some();
+    for param in &generics.params {
+        match param {
+            GenericParam::Lifetime(lifetime) => {
+                has_unsupported_generic_param |= !lifetime.bounds.is_empty();
+            }
+            GenericParam::Type(param)
+                if !instantiate.is_empty()
+                    && type_param.is_none()
+                    && param.colon_token.is_none()
+                    && param.default.is_none() =>
+            {
+                type_param = Some(param.ident.clone());
+            }
+            GenericParam::Type(_) | GenericParam::Const(_) => {
+                has_unsupported_generic_param = true;
+            }
+        }
+    }
+    if has_unsupported_generic_param {
         return Err(Error::new_spanned(
             foreign_fn,
             "extern function with generic parameters is not supported yet",
         ));
     }
 
+    if !instantiate.is_empty() {
+        if lang != Lang::Rust {
+            return Err(Error::new_spanned(
+                foreign_fn,
+                "#[instantiate] is only supported on extern Rust functions",
+            ));
+        }
+        if type_param.is_none() {
+            return Err(Error::new_spanned(
+                foreign_fn,
+                "#[instantiate] requires a function with one type parameter",
+            ));
+        }
+    }
+
+    let cxx_template = match cxx_template {
+        Some(_) if lang == Lang::Rust => {
+            return Err(Error::new_spanned(
+                foreign_fn,
+                "#[cxx_template] is only supported on extern C++ functions",
+            ));
+        }
+        Some(template_args) => Some(
+            template_args
+                .iter()
+                .map(parse_type)
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        None => None,
+    };
+
     if let Some(variadic) = &foreign_fn.sig.variadic {
         return Err(Error::new_spanned(
             variadic,
@@ -605,9 +845,118 @@ fn parse_extern_fn(
         ));
     }
 
+    let visibility = visibility_pub(&foreign_fn.vis, inherited_span(&foreign_fn.sig));
+    let name = pair(namespace, &foreign_fn.sig.ident, cxx_name, rust_name);
+    let semi_token = foreign_fn.semi_token;
+    let api = match lang {
+        Lang::Cxx | Lang::CxxUnwind => Api::CxxFunction,
+        Lang::Rust => Api::RustFunction,
+    };
+
+    let Some(param) = type_param else {
+        let sig =
+            parse_extern_fn_signature(cx, &foreign_fn.sig, self_type, catch_unwind, error_details)?;
+        return Ok(vec![api(ExternFn {
+            cfg,
+            lang,
+            doc,
+            attrs,
+            visibility,
+            name,
+            sig,
+            semi_token,
+            trusted,
+            instance: None,
+            cxx_template,
+        })]);
+    };
+
+    let template = parse_extern_fn_signature(
+        cx,
+        &foreign_fn.sig,
+        self_type.clone(),
+        catch_unwind,
+        error_details,
+    )?;
+    let mut template = Some(Box::new(template));
+
+    let mut generic_sig = foreign_fn.sig;
+    generic_sig.generics.params = generic_sig
+        .generics
+        .params
+        .into_pairs()
+        .filter(|pair| matches!(pair.value(), GenericParam::Lifetime(_)))
+        .collect();
+    if generic_sig.generics.params.is_empty() {
+        generic_sig.generics.lt_token = None;
+        generic_sig.generics.gt_token = None;
+    }
+    let generic_sig = generic_sig.into_token_stream();
+
+    let mut apis = Vec::new();
+    for arg in instantiate {
+        let tokens = substitute_type_param(generic_sig.clone(), &param, &arg);
+        let sig: RustSignature = syn::parse2(tokens)?;
+        let sig =
+            parse_extern_fn_signature(cx, &sig, self_type.clone(), catch_unwind, error_details)?;
+        apis.push(api(ExternFn {
+            cfg: cfg.clone(),
+            lang,
+            doc: doc.clone(),
+            attrs: attrs.clone(),
+            visibility,
+            name: name.clone(),
+            sig,
+            semi_token,
+            trusted,
+            instance: Some(FnInstance {
+                param: param.clone(),
+                arg: NamedType::new(arg),
+                template: template.take(),
+            }),
+            cxx_template: None,
+        }));
+    }
+    Ok(apis)
+}
+
+fn inherited_span(sig: &RustSignature) -> Span {
+    match &sig.safety {
+        Safety::Unsafe(unsafety) => unsafety.span,
+        Safety::Safe(_) | Safety::Default => sig.fn_token.span,
+    }
+}
+
+// Replaces every occurrence of the generic type parameter `param` in a
+// function signature with the concrete type `arg`.
+fn substitute_type_param(tokens: TokenStream, param: &Ident, arg: &Ident) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(ident) if ident == *param => {
+                TokenTree::Ident(Ident::new(&arg.to_string(), ident.span()))
+            }
+            TokenTree::Group(group) => {
+                let stream = substitute_type_param(group.stream(), param, arg);
+                let mut substituted = Group::new(group.delimiter(), stream);
+                substituted.set_span(group.span());
+                TokenTree::Group(substituted)
+            }
+            tt => tt,
+        })
+        .collect()
+}
+
+fn parse_extern_fn_signature(
+    cx: &mut Errors,
+    sig: &RustSignature,
+    self_type: Option<Ident>,
+    catch_unwind: bool,
+    error_details: bool,
+) -> Result<Signature> {
     let mut receiver = None;
     let mut args = Punctuated::new();
-    for arg in foreign_fn.sig.inputs.pairs() {
+    for arg in sig.inputs.pairs() {
         let (arg, comma) = arg.into_tuple();
         match arg {
             FnArg::Receiver(arg) => {
@@ -663,7 +1012,18 @@ fn parse_extern_fn(
                 let ty = parse_type(&arg.ty)?;
                 let cfg = CfgExpr::Unconditional;
                 let doc = Doc::new();
-                let attrs = OtherAttrs::new();
+                let mut cxx_default = None;
+                let mut cxx_char16 = false;
+                let attrs = attrs::parse(
+                    cx,
+                    arg.attrs.clone(),
+                    attrs::Parser {
+                        cxx_default: Some(&mut cxx_default),
+                        cxx_char16: Some(&mut cxx_char16),
+                        ignore_unrecognized: true,
+                        ..Default::default()
+                    },
+                );
                 let visibility = Token![pub](ident.span());
                 let name = pair(Namespace::default(), &ident, None, None);
                 let colon_token = arg.colon_token;
@@ -675,6 +1035,8 @@ fn parse_extern_fn(
                     name,
                     colon_token,
                     ty,
+                    cxx_default,
+                    cxx_char16,
                 });
                 if let Some(comma) = comma {
                     args.push_punct(*comma);
@@ -695,46 +1057,31 @@ fn parse_extern_fn(
     };
 
     let mut throws_tokens = None;
-    let ret = parse_return_type(&foreign_fn.sig.output, &mut throws_tokens)?;
+    let ret = parse_return_type(&sig.output, &mut throws_tokens)?;
     let throws = throws_tokens.is_some();
-    let asyncness = foreign_fn.sig.asyncness;
-    let unsafety = match foreign_fn.sig.safety {
+    let asyncness = sig.asyncness;
+    let unsafety = match sig.safety {
         Safety::Safe(_) | Safety::Default => None,
         Safety::Unsafe(unsafety) => Some(unsafety),
     };
-    let fn_token = foreign_fn.sig.fn_token;
-    let inherited_span = unsafety.map_or(fn_token.span, |unsafety| unsafety.span);
-    let visibility = visibility_pub(&foreign_fn.vis, inherited_span);
-    let name = pair(namespace, &foreign_fn.sig.ident, cxx_name, rust_name);
-    let generics = generics.clone();
-    let paren_token = foreign_fn.sig.paren_token;
-    let semi_token = foreign_fn.semi_token;
+    let fn_token = sig.fn_token;
+    let generics = sig.generics.clone();
+    let paren_token = sig.paren_token;
 
-    Ok(match lang {
-        Lang::Cxx | Lang::CxxUnwind => Api::CxxFunction,
-        Lang::Rust => Api::RustFunction,
-    }(ExternFn {
-        cfg,
-        lang,
-        doc,
-        attrs,
-        visibility,
-        name,
-        sig: Signature {
-            asyncness,
-            unsafety,
-            fn_token,
-            generics,
-            kind,
-            args,
-            ret,
-            throws,
-            paren_token,
-            throws_tokens,
-        },
-        semi_token,
-        trusted,
-    }))
+    Ok(Signature {
+        asyncness,
+        unsafety,
+        fn_token,
+        generics,
+        kind,
+        args,
+        ret,
+        throws,
+        catch_unwind,
+        error_details,
+        paren_token,
+        throws_tokens,
+    })
 }
 
 fn parse_extern_verbatim(
@@ -895,6 +1242,7 @@ fn parse_type_alias(
     let mut namespace = namespace.clone();
     let mut cxx_name = None;
     let mut rust_name = None;
+    let mut cxx_deleter = None;
     let mut attrs = attrs.clone();
     attrs.extend(attrs::parse(
         cx,
@@ -906,6 +1254,7 @@ fn parse_type_alias(
             namespace: Some(&mut namespace),
             cxx_name: Some(&mut cxx_name),
             rust_name: Some(&mut rust_name),
+            cxx_deleter: Some(&mut cxx_deleter),
             ..Default::default()
         },
     ));
@@ -931,6 +1280,7 @@ fn parse_type_alias(
         eq_token,
         ty,
         semi_token,
+        cxx_deleter,
     }))
 }
 
@@ -991,6 +1341,8 @@ fn parse_extern_type_bounded(
     let mut namespace = namespace.clone();
     let mut cxx_name = None;
     let mut rust_name = None;
+    let mut cxx_deleter = None;
+    let mut enable_shared_from_this = None;
     let mut attrs = attrs.clone();
     attrs.extend(attrs::parse(
         cx,
@@ -1002,12 +1354,15 @@ fn parse_extern_type_bounded(
             namespace: Some(&mut namespace),
             cxx_name: Some(&mut cxx_name),
             rust_name: Some(&mut rust_name),
+            cxx_deleter: Some(&mut cxx_deleter),
+            enable_shared_from_this: Some(&mut enable_shared_from_this),
             ..Default::default()
         },
     ));
 
     let visibility = visibility_pub(&visibility, type_token.span);
     let name = pair(namespace, &ident, cxx_name, rust_name);
+    let shared_from_this = enable_shared_from_this.map(|span| shared_from_this(&name, span));
 
     Ok(match lang {
         Lang::Cxx | Lang::CxxUnwind => Api::CxxType,
@@ -1026,22 +1381,32 @@ fn parse_extern_type_bounded(
         bounds,
         semi_token,
         trusted,
+        cxx_deleter,
+        shared_from_this,
     }))
 }
 
-fn parse_impl(cx: &mut Errors, imp: ItemImpl) -> Result<Api> {
+fn parse_impl(cx: &mut Errors, mut imp: ItemImpl) -> Result<Api> {
     let impl_token = imp.impl_token;
 
     let mut cfg = CfgExpr::Unconditional;
     let attrs = attrs::parse(
         cx,
-        imp.attrs,
+        mem::take(&mut imp.attrs),
         attrs::Parser {
             cfg: Some(&mut cfg),
             ..Default::default()
         },
     );
 
+    if let Some((path, _for_token)) = &imp.trait_ {
+        if (path.is_ident("Iterator") || path.is_ident("IntoIterator"))
+            && imp.modifiers.polarity.is_none()
+        {
+            return parse_impl_iterator(cfg, attrs, imp);
+        }
+    }
+
     if !imp.items.is_empty() {
         let mut span = Group::new(Delimiter::Brace, TokenStream::new());
         span.set_span(imp.brace_token.span.join());
@@ -1125,6 +1490,86 @@ fn parse_impl(cx: &mut Errors, imp: ItemImpl) -> Result<Api> {
     }))
 }
 
+fn parse_impl_iterator(cfg: CfgExpr, attrs: OtherAttrs, imp: ItemImpl) -> Result<Api> {
+    let impl_token = imp.impl_token;
+    let (path, for_token) = imp.trait_.unwrap();
+    let trait_token = path.get_ident().unwrap().clone();
+
+    imp.modifiers.require_empty()?;
+
+    if !imp.generics.params.is_empty() || imp.generics.where_clause.is_some() {
+        let generics = &imp.generics;
+        return Err(Error::new_spanned(
+            quote!(#impl_token #generics),
+            "generic parameter on an impl is not supported yet",
+        ));
+    }
+
+    // The C++ direction iterates a container by shared reference:
+    // `impl IntoIterator for &Container`.
+    let cxx = trait_token == "IntoIterator";
+    let (ampersand, self_ty) = match &*imp.self_ty {
+        RustType::Reference(ty) if cxx && ty.mutability.is_none() => {
+            (Some(ty.and_token), &*ty.elem)
+        }
+        ty => (None, ty),
+    };
+    let self_ty = match self_ty {
+        RustType::Path(ty) if ty.qself.is_none() && ampersand.is_some() == cxx => {
+            ty.path.get_ident()
+        }
+        _ => None,
+    };
+    let Some(self_ty) = self_ty else {
+        let msg = if cxx {
+            "expected a shared reference to an opaque C++ type from this bridge"
+        } else {
+            "expected an opaque Rust type from this bridge"
+        };
+        return Err(Error::new_spanned(imp.self_ty, msg));
+    };
+    let self_ty = NamedType::new(self_ty.clone());
+
+    let mut item = None;
+    for impl_item in &imp.items {
+        match impl_item {
+            ImplItem::Type(assoc)
+                if assoc.ident == "Item" && assoc.generics.params.is_empty() && item.is_none() =>
+            {
+                item = Some(parse_type(&assoc.ty)?);
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    impl_item,
+                    "unexpected item, expected only `type Item = ...;`",
+                ));
+            }
+        }
+    }
+    let Some(item) = item else {
+        let mut span = Group::new(Delimiter::Brace, TokenStream::new());
+        span.set_span(imp.brace_token.span.join());
+        return Err(Error::new_spanned(span, "missing `type Item = ...;`"));
+    };
+
+    let iter = IterImpl {
+        cfg,
+        attrs,
+        impl_token,
+        trait_token,
+        for_token,
+        ampersand,
+        self_ty,
+        brace_token: imp.brace_token,
+        item,
+    };
+    Ok(if cxx {
+        Api::CxxIterator(iter)
+    } else {
+        Api::RustIterator(iter)
+    })
+}
+
 fn parse_include(input: ParseStream) -> Result<Include> {
     if input.peek(LitStr) {
         let lit: LitStr = input.parse()?;
@@ -1292,6 +1737,36 @@ fn parse_type_path(ty: &TypePath) -> Result<Type> {
                             rangle: generic.gt_token,
                         })));
                     }
+                } else if ident == "CxxDeque" && generic.args.len() == 1 {
+                    if let GenericArgument::Type(arg) = &generic.args[0] {
+                        let inner = parse_type(arg)?;
+                        return Ok(Type::CxxDeque(Box::new(Ty1 {
+                            name: ident,
+                            langle: generic.lt_token,
+                            inner,
+                            rangle: generic.gt_token,
+                        })));
+                    }
+                } else if ident == "CxxList" && generic.args.len() == 1 {
+                    if let GenericArgument::Type(arg) = &generic.args[0] {
+                        let inner = parse_type(arg)?;
+                        return Ok(Type::CxxList(Box::new(Ty1 {
+                            name: ident,
+                            langle: generic.lt_token,
+                            inner,
+                            rangle: generic.gt_token,
+                        })));
+                    }
+                } else if ident == "CxxSet" && generic.args.len() == 1 {
+                    if let GenericArgument::Type(arg) = &generic.args[0] {
+                        let inner = parse_type(arg)?;
+                        return Ok(Type::CxxSet(Box::new(Ty1 {
+                            name: ident,
+                            langle: generic.lt_token,
+                            inner,
+                            rangle: generic.gt_token,
+                        })));
+                    }
                 } else if ident == "Box" && generic.args.len() == 1 {
                     if let GenericArgument::Type(arg) = &generic.args[0] {
                         let inner = parse_type(arg)?;
@@ -1302,6 +1777,16 @@ fn parse_type_path(ty: &TypePath) -> Result<Type> {
                             rangle: generic.gt_token,
                         })));
                     }
+                } else if ident == "Arc" && generic.args.len() == 1 {
+                    if let GenericArgument::Type(arg) = &generic.args[0] {
+                        let inner = parse_type(arg)?;
+                        return Ok(Type::RustArc(Box::new(Ty1 {
+                            name: ident,
+                            langle: generic.lt_token,
+                            inner,
+                            rangle: generic.gt_token,
+                        })));
+                    }
                 } else if ident == "Vec" && generic.args.len() == 1 {
                     if let GenericArgument::Type(arg) = &generic.args[0] {
                         let inner = parse_type(arg)?;
@@ -1430,6 +1915,8 @@ fn parse_type_fn(ty: &TypeFnPtr) -> Result<Type> {
             let attrs = OtherAttrs::new();
             let visibility = Token![pub](ident.span());
             let name = pair(Namespace::default(), &ident, None, None);
+            let cxx_default = None;
+            let cxx_char16 = false;
             Ok(Var {
                 cfg,
                 doc,
@@ -1438,6 +1925,8 @@ fn parse_type_fn(ty: &TypeFnPtr) -> Result<Type> {
                 name,
                 colon_token,
                 ty,
+                cxx_default,
+                cxx_char16,
             })
         })
         .collect::<Result<_>>()?;
@@ -1445,6 +1934,8 @@ fn parse_type_fn(ty: &TypeFnPtr) -> Result<Type> {
     let mut throws_tokens = None;
     let ret = parse_return_type(&ty.output, &mut throws_tokens)?;
     let throws = throws_tokens.is_some();
+    let catch_unwind = false;
+    let error_details = false;
 
     let asyncness = None;
     let unsafety = ty.unsafety;
@@ -1462,6 +1953,8 @@ fn parse_type_fn(ty: &TypeFnPtr) -> Result<Type> {
         args,
         ret,
         throws,
+        catch_unwind,
+        error_details,
         paren_token,
         throws_tokens,
     })))
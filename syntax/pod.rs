@@ -11,7 +11,7 @@ impl<'a> Types<'a> {
                     match atom {
                         Bool | Char | U8 | U16 | U32 | U64 | Usize | I8 | I16 | I32 | I64
                         | Isize | F32 | F64 => true,
-                        CxxString | RustString => false,
+                        CxxString | CxxU16String | CxxU32String | RustString => false,
                     }
                 } else if let Some(strct) = self.structs.get(ident) {
                     strct.fields.iter().all(|field| {
@@ -22,11 +22,15 @@ impl<'a> Types<'a> {
                 }
             }
             TypeQuery::RustBox
+            | TypeQuery::RustArc
             | TypeQuery::RustVec
             | TypeQuery::UniquePtr
             | TypeQuery::SharedPtr
             | TypeQuery::WeakPtr
             | TypeQuery::CxxVector
+            | TypeQuery::CxxDeque
+            | TypeQuery::CxxList
+            | TypeQuery::CxxSet
             | TypeQuery::Void => false,
             TypeQuery::Ref(_)
             | TypeQuery::Str
@@ -14,7 +14,7 @@ pub(crate) fn kind(ty: &Type) -> Option<PrimitiveKind> {
             Char | U8 | U16 | U32 | U64 | Usize | I8 | I16 | I32 | I64 | Isize | F32 | F64 => {
                 Some(PrimitiveKind::Number)
             }
-            CxxString | RustString => None,
+            CxxString | CxxU16String | CxxU32String | RustString => None,
         }),
         Type::Ptr(_) => Some(PrimitiveKind::Pointer),
         _ => None,
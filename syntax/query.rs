@@ -4,6 +4,7 @@ use crate::syntax::{Array, NamedType, Ptr, Ref, Type};
 pub(crate) enum TypeQuery<'a> {
     Ident(&'a NamedType),
     RustBox,
+    RustArc,
     RustVec,
     UniquePtr,
     SharedPtr,
@@ -12,6 +13,9 @@ pub(crate) enum TypeQuery<'a> {
     Ptr(&'a Ptr),
     Str,
     CxxVector,
+    CxxDeque,
+    CxxList,
+    CxxSet,
     Fn,
     Void,
     SliceRef,
@@ -29,6 +33,7 @@ impl<'a> From<&'a Type> for TypeQuery<'a> {
         match query {
             Type::Ident(query) => TypeQuery::Ident(query),
             Type::RustBox(_) => TypeQuery::RustBox,
+            Type::RustArc(_) => TypeQuery::RustArc,
             Type::RustVec(_) => TypeQuery::RustVec,
             Type::UniquePtr(_) => TypeQuery::UniquePtr,
             Type::SharedPtr(_) => TypeQuery::SharedPtr,
@@ -37,6 +42,9 @@ impl<'a> From<&'a Type> for TypeQuery<'a> {
             Type::Ptr(query) => TypeQuery::Ptr(query),
             Type::Str(_) => TypeQuery::Str,
             Type::CxxVector(_) => TypeQuery::CxxVector,
+            Type::CxxDeque(_) => TypeQuery::CxxDeque,
+            Type::CxxList(_) => TypeQuery::CxxList,
+            Type::CxxSet(_) => TypeQuery::CxxSet,
             Type::Fn(_) => TypeQuery::Fn,
             Type::Void(_) => TypeQuery::Void,
             Type::SliceRef(_) => TypeQuery::SliceRef,
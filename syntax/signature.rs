@@ -18,6 +18,12 @@ impl Signature {
         }
     }
 
+    // Whether the function's C ABI reports failure through a PtrLen error,
+    // either from a returned Result or from a caught panic.
+    pub fn fallible(&self) -> bool {
+        self.throws || self.catch_unwind
+    }
+
     pub fn self_type(&self) -> Option<&Ident> {
         match &self.kind {
             FnKind::Method(receiver) => Some(&receiver.ty.rust),
@@ -68,11 +74,15 @@ impl Signature {
                     }
                 }
                 Type::RustBox(ty1)
+                | Type::RustArc(ty1)
                 | Type::RustVec(ty1)
                 | Type::UniquePtr(ty1)
                 | Type::SharedPtr(ty1)
                 | Type::WeakPtr(ty1)
-                | Type::CxxVector(ty1) => collect_type(collect_lifetime, &ty1.inner),
+                | Type::CxxVector(ty1)
+                | Type::CxxDeque(ty1)
+                | Type::CxxList(ty1)
+                | Type::CxxSet(ty1) => collect_type(collect_lifetime, &ty1.inner),
                 Type::Ref(ty) | Type::Str(ty) => {
                     if let Some(lifetime) = &ty.lifetime {
                         collect_lifetime(lifetime);
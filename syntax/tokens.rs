@@ -1,7 +1,7 @@
 use crate::syntax::atom::Atom::*;
 use crate::syntax::{
-    Array, Atom, Derive, Enum, EnumRepr, ExternFn, ExternType, Impl, Lifetimes, NamedType, Ptr,
-    Ref, Signature, SliceRef, Struct, Ty1, Type, TypeAlias, Var,
+    Array, Atom, Const, Derive, Enum, EnumRepr, ExternFn, ExternStatic, ExternType, Impl, IterImpl,
+    Lifetimes, NamedType, Ptr, Ref, Signature, SliceRef, Struct, Ty1, Type, TypeAlias, Var,
 };
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{ToTokens, quote_spanned};
@@ -14,7 +14,10 @@ impl ToTokens for Type {
                 if ident.rust == Char {
                     let span = ident.rust.span();
                     tokens.extend(quote_spanned!(span=> ::cxx::core::ffi::));
-                } else if ident.rust == CxxString {
+                } else if ident.rust == CxxString
+                    || ident.rust == CxxU16String
+                    || ident.rust == CxxU32String
+                {
                     let span = ident.rust.span();
                     tokens.extend(quote_spanned!(span=> ::cxx::));
                 } else if ident.rust == RustString {
@@ -24,10 +27,14 @@ impl ToTokens for Type {
                 ident.to_tokens(tokens);
             }
             Type::RustBox(ty)
+            | Type::RustArc(ty)
             | Type::UniquePtr(ty)
             | Type::SharedPtr(ty)
             | Type::WeakPtr(ty)
             | Type::CxxVector(ty)
+            | Type::CxxDeque(ty)
+            | Type::CxxList(ty)
+            | Type::CxxSet(ty)
             | Type::RustVec(ty) => ty.to_tokens(tokens),
             Type::Ref(r) | Type::Str(r) => r.to_tokens(tokens),
             Type::Ptr(p) => p.to_tokens(tokens),
@@ -49,6 +56,8 @@ impl ToTokens for Var {
             name,
             colon_token: _,
             ty,
+            cxx_default: _,
+            cxx_char16: _,
         } = self;
         name.rust.to_tokens(tokens);
         Token![:](name.rust.span()).to_tokens(tokens);
@@ -66,12 +75,16 @@ impl ToTokens for Ty1 {
         } = self;
         let span = name.span();
         match name.to_string().as_str() {
-            "UniquePtr" | "SharedPtr" | "WeakPtr" | "CxxVector" => {
+            "UniquePtr" | "SharedPtr" | "WeakPtr" | "CxxVector" | "CxxDeque" | "CxxList"
+            | "CxxSet" => {
                 tokens.extend(quote_spanned!(span=> ::cxx::));
             }
             "Box" => {
                 tokens.extend(quote_spanned!(span=> ::cxx::alloc::boxed::));
             }
+            "Arc" => {
+                tokens.extend(quote_spanned!(span=> ::cxx::alloc::sync::));
+            }
             "Vec" => {
                 tokens.extend(quote_spanned!(span=> ::cxx::alloc::vec::));
             }
@@ -180,6 +193,22 @@ impl ToTokens for ExternType {
     }
 }
 
+impl ToTokens for Const {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // Notional token range for error reporting purposes.
+        self.const_token.to_tokens(tokens);
+        self.name.rust.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for ExternStatic {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        // Notional token range for error reporting purposes.
+        self.static_token.to_tokens(tokens);
+        self.name.rust.to_tokens(tokens);
+    }
+}
+
 impl ToTokens for TypeAlias {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         // Notional token range for error reporting purposes.
@@ -236,6 +265,28 @@ impl ToTokens for Impl {
     }
 }
 
+impl ToTokens for IterImpl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let IterImpl {
+            cfg: _,
+            attrs: _,
+            impl_token,
+            trait_token,
+            for_token,
+            ampersand,
+            self_ty,
+            brace_token,
+            item: _,
+        } = self;
+        impl_token.to_tokens(tokens);
+        trait_token.to_tokens(tokens);
+        for_token.to_tokens(tokens);
+        ampersand.to_tokens(tokens);
+        self_ty.to_tokens(tokens);
+        brace_token.surround(tokens, |_tokens| {});
+    }
+}
+
 impl ToTokens for Lifetimes {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Lifetimes {
@@ -260,6 +311,8 @@ impl ToTokens for Signature {
             args,
             ret,
             throws: _,
+            catch_unwind: _,
+            error_details: _,
             paren_token,
             throws_tokens,
         } = self;
@@ -11,11 +11,13 @@ use crate::syntax::trivial::{self, TrivialReason};
 use crate::syntax::unpin::{self, UnpinReason};
 use crate::syntax::visit::{self, Visit};
 use crate::syntax::{
-    Api, Atom, Enum, ExternFn, ExternType, Impl, Lifetimes, Pair, Struct, Type, TypeAlias, toposort,
+    Api, Atom, Enum, ExternFn, ExternType, Impl, Lang, Lifetimes, Pair, Struct, Type, TypeAlias,
+    toposort,
 };
 use indexmap::map::Entry;
 use proc_macro2::Ident;
 use quote::ToTokens;
+use syn::LitStr;
 
 pub(crate) struct Types<'a> {
     pub all: OrderedMap<&'a Type, ComputedCfg<'a>>,
@@ -25,6 +27,7 @@ pub(crate) struct Types<'a> {
     pub rust: UnorderedSet<&'a Ident>,
     pub aliases: UnorderedMap<&'a Ident, &'a TypeAlias>,
     pub untrusted: UnorderedMap<&'a Ident, &'a ExternType>,
+    pub deleters: UnorderedMap<&'a Ident, &'a LitStr>,
     pub required_trivial: UnorderedMap<&'a Ident, Vec<TrivialReason<'a>>>,
     #[cfg_attr(not(proc_macro), expect(dead_code))]
     pub required_unpin: UnorderedMap<&'a Ident, UnpinReason<'a>>,
@@ -32,6 +35,8 @@ pub(crate) struct Types<'a> {
     pub resolutions: UnorderedMap<&'a Ident, Resolution<'a>>,
     #[cfg_attr(not(proc_macro), expect(dead_code))]
     pub associated_fn: UnorderedMap<&'a Ident, Vec<&'a ExternFn>>,
+    #[cfg_attr(proc_macro, expect(dead_code))]
+    pub template_params: UnorderedSet<&'a Ident>,
     pub struct_improper_ctypes: UnorderedSet<&'a Ident>,
     pub toposorted_structs: Vec<&'a Struct>,
 }
@@ -53,9 +58,11 @@ impl<'a> Types<'a> {
         let mut rust = UnorderedSet::new();
         let mut aliases = UnorderedMap::new();
         let mut untrusted = UnorderedMap::new();
+        let mut deleters = UnorderedMap::new();
         let mut impls = OrderedMap::new();
         let mut resolutions = UnorderedMap::new();
         let mut associated_fn = UnorderedMap::new();
+        let mut template_params = UnorderedSet::new();
         let struct_improper_ctypes = UnorderedSet::new();
         let toposorted_structs = Vec::new();
 
@@ -166,6 +173,13 @@ impl<'a> Types<'a> {
                     if !ety.trusted {
                         untrusted.insert(ident, ety);
                     }
+                    if let Some(cxx_deleter) = &ety.cxx_deleter {
+                        deleters.insert(ident, cxx_deleter);
+                    }
+                    if let Some(shared_from_this) = &ety.shared_from_this {
+                        visit(&mut all, &shared_from_this.shared_ptr, &ety.cfg);
+                        visit(&mut all, &shared_from_this.weak_ptr, &ety.cfg);
+                    }
                     add_resolution(&ety.name, &ety.attrs, &ety.generics);
                 }
                 Api::RustType(ety) => {
@@ -186,17 +200,32 @@ impl<'a> Types<'a> {
                             .or_insert_with(Vec::new)
                             .push(efn);
                     }
-                    if !self_type.is_some_and(|self_type| self_type == "Self")
+                    // Instantiations of one generic function share its name;
+                    // only the first one declares it.
+                    let declares_name = match &efn.instance {
+                        Some(instance) => instance.template.is_some(),
+                        None => true,
+                    };
+                    if declares_name
+                        && !self_type.is_some_and(|self_type| self_type == "Self")
                         && !function_names.insert((self_type, &efn.name.rust))
                     {
                         duplicate_name(cx, efn, ItemName::Function(self_type, &efn.name.rust));
                     }
+                    if let Some(instance) = &efn.instance {
+                        if instance.template.is_some() {
+                            template_params.insert(&instance.param);
+                        }
+                    }
                     for arg in &efn.args {
                         visit(&mut all, &arg.ty, &efn.cfg);
                     }
                     if let Some(ret) = &efn.ret {
                         visit(&mut all, ret, &efn.cfg);
                     }
+                    for template_arg in efn.cxx_template.iter().flatten() {
+                        visit(&mut all, template_arg, &efn.cfg);
+                    }
                 }
                 Api::TypeAlias(alias) => {
                     let ident = &alias.name.rust;
@@ -205,11 +234,35 @@ impl<'a> Types<'a> {
                     }
                     cxx.insert(ident);
                     aliases.insert(ident, alias);
+                    if let Some(cxx_deleter) = &alias.cxx_deleter {
+                        deleters.insert(ident, cxx_deleter);
+                    }
                     add_resolution(&alias.name, &alias.attrs, &alias.generics);
                 }
                 Api::Impl(imp) => {
                     visit(&mut all, &imp.ty, &imp.cfg);
                 }
+                Api::Const(konst) => {
+                    let self_type = konst.self_type.as_ref();
+                    if !function_names.insert((self_type, &konst.name.rust)) {
+                        duplicate_name(cx, konst, ItemName::Value(self_type, &konst.name.rust));
+                    }
+                    visit(&mut all, &konst.ty, &konst.cfg);
+                }
+                Api::CxxStatic(stc) | Api::RustStatic(stc) => {
+                    if !function_names.insert((None, &stc.name.rust)) {
+                        duplicate_name(cx, stc, ItemName::Value(None, &stc.name.rust));
+                    }
+                    // The Rust side of a C++ static is a unit struct of the
+                    // same name, which lives in the type namespace too.
+                    if stc.lang != Lang::Rust && !type_names.insert(&stc.name.rust) {
+                        duplicate_name(cx, stc, ItemName::Type(&stc.name.rust));
+                    }
+                    visit(&mut all, &stc.ty, &stc.cfg);
+                }
+                Api::CxxIterator(iter) | Api::RustIterator(iter) => {
+                    visit(&mut all, &iter.item, &iter.cfg);
+                }
             }
         }
 
@@ -247,11 +300,13 @@ impl<'a> Types<'a> {
             rust,
             aliases,
             untrusted,
+            deleters,
             required_trivial,
             required_unpin,
             impls,
             resolutions,
             associated_fn,
+            template_params,
             struct_improper_ctypes,
             toposorted_structs,
         };
@@ -303,6 +358,7 @@ impl<'a> Types<'a> {
         let ty = ty.into();
         match ty {
             TypeQuery::RustBox
+            | TypeQuery::RustArc
             | TypeQuery::UniquePtr
             | TypeQuery::Ref(_)
             | TypeQuery::Ptr(_)
@@ -337,13 +393,27 @@ impl<'a> Types<'a> {
                     || self.enums.contains_key(ident)
                     || self.aliases.contains_key(ident)
             }
-            Type::CxxVector(_) => false,
+            Type::CxxVector(_)
+            | Type::CxxDeque(_)
+            | Type::CxxList(_)
+            | Type::CxxSet(_)
+            | Type::UniquePtr(_)
+            | Type::SharedPtr(_) => false,
             // No other type can appear as the inner type of CxxVector,
             // UniquePtr, or SharedPtr.
             _ => unreachable!("syntax/check.rs should reject other types"),
         }
     }
 
+    // The custom deleter type of std::unique_ptr<T, D> declared via
+    // #[cxx_deleter = "..."] on an extern C++ type, if any.
+    pub(crate) fn cxx_deleter(&self, ty: &Type) -> Option<&'a LitStr> {
+        match ty {
+            Type::Ident(named_type) => self.deleters.get(&named_type.rust).copied(),
+            _ => None,
+        }
+    }
+
     pub(crate) fn contains_elided_lifetime(&self, ty: &Type) -> bool {
         match ty {
             Type::Ident(ty) => {
@@ -352,11 +422,15 @@ impl<'a> Types<'a> {
                         != self.resolve(&ty.rust).generics.lifetimes.len()
             }
             Type::RustBox(ty)
+            | Type::RustArc(ty)
             | Type::RustVec(ty)
             | Type::UniquePtr(ty)
             | Type::SharedPtr(ty)
             | Type::WeakPtr(ty)
-            | Type::CxxVector(ty) => self.contains_elided_lifetime(&ty.inner),
+            | Type::CxxVector(ty)
+            | Type::CxxDeque(ty)
+            | Type::CxxList(ty)
+            | Type::CxxSet(ty) => self.contains_elided_lifetime(&ty.inner),
             Type::Ref(ty) => ty.lifetime.is_none() || self.contains_elided_lifetime(&ty.inner),
             Type::Ptr(ty) => self.contains_elided_lifetime(&ty.inner),
             Type::Str(ty) => ty.lifetime.is_none(),
@@ -381,8 +455,12 @@ impl<'a> Types<'a> {
             }
             Type::Array(_)
             | Type::CxxVector(_)
+            | Type::CxxDeque(_)
+            | Type::CxxList(_)
+            | Type::CxxSet(_)
             | Type::Fn(_)
             | Type::Void(_)
+            | Type::RustArc(_)
             | Type::RustVec(_)
             | Type::UniquePtr(_)
             | Type::SharedPtr(_)
@@ -424,6 +502,7 @@ impl<'a> From<&'a Impl> for ConditionalImpl<'a> {
 enum ItemName<'a> {
     Type(&'a Ident),
     Function(Option<&'a Ident>, &'a Ident),
+    Value(Option<&'a Ident>, &'a Ident),
 }
 
 fn duplicate_name(cx: &mut Errors, sp: impl ToTokens, name: ItemName) {
@@ -433,6 +512,10 @@ fn duplicate_name(cx: &mut Errors, sp: impl ToTokens, name: ItemName) {
             format!("associated function `{}::{}`", self_type, name)
         }
         ItemName::Function(None, name) => format!("function `{}`", name),
+        ItemName::Value(Some(self_type), name) => {
+            format!("associated const `{}::{}`", self_type, name)
+        }
+        ItemName::Value(None, name) => format!("value `{}`", name),
     };
     let msg = format!("the {} is defined multiple times", description);
     cx.error(sp, msg);
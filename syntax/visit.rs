@@ -13,10 +13,14 @@ where
     match ty {
         Type::Ident(_) | Type::Str(_) | Type::Void(_) => {}
         Type::RustBox(ty)
+        | Type::RustArc(ty)
         | Type::UniquePtr(ty)
         | Type::SharedPtr(ty)
         | Type::WeakPtr(ty)
         | Type::CxxVector(ty)
+        | Type::CxxDeque(ty)
+        | Type::CxxList(ty)
+        | Type::CxxSet(ty)
         | Type::RustVec(ty) => visitor.visit_type(&ty.inner),
         Type::Ref(r) => visitor.visit_type(&r.inner),
         Type::Ptr(p) => visitor.visit_type(&p.inner),
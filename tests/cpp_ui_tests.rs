@@ -26,3 +26,19 @@ fn test_unique_ptr_of_incomplete_foward_declared_pointee() {
     let err_msg = test.compile().expect_single_error();
     assert!(err_msg.contains("definition of `::ForwardDeclaredType` is required"));
 }
+
+/// The rust::Error builtin exposes a std::vector<std::string> through
+/// `chain()`, so it needs <vector> and <string> even in a bridge that uses
+/// neither type anywhere else.
+#[test]
+fn test_result_without_other_std_types() {
+    let test = cpp_compile::Test::new(quote! {
+        #[cxx::bridge]
+        mod ffi {
+            extern "Rust" {
+                fn f() -> Result<u8>;
+            }
+        }
+    });
+    test.compile().assert_success();
+}
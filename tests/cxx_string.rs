@@ -68,3 +68,51 @@ fn test_panic() {
         let_cxx_string!(s = None::<&[u8]>.unwrap());
     });
 }
+
+#[test]
+fn test_edit() {
+    let_cxx_string!(s = "Hello world");
+
+    s.as_mut().insert(5, ",");
+    assert_eq!(s.to_str(), Ok("Hello, world"));
+    s.as_mut().replace_range(7.., "C++");
+    assert_eq!(s.to_str(), Ok("Hello, C++"));
+    s.as_mut().replace_range(..5, b"Bye");
+    assert_eq!(s.to_str(), Ok("Bye, C++"));
+    s.as_mut().truncate(3);
+    assert_eq!(s.to_str(), Ok("Bye"));
+    s.as_mut().resize(5, b'!');
+    assert_eq!(s.to_str(), Ok("Bye!!"));
+    s.as_mut().push_utf16_lossy(&[0xD83E, 0xDD80, 0xD800]);
+    assert_eq!(s.to_str(), Ok("Bye!!\u{1f980}\u{fffd}"));
+
+    s.as_mut().reserve(100);
+    assert!(s.capacity() >= 100);
+    s.as_mut().shrink_to_fit();
+    assert!(s.capacity() >= s.len());
+}
+
+#[test]
+fn test_find() {
+    let_cxx_string!(s = "abcabc");
+
+    assert_eq!(s.find("bc"), Some(1));
+    assert_eq!(s.rfind("bc"), Some(4));
+    assert_eq!(s.find("cb"), None);
+    assert_eq!(s.rfind(""), Some(6));
+}
+
+#[test]
+#[should_panic = "range end index 4 out of range for CxxString of length 3"]
+fn test_replace_range_out_of_bounds() {
+    let_cxx_string!(s = "abc");
+
+    s.as_mut().replace_range(1..4, "");
+}
+
+#[test]
+fn test_new_unique() {
+    let s = CxxString::new_unique("2020");
+
+    assert_eq!(s.to_str(), Ok("2020"));
+}
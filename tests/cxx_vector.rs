@@ -5,3 +5,46 @@ fn test_cxx_vector_new() {
     let vector = CxxVector::<i32>::new();
     assert!(vector.is_empty());
 }
+
+#[test]
+#[should_panic = "CxxVector<bool> index out of bounds: the len is 2 but the index is 2"]
+fn test_cxx_vector_bool_set_out_of_bounds() {
+    let mut vector = CxxVector::<bool>::new();
+    vector.pin_mut().extend([true, false]);
+    assert!(vector.capacity() >= 2);
+    vector.pin_mut().set(2, true);
+}
+
+#[test]
+fn test_cxx_vector_mutation() {
+    let mut vector = CxxVector::<u8>::new();
+    vector.pin_mut().extend_from_slice(&[1, 2, 3, 4, 5]);
+    vector.pin_mut().insert(0, 0);
+    assert_eq!(vector.as_slice(), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(vector.pin_mut().remove(1), 1);
+    assert_eq!(vector.pin_mut().swap_remove(0), 0);
+    assert_eq!(vector.as_slice(), [5, 2, 3, 4]);
+    vector.pin_mut().retain(|&x| x % 2 == 0);
+    assert_eq!(vector.as_slice(), [2, 4]);
+    vector.pin_mut().resize(4, 0);
+    assert_eq!(vector.as_slice(), [2, 4, 0, 0]);
+    vector.pin_mut().truncate(1);
+    assert_eq!(vector.as_slice(), [2]);
+
+    let mut other = CxxVector::<u8>::new();
+    other.pin_mut().push(9);
+    vector.pin_mut().swap(other.pin_mut());
+    assert_eq!(vector.as_slice(), [9]);
+    assert_eq!(other.as_slice(), [2]);
+
+    vector.pin_mut().clear();
+    assert!(vector.is_empty());
+}
+
+#[test]
+#[should_panic = "insertion index (is 2) should be <= len (is 1)"]
+fn test_cxx_vector_insert_out_of_bounds() {
+    let mut vector = CxxVector::<i32>::new();
+    vector.pin_mut().push(0);
+    vector.pin_mut().insert(2, 1);
+}
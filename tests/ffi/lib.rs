@@ -22,6 +22,7 @@ use cxx::{CxxString, CxxVector, ExternType, SharedPtr, UniquePtr, type_id};
 use std::fmt::{self, Display};
 use std::mem::MaybeUninit;
 use std::os::raw::c_char;
+use std::sync::Arc;
 
 #[cxx::bridge(namespace = "tests")]
 pub mod ffi {
@@ -32,6 +33,10 @@ pub mod ffi {
         type Private;
         type Unmovable;
         type Array;
+        #[cxx_deleter = "::tests::PooledDeleter"]
+        type Pooled;
+        #[enable_shared_from_this]
+        type Node;
     }
 
     #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -109,12 +114,23 @@ pub mod ffi {
         s: &'a str,
     }
 
+    /// Capacity shared by both languages.
+    const MAX_ITEMS: usize = 64;
+    const NEGATIVE_OFFSET: i32 = -2020;
+    const SCALE: f64 = 0.5;
+    const FEATURE_ENABLED: bool = true;
+    #[Self = "Shared"]
+    const MAX_Z: usize = 2021;
+
     unsafe extern "C++" {
         type C;
 
+        static C_STATIC_NUMBER: i32;
+
         fn c_return_primitive() -> usize;
         fn c_return_shared() -> Shared;
         fn c_return_box() -> Box<R>;
+        fn c_return_arc(r: Arc<R>) -> Arc<R>;
         fn c_return_unique_ptr() -> UniquePtr<C>;
         fn c_return_shared_ptr() -> SharedPtr<C>;
         fn c_return_ref(shared: &Shared) -> &usize;
@@ -125,11 +141,28 @@ pub mod ffi {
         fn c_return_rust_string() -> String;
         fn c_return_rust_string_lossy() -> String;
         fn c_return_unique_ptr_string() -> UniquePtr<CxxString>;
+        fn c_return_unique_ptr_u16string() -> UniquePtr<CxxU16String>;
+        fn c_return_unique_ptr_u32string() -> UniquePtr<CxxU32String>;
         fn c_return_unique_ptr_vector_u8() -> UniquePtr<CxxVector<u8>>;
         fn c_return_unique_ptr_vector_f64() -> UniquePtr<CxxVector<f64>>;
         fn c_return_unique_ptr_vector_string() -> UniquePtr<CxxVector<CxxString>>;
         fn c_return_unique_ptr_vector_shared() -> UniquePtr<CxxVector<Shared>>;
+        fn c_return_unique_ptr_vector_bool() -> UniquePtr<CxxVector<bool>>;
+        fn c_return_unique_ptr_deque_shared() -> UniquePtr<CxxDeque<Shared>>;
+        fn c_return_unique_ptr_list_string() -> UniquePtr<CxxList<CxxString>>;
+        fn c_return_unique_ptr_set_i32() -> UniquePtr<CxxSet<i32>>;
         fn c_return_unique_ptr_vector_opaque() -> UniquePtr<CxxVector<C>>;
+        fn c_return_unique_ptr_vector_shared_ptr() -> UniquePtr<CxxVector<SharedPtr<C>>>;
+        fn c_return_unique_ptr_vector_unique_ptr_string()
+        -> UniquePtr<CxxVector<UniquePtr<CxxString>>>;
+        fn c_return_unique_ptr_vector_vector_f32() -> UniquePtr<CxxVector<CxxVector<f32>>>;
+        fn c_return_unique_ptr_vector_vector_shared() -> UniquePtr<CxxVector<CxxVector<Shared>>>;
+        fn c_return_unique_ptr_vector_u32string() -> UniquePtr<CxxVector<CxxU32String>>;
+        fn c_return_unique_ptr_pooled() -> UniquePtr<Pooled>;
+        fn c_pooled_drop_count() -> usize;
+        fn c_return_shared_ptr_node() -> SharedPtr<Node>;
+        fn c_return_unique_ptr_node() -> UniquePtr<Node>;
+        fn c_get_node_value(node: &Node) -> usize;
         fn c_return_ref_vector(c: &C) -> &CxxVector<u8>;
         fn c_return_mut_vector(c: Pin<&mut C>) -> Pin<&mut CxxVector<u8>>;
         fn c_return_rust_vec_u8() -> Vec<u8>;
@@ -146,25 +179,36 @@ pub mod ffi {
         fn c_return_nested_ns_enum(n: u16) -> ABEnum;
         fn c_return_const_ptr(n: usize) -> *const C;
         fn c_return_mut_ptr(n: usize) -> *mut C;
+        fn c_return_unique_ptr_array_u32(len: usize) -> *mut u32;
 
         fn c_take_primitive(n: usize);
         fn c_take_shared(shared: Shared);
         fn c_take_box(r: Box<R>);
+        fn c_take_arc(r: Arc<R>) -> usize;
+        fn c_take_unique_ptr_pooled(pooled: UniquePtr<Pooled>);
+        fn c_take_shared_ptr_pooled(pooled: SharedPtr<Pooled>);
         fn c_take_ref_r(r: &R);
         fn c_take_ref_c(c: &C);
         fn c_take_str(s: &str);
         fn c_take_slice_char(s: &[c_char]);
+        fn c_take_slice_u16(s: &[u16]);
+        fn c_take_slice_char16(#[cxx_char16] s: &[u16]);
         fn c_take_slice_shared(s: &[Shared]);
         fn c_take_slice_shared_sort(s: &mut [Shared]);
         fn c_take_slice_r(s: &[R]);
         fn c_take_slice_r_sort(s: &mut [R]);
         fn c_take_rust_string(s: String);
         fn c_take_unique_ptr_string(s: UniquePtr<CxxString>);
+        fn c_take_ref_u16string(s: &CxxU16String);
         fn c_take_unique_ptr_vector_u8(v: UniquePtr<CxxVector<u8>>);
         fn c_take_unique_ptr_vector_f64(v: UniquePtr<CxxVector<f64>>);
         fn c_take_unique_ptr_vector_string(v: UniquePtr<CxxVector<CxxString>>);
         fn c_take_unique_ptr_vector_shared(v: UniquePtr<CxxVector<Shared>>);
         fn c_take_ref_vector(v: &CxxVector<u8>);
+        fn c_take_ref_vector_bool(v: &CxxVector<bool>);
+        fn c_take_ref_vector_shared_ptr(v: &CxxVector<SharedPtr<C>>);
+        fn c_take_unique_ptr_deque_shared(d: UniquePtr<CxxDeque<Shared>>);
+        fn c_take_ref_set_string(s: &CxxSet<CxxString>);
         fn c_take_rust_vec(v: Vec<u8>);
         fn c_take_rust_vec_shared(v: Vec<Shared>);
         fn c_take_rust_vec_string(v: Vec<String>);
@@ -194,10 +238,13 @@ pub mod ffi {
         fn c_take_rust_vec_nested_ns_shared(v: Vec<ABShared>);
         unsafe fn c_take_const_ptr(c: *const C) -> usize;
         unsafe fn c_take_mut_ptr(c: *mut C) -> usize;
+        unsafe fn c_take_unique_ptr_array_f32(data: *mut f32, len: usize);
+        unsafe fn c_take_unique_ptr_array_shared(data: *mut Shared, len: usize);
 
         fn c_try_return_void() -> Result<()>;
         fn c_try_return_primitive() -> Result<usize>;
         fn c_fail_return_primitive() -> Result<usize>;
+        fn c_fail_nested_exception() -> Result<usize>;
         fn c_try_return_box() -> Result<Box<R>>;
         fn c_try_return_ref(s: &String) -> Result<&String>;
         fn c_try_return_str(s: &str) -> Result<&str>;
@@ -233,6 +280,14 @@ pub mod ffi {
         fn cOverloadedFunction(x: i32) -> String;
         #[rust_name = "str_overloaded_function"]
         fn cOverloadedFunction(x: &str) -> String;
+        #[cxx_name = "c_template_sum"]
+        #[cxx_template(i32)]
+        fn c_template_sum_i32(a: i32, b: i32) -> i32;
+        #[cxx_name = "c_template_sum"]
+        #[cxx_template(f64)]
+        fn c_template_sum_f64(a: f64, b: f64) -> f64;
+        #[cxx_template(C)]
+        fn c_template_new(n: usize) -> UniquePtr<C>;
 
         #[namespace = "other"]
         fn ns_c_take_ns_shared(shared: AShared);
@@ -287,12 +342,25 @@ pub mod ffi {
         type Buffer = crate::Buffer;
     }
 
+    unsafe extern "C++" {
+        type IntList;
+        type StringSet;
+
+        fn c_return_int_list() -> UniquePtr<IntList>;
+        fn c_return_string_set() -> UniquePtr<StringSet>;
+    }
+
     extern "Rust" {
         type R;
+        type RCountdown;
+        type RWords;
+
+        static R_STATIC_NUMBER: usize;
 
         fn r_return_primitive() -> usize;
         fn r_return_shared() -> Shared;
         fn r_return_box() -> Box<R>;
+        fn r_return_arc() -> Arc<R>;
         fn r_return_unique_ptr() -> UniquePtr<C>;
         fn r_return_shared_ptr() -> SharedPtr<C>;
         fn r_return_ref(shared: &Shared) -> &usize;
@@ -310,21 +378,27 @@ pub mod ffi {
         fn r_return_rust_vec_box() -> Vec<Box<R>>;
         #[allow(clippy::vec_box)]
         fn r_return_rust_vec_box_other_module_type() -> Vec<Box<OpaqueRust>>;
+        fn r_return_rust_vec_vec_f32() -> Vec<Vec<f32>>;
+        fn r_return_rust_vec_vec_shared() -> Vec<Vec<Shared>>;
+        fn r_return_rust_vec_shared_ptr() -> Vec<SharedPtr<C>>;
         fn r_return_ref_rust_vec(shared: &Shared) -> &Vec<u8>;
         fn r_return_mut_rust_vec(shared: &mut Shared) -> &mut Vec<u8>;
         fn r_return_identity(_: usize) -> usize;
         fn r_return_sum(_: usize, _: usize) -> usize;
+        fn r_return_sum_with_default(n1: usize, #[cxx_default = "1"] n2: usize) -> usize;
         fn r_return_enum(n: u32) -> Enum;
 
         fn r_take_primitive(n: usize);
         fn r_take_shared(shared: Shared);
         fn r_take_box(r: Box<R>);
+        fn r_take_arc(r: Arc<R>);
         fn r_take_unique_ptr(c: UniquePtr<C>);
         fn r_take_shared_ptr(c: SharedPtr<C>);
         fn r_take_ref_r(r: &R);
         fn r_take_ref_c(c: &C);
         fn r_take_str(s: &str);
         fn r_take_slice_char(s: &[c_char]);
+        fn r_take_slice_char16(#[cxx_char16] s: &[u16]);
         fn r_take_rust_string(s: String);
         fn r_take_unique_ptr_string(s: UniquePtr<CxxString>);
         fn r_take_ref_vector(v: &CxxVector<u8>);
@@ -333,14 +407,29 @@ pub mod ffi {
         fn r_take_rust_vec_string(v: Vec<String>);
         fn r_take_ref_rust_vec(v: &Vec<u8>);
         fn r_take_ref_rust_vec_string(v: &Vec<String>);
+        fn r_take_rust_vec_vec_string(v: Vec<Vec<String>>);
         fn r_take_enum(e: Enum);
 
         fn r_try_return_void() -> Result<()>;
         fn r_try_return_primitive() -> Result<usize>;
         fn r_try_return_box() -> Result<Box<R>>;
         fn r_fail_return_primitive() -> Result<usize>;
+        #[error_details]
+        fn r_fail_with_details() -> Result<usize>;
         fn r_try_return_sliceu8(s: &[u8]) -> Result<&[u8]>;
         fn r_try_return_mutsliceu8(s: &mut [u8]) -> Result<&mut [u8]>;
+        #[catch_unwind]
+        fn r_catch_panic(panic: bool) -> usize;
+        #[catch_unwind]
+        fn r_catch_panic_or_fail(panic: bool) -> Result<usize>;
+
+        /// Sums the elements of a slice.
+        #[instantiate(i32, f64)]
+        fn r_sum<T>(v: &[T]) -> T;
+        #[instantiate(u8, usize)]
+        fn r_default_value<T>() -> T;
+        fn r_countdown(n: usize) -> Box<RCountdown>;
+        fn r_words(s: &str) -> Box<RWords>;
 
         fn get(self: &R) -> usize;
         fn set(self: &mut R, n: usize) -> usize;
@@ -352,6 +441,15 @@ pub mod ffi {
         #[cxx_name = "rAliasedFunction"]
         fn r_aliased_function(x: i32) -> String;
 
+        #[cxx_name = "rOverloadedFunction"]
+        fn r_i32_overloaded_function(x: i32) -> String;
+        #[cxx_name = "rOverloadedFunction"]
+        fn r_str_overloaded_function(s: &str) -> String;
+        #[cxx_name = "rOverloadedMethod"]
+        fn r_i32_overloaded_method(self: &R, x: i32) -> String;
+        #[cxx_name = "rOverloadedMethod"]
+        fn r_str_overloaded_method(self: &R, s: &str) -> String;
+
         #[Self = "Shared"]
         fn r_static_method_on_shared() -> usize;
 
@@ -390,6 +488,24 @@ pub mod ffi {
     impl SharedPtr<Private> {}
     impl CxxVector<Unmovable> {}
     impl UniquePtr<Array> {}
+    impl CxxList<SharedString> {}
+    impl CxxSet<Enum> {}
+
+    impl IntoIterator for &IntList {
+        type Item = &i32;
+    }
+
+    impl IntoIterator for &StringSet {
+        type Item = &CxxString;
+    }
+
+    impl Iterator for RCountdown {
+        type Item = usize;
+    }
+
+    impl Iterator for RWords {
+        type Item = String;
+    }
 }
 
 #[rustfmt::skip]
@@ -473,10 +589,40 @@ impl R {
     fn r_static_method() -> usize {
         2024
     }
+
+    fn r_i32_overloaded_method(&self, x: i32) -> String {
+        (self.0 as i32 + x).to_string()
+    }
+
+    fn r_str_overloaded_method(&self, s: &str) -> String {
+        format!("{}{}", self.0, s)
+    }
 }
 
 pub struct Reference<'a>(pub &'a String);
 
+pub struct RCountdown(usize);
+
+impl Iterator for RCountdown {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.0.checked_sub(1)?;
+        self.0 = n;
+        Some(n)
+    }
+}
+
+pub struct RWords(std::vec::IntoIter<String>);
+
+impl Iterator for RWords {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
 impl ffi::Shared {
     fn r_method_on_shared(&self) -> String {
         "2020".to_owned()
@@ -520,6 +666,23 @@ impl Display for Error {
     }
 }
 
+#[derive(Debug)]
+struct ContextError(Error);
+
+impl std::error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("rust context")
+    }
+}
+
+static R_STATIC_NUMBER: usize = 2020;
+
 fn r_return_primitive() -> usize {
     2020
 }
@@ -532,6 +695,10 @@ fn r_return_box() -> Box<R> {
     Box::new(R(2020))
 }
 
+fn r_return_arc() -> Arc<R> {
+    Arc::new(R(2020))
+}
+
 fn r_return_unique_ptr() -> UniquePtr<ffi::C> {
     #[allow(missing_unsafe_on_extern)]
     unsafe extern "C" {
@@ -614,6 +781,19 @@ fn r_return_rust_vec_box_other_module_type() -> Vec<Box<module::OpaqueRust>> {
     vec![Box::new(module::OpaqueRust(2025))]
 }
 
+fn r_return_rust_vec_vec_f32() -> Vec<Vec<f32>> {
+    vec![vec![1.5, 2.5], Vec::new(), vec![3.5]]
+}
+
+fn r_return_rust_vec_vec_shared() -> Vec<Vec<ffi::Shared>> {
+    vec![Vec::new(), vec![ffi::Shared { z: 2021 }]]
+}
+
+fn r_return_rust_vec_shared_ptr() -> Vec<SharedPtr<ffi::C>> {
+    let ptr = ffi::c_return_shared_ptr();
+    vec![ptr.clone(), SharedPtr::null(), ptr]
+}
+
 fn r_return_ref_rust_vec(shared: &ffi::Shared) -> &Vec<u8> {
     let _ = shared;
     unimplemented!()
@@ -632,6 +812,10 @@ fn r_return_sum(n1: usize, n2: usize) -> usize {
     n1 + n2
 }
 
+fn r_return_sum_with_default(n1: usize, n2: usize) -> usize {
+    n1 + n2
+}
+
 fn r_return_enum(n: u32) -> ffi::Enum {
     if n == 0 {
         ffi::Enum::AVal
@@ -654,6 +838,10 @@ fn r_take_box(r: Box<R>) {
     let _ = r;
 }
 
+fn r_take_arc(r: Arc<R>) {
+    assert_eq!(r.0, 2020);
+}
+
 fn r_take_unique_ptr(c: UniquePtr<ffi::C>) {
     let _ = c;
 }
@@ -684,6 +872,10 @@ fn r_take_slice_char(s: &[c_char]) {
     assert_eq!(std::str::from_utf8(s).unwrap(), "2020\0");
 }
 
+fn r_take_slice_char16(s: &[u16]) {
+    assert_eq!(String::from_utf16(s).unwrap(), "crab \u{1f980}");
+}
+
 fn r_take_unique_ptr_string(s: UniquePtr<CxxString>) {
     assert_eq!(s.as_ref().unwrap().to_str().unwrap(), "2020");
 }
@@ -706,6 +898,10 @@ fn r_take_rust_vec_string(v: Vec<String>) {
     let _ = v;
 }
 
+fn r_take_rust_vec_vec_string(v: Vec<Vec<String>>) {
+    assert_eq!(v, [vec!["2020".to_owned()], Vec::new()]);
+}
+
 fn r_take_ref_rust_vec(v: &Vec<u8>) {
     let _ = v;
 }
@@ -739,6 +935,10 @@ fn r_fail_return_primitive() -> Result<usize, Error> {
     Err(Error)
 }
 
+fn r_fail_with_details() -> Result<usize, ContextError> {
+    Err(ContextError(Error))
+}
+
 fn r_try_return_sliceu8(slice: &[u8]) -> Result<&[u8], Error> {
     Ok(slice)
 }
@@ -747,6 +947,45 @@ fn r_try_return_mutsliceu8(slice: &mut [u8]) -> Result<&mut [u8], Error> {
     Ok(slice)
 }
 
+fn r_catch_panic(panic: bool) -> usize {
+    if panic {
+        panic!("rust panic");
+    }
+    2020
+}
+
+fn r_catch_panic_or_fail(panic: bool) -> Result<usize, Error> {
+    if panic {
+        panic!("rust panic {}", 2020);
+    }
+    Err(Error)
+}
+
+fn r_sum<T: Copy + std::iter::Sum<T>>(v: &[T]) -> T {
+    v.iter().copied().sum()
+}
+
+fn r_default_value<T: Default>() -> T {
+    T::default()
+}
+
+fn r_countdown(n: usize) -> Box<RCountdown> {
+    Box::new(RCountdown(n))
+}
+
+fn r_words(s: &str) -> Box<RWords> {
+    let words: Vec<String> = s.split_whitespace().map(str::to_owned).collect();
+    Box::new(RWords(words.into_iter()))
+}
+
 fn r_aliased_function(x: i32) -> String {
     x.to_string()
 }
+
+fn r_i32_overloaded_function(x: i32) -> String {
+    x.to_string()
+}
+
+fn r_str_overloaded_function(s: &str) -> String {
+    s.to_owned()
+}
@@ -12,8 +12,15 @@ pub mod ffi {
         include!("tests/ffi/tests.h");
 
         type C = crate::ffi::C;
+        #[cxx_deleter = "::tests::PooledDeleter"]
+        type Pooled = crate::ffi::Pooled;
+        type Node = crate::ffi::Node;
 
         fn c_take_unique_ptr(c: UniquePtr<C>);
+        #[cxx_name = "c_take_unique_ptr_pooled"]
+        fn c_take_unique_ptr_pooled_alias(pooled: UniquePtr<Pooled>);
+        #[cxx_name = "c_get_node_value"]
+        fn c_get_node_value_alias(node: &Node) -> usize;
         fn c_lifetime_elision_member_fn(self: &C) -> &CxxVector<u8>;
         fn c_lifetime_elision_fn(c: &C) -> &CxxVector<u8>;
     }
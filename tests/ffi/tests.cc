@@ -3,9 +3,13 @@
 #include <array>
 #include <cstdlib>
 #include <cstring>
+#include <exception>
 #include <iterator>
 #include <memory>
 #include <numeric>
+#if __cplusplus >= 202002L
+#include <ranges>
+#endif
 #ifdef __cpp_lib_span
 #include <span>
 #endif // __cpp_lib_span
@@ -13,6 +17,12 @@
 #include <string>
 #include <tuple>
 
+// Set by the CI jobs whose standard library is known to provide std::format,
+// so that the formatter tests below cannot be skipped without notice.
+#if defined(CXX_TEST_REQUIRE_FORMAT) && !defined(__cpp_lib_format)
+#error "CXX_TEST_REQUIRE_FORMAT is set but std::format is not available"
+#endif
+
 #ifdef __GNUC__
 #pragma GCC diagnostic ignored "-Wshadow"
 #endif
@@ -25,6 +35,11 @@ namespace tests {
 
 static_assert(4 == alignof(OveralignedStruct), "expected 4 byte alignment");
 
+#ifdef __cpp_lib_ranges
+static_assert(std::ranges::input_range<RWords>,
+              "expected Rust iterator to be an input range");
+#endif
+
 static constexpr char SLICE_DATA[] = "2020";
 
 C::C(size_t n) : n(n) {}
@@ -64,6 +79,8 @@ const std::vector<uint8_t> &C::get_v() const { return this->v; }
 
 std::vector<uint8_t> &C::get_v() { return this->v; }
 
+const int32_t C_STATIC_NUMBER = 2020;
+
 size_t c_return_primitive() { return 2020; }
 
 Shared c_return_shared() { return Shared{2020}; }
@@ -84,10 +101,26 @@ rust::Box<R> c_return_box() {
   return rust::Box<R>::from_raw(cxx_test_suite_get_box());
 }
 
+rust::Arc<R> c_return_arc(rust::Arc<R> r) {
+  rust::Arc<R> copy = r;              // copy constructor
+  rust::Arc<R> other = std::move(r);  // move constructor
+  other = copy;                       // copy assignment
+  copy = std::move(other);            // move assignment
+  return copy;
+}
+
 std::unique_ptr<C> c_return_unique_ptr() {
   return std::unique_ptr<C>(new C{2020});
 }
 
+std::unique_ptr<IntList> c_return_int_list() {
+  return std::unique_ptr<IntList>(new IntList{2020, 2021, 2022});
+}
+
+std::unique_ptr<StringSet> c_return_string_set() {
+  return std::unique_ptr<StringSet>(new StringSet{"c", "a", "b"});
+}
+
 std::shared_ptr<C> c_return_shared_ptr() {
   return std::shared_ptr<C>(new C{2020});
 }
@@ -132,6 +165,17 @@ std::unique_ptr<std::string> c_return_unique_ptr_string() {
   return std::unique_ptr<std::string>(new std::string("2020"));
 }
 
+std::unique_ptr<std::u16string> c_return_unique_ptr_u16string() {
+  return std::unique_ptr<std::u16string>(
+      new std::u16string(u"crab \U0001F980"));
+}
+
+std::unique_ptr<std::u32string> c_return_unique_ptr_u32string() {
+  auto s = std::unique_ptr<std::u32string>(new std::u32string(U"2020"));
+  s->push_back(0xD800);
+  return s;
+}
+
 std::unique_ptr<std::vector<uint8_t>> c_return_unique_ptr_vector_u8() {
   auto vec = std::unique_ptr<std::vector<uint8_t>>(new std::vector<uint8_t>());
   vec->push_back(86);
@@ -162,10 +206,93 @@ std::unique_ptr<std::vector<Shared>> c_return_unique_ptr_vector_shared() {
   return vec;
 }
 
+std::unique_ptr<std::vector<bool>> c_return_unique_ptr_vector_bool() {
+  return std::unique_ptr<std::vector<bool>>(
+      new std::vector<bool>{true, false, true});
+}
+
+std::unique_ptr<std::deque<Shared>> c_return_unique_ptr_deque_shared() {
+  auto deque = std::unique_ptr<std::deque<Shared>>(new std::deque<Shared>());
+  deque->push_back(Shared{1011});
+  deque->push_front(Shared{1010});
+  return deque;
+}
+
+std::unique_ptr<std::list<std::string>> c_return_unique_ptr_list_string() {
+  return std::unique_ptr<std::list<std::string>>(
+      new std::list<std::string>{"2020", "2021"});
+}
+
+std::unique_ptr<std::set<int32_t>> c_return_unique_ptr_set_i32() {
+  return std::unique_ptr<std::set<int32_t>>(new std::set<int32_t>{3, 1, 2});
+}
+
 std::unique_ptr<std::vector<C>> c_return_unique_ptr_vector_opaque() {
   return std::unique_ptr<std::vector<C>>(new std::vector<C>());
 }
 
+std::unique_ptr<std::vector<std::shared_ptr<C>>>
+c_return_unique_ptr_vector_shared_ptr() {
+  auto vec = std::unique_ptr<std::vector<std::shared_ptr<C>>>(
+      new std::vector<std::shared_ptr<C>>());
+  vec->push_back(std::shared_ptr<C>(new C{2020}));
+  vec->push_back(std::shared_ptr<C>());
+  vec->push_back(vec->front());
+  return vec;
+}
+
+std::unique_ptr<std::vector<std::unique_ptr<std::string>>>
+c_return_unique_ptr_vector_unique_ptr_string() {
+  auto vec = std::unique_ptr<std::vector<std::unique_ptr<std::string>>>(
+      new std::vector<std::unique_ptr<std::string>>());
+  vec->push_back(std::unique_ptr<std::string>(new std::string("2020")));
+  vec->push_back(std::unique_ptr<std::string>());
+  return vec;
+}
+
+std::unique_ptr<std::vector<std::vector<float>>>
+c_return_unique_ptr_vector_vector_f32() {
+  return std::unique_ptr<std::vector<std::vector<float>>>(
+      new std::vector<std::vector<float>>{{1.5f, 2.5f}, {}, {3.5f}});
+}
+
+std::unique_ptr<std::vector<std::vector<Shared>>>
+c_return_unique_ptr_vector_vector_shared() {
+  return std::unique_ptr<std::vector<std::vector<Shared>>>(
+      new std::vector<std::vector<Shared>>{{Shared{1010}}, {Shared{1011}}});
+}
+
+std::unique_ptr<std::vector<std::u32string>>
+c_return_unique_ptr_vector_u32string() {
+  return std::unique_ptr<std::vector<std::u32string>>(
+      new std::vector<std::u32string>{U"2020", U"\U0001F980"});
+}
+
+static size_t pooled_drop_count = 0;
+
+void PooledDeleter::operator()(Pooled *pooled) const noexcept {
+  pooled_drop_count++;
+  delete pooled;
+}
+
+std::unique_ptr<Pooled, PooledDeleter> c_return_unique_ptr_pooled() {
+  return std::unique_ptr<Pooled, PooledDeleter>(new Pooled{2020});
+}
+
+size_t c_pooled_drop_count() { return pooled_drop_count; }
+
+std::shared_ptr<Node> c_return_shared_ptr_node() {
+  auto node = std::make_shared<Node>();
+  node->value = 2020;
+  return node;
+}
+
+std::unique_ptr<Node> c_return_unique_ptr_node() {
+  return std::unique_ptr<Node>(new Node());
+}
+
+size_t c_get_node_value(const Node &node) { return node.value; }
+
 const std::vector<uint8_t> &c_return_ref_vector(const C &c) {
   return c.get_v();
 }
@@ -231,6 +358,14 @@ const C *c_return_const_ptr(size_t c) { return new C(c); }
 
 C *c_return_mut_ptr(size_t c) { return new C(c); }
 
+uint32_t *c_return_unique_ptr_array_u32(size_t len) {
+  std::unique_ptr<uint32_t[]> array(new uint32_t[len]);
+  for (size_t i = 0; i < len; i++) {
+    array[i] = 2020 + i;
+  }
+  return array.release();
+}
+
 Borrow::Borrow(const std::string &s) : s(s) {}
 
 void Borrow::const_member() const {}
@@ -271,12 +406,26 @@ void c_take_box(rust::Box<R> r) {
   }
 }
 
+size_t c_take_arc(rust::Arc<R> r) { return r->get(); }
+
 void c_take_unique_ptr(std::unique_ptr<C> c) {
   if (c->get() == 2020) {
     cxx_test_suite_set_correct();
   }
 }
 
+void c_take_unique_ptr_pooled(std::unique_ptr<Pooled, PooledDeleter> pooled) {
+  if (pooled->n == 2020) {
+    cxx_test_suite_set_correct();
+  }
+}
+
+void c_take_shared_ptr_pooled(std::shared_ptr<Pooled> pooled) {
+  if (pooled->n == 2020) {
+    cxx_test_suite_set_correct();
+  }
+}
+
 void c_take_ref_r(const R &r) {
   if (cxx_test_suite_r_is_correct(&r)) {
     cxx_test_suite_set_correct();
@@ -307,6 +456,26 @@ void c_take_slice_char(rust::Slice<const char> s) {
   }
 }
 
+void c_take_slice_u16(rust::Slice<const uint16_t> s) {
+  std::u16string copy(s.begin(), s.end());
+  if (copy == u"crab \U0001F980") {
+    cxx_test_suite_set_correct();
+  }
+}
+
+void c_take_slice_char16(rust::Slice<const char16_t> s) {
+#if __cplusplus >= 201703L
+  std::u16string_view view(s);
+  if (view == u"crab \U0001F980" && view.data() == s.data()) {
+    cxx_test_suite_set_correct();
+  }
+#else
+  if (std::u16string(s.begin(), s.end()) == u"crab \U0001F980") {
+    cxx_test_suite_set_correct();
+  }
+#endif
+}
+
 void c_take_slice_shared(rust::Slice<const Shared> s) {
   if (s.size() == 2 && s.data()->z == 2020 && s[1].z == 2021 &&
       s.at(1).z == 2021 && s.front().z == 2020 && s.back().z == 2021) {
@@ -355,6 +524,12 @@ void c_take_unique_ptr_string(std::unique_ptr<std::string> s) {
   }
 }
 
+void c_take_ref_u16string(const std::u16string &s) {
+  if (s == u"crab \U0001F980!") {
+    cxx_test_suite_set_correct();
+  }
+}
+
 void c_take_unique_ptr_vector_u8(std::unique_ptr<std::vector<uint8_t>> v) {
   if (v->size() == 3) {
     cxx_test_suite_set_correct();
@@ -385,6 +560,31 @@ void c_take_ref_vector(const std::vector<uint8_t> &v) {
   }
 }
 
+void c_take_ref_vector_bool(const std::vector<bool> &v) {
+  if (v == std::vector<bool>{false, false, true, true}) {
+    cxx_test_suite_set_correct();
+  }
+}
+
+void c_take_ref_vector_shared_ptr(const std::vector<std::shared_ptr<C>> &v) {
+  if (v.size() == 3 && v[0] == v[2] && v[1] == nullptr &&
+      v[0].use_count() == 2) {
+    cxx_test_suite_set_correct();
+  }
+}
+
+void c_take_unique_ptr_deque_shared(std::unique_ptr<std::deque<Shared>> d) {
+  if (d->size() == 3 && d->front().z == 1009 && d->back().z == 1011) {
+    cxx_test_suite_set_correct();
+  }
+}
+
+void c_take_ref_set_string(const std::set<std::string> &s) {
+  if (s.size() == 2 && s.count("x") == 1 && s.count("y") == 1) {
+    cxx_test_suite_set_correct();
+  }
+}
+
 void c_take_rust_vec(rust::Vec<uint8_t> v) { c_take_ref_rust_vec(v); }
 
 void c_take_rust_vec_index(rust::Vec<uint8_t> v) {
@@ -567,12 +767,38 @@ size_t c_take_mut_ptr(C *c) {
   return result;
 }
 
+void c_take_unique_ptr_array_f32(float *data, size_t len) {
+  std::unique_ptr<float[]> array(data);
+  float sum = 0;
+  for (size_t i = 0; i < len; i++) {
+    sum += array[i];
+  }
+  if (len == 4 && sum == 2020) {
+    cxx_test_suite_set_correct();
+  }
+}
+
+void c_take_unique_ptr_array_shared(Shared *data, size_t len) {
+  std::unique_ptr<Shared[]> array(data);
+  if (len == 2 && array[0].z == 0 && array[1].z == 2020) {
+    cxx_test_suite_set_correct();
+  }
+}
+
 void c_try_return_void() {}
 
 size_t c_try_return_primitive() { return 2020; }
 
 size_t c_fail_return_primitive() { throw std::logic_error("logic error"); }
 
+size_t c_fail_nested_exception() {
+  try {
+    throw std::out_of_range("inner");
+  } catch (...) {
+    std::throw_with_nested(std::runtime_error("outer"));
+  }
+}
+
 rust::Box<R> c_try_return_box() { return c_return_box(); }
 
 const rust::String &c_try_return_ref(const rust::String &s) { return s; }
@@ -796,9 +1022,18 @@ extern "C" const char *cxx_run_test() noexcept {
   ASSERT(rust::size_of<size_t>() == sizeof(size_t));
   ASSERT(rust::align_of<size_t>() == alignof(size_t));
 
+  ASSERT(R_STATIC_NUMBER() == 2020);
+  ASSERT(MAX_ITEMS == 64);
+  ASSERT(NEGATIVE_OFFSET == -2020);
+  ASSERT(SCALE == 0.5);
+  ASSERT(FEATURE_ENABLED);
+  static_assert(MAX_ITEMS == 64, "expected constexpr");
+  static_assert(Shared::MAX_Z == 2021, "expected static constexpr member");
+
   ASSERT(r_return_primitive() == 2020);
   ASSERT(r_return_shared().z == 2020);
   ASSERT(cxx_test_suite_r_is_correct(&*r_return_box()));
+  ASSERT(r_return_arc()->get() == 2020);
   ASSERT(r_return_unique_ptr()->get() == 2020);
   ASSERT(r_return_shared_ptr()->get() == 2020);
   ASSERT(r_return_ref(Shared{2020}) == 2020);
@@ -807,6 +1042,8 @@ extern "C" const char *cxx_run_test() noexcept {
   ASSERT(*r_return_unique_ptr_string() == "2020");
   ASSERT(r_return_identity(2020) == 2020);
   ASSERT(r_return_sum(2020, 1) == 2021);
+  ASSERT(r_return_sum_with_default(2020) == 2021);
+  ASSERT(r_return_sum_with_default(2020, 2) == 2022);
   ASSERT(r_return_enum(0) == Enum::AVal);
   ASSERT(r_return_enum(1) == Enum::BVal);
   ASSERT(r_return_enum(2021) == Enum::CVal);
@@ -815,14 +1052,33 @@ extern "C" const char *cxx_run_test() noexcept {
   ASSERT(r_return_rust_vec_box()[0]->get() == 2020);
   ASSERT(r_return_rust_vec_box_other_module_type().size() == 1);
 
+  auto matrix = r_return_rust_vec_vec_f32();
+  ASSERT(matrix.size() == 3);
+  ASSERT(matrix[0][1] == 2.5f && matrix[1].empty() && matrix[2][0] == 3.5f);
+  auto nested = r_return_rust_vec_vec_shared();
+  ASSERT(nested.size() == 2 && nested[0].empty() && nested[1][0].z == 2021);
+  auto handles = r_return_rust_vec_shared_ptr();
+  ASSERT(handles.size() == 3 && handles[0] == handles[2] && !handles[1]);
+  ASSERT(handles[0]->get() == 2020 && handles[0].use_count() == 2);
+
   r_take_primitive(2020);
   r_take_shared(Shared{2020});
   r_take_unique_ptr(std::unique_ptr<C>(new C{2020}));
   r_take_shared_ptr(std::shared_ptr<C>(new C{2020}));
+  r_take_arc(r_return_arc());
   r_take_ref_c(C{2020});
   r_take_str(rust::Str("2020"));
   r_take_slice_char(rust::Slice<const char>(SLICE_DATA, sizeof(SLICE_DATA)));
+  r_take_slice_char16(rust::Slice<const char16_t>(u"crab \U0001F980", 7));
+#if __cplusplus >= 201703L
+  r_take_slice_char16(
+      rust::Slice<const char16_t>(std::u16string_view(u"crab \U0001F980")));
+#endif
   r_take_rust_string(rust::String("2020"));
+  rust::Vec<rust::Vec<rust::String>> strings;
+  strings.push_back(rust::Vec<rust::String>{"2020"});
+  strings.emplace_back();
+  r_take_rust_vec_vec_string(std::move(strings));
   r_take_unique_ptr_string(
       std::unique_ptr<std::string>(new std::string("2020")));
   r_take_ref_vector(std::vector<uint8_t>{20, 2, 0});
@@ -838,7 +1094,65 @@ extern "C" const char *cxx_run_test() noexcept {
     ASSERT(false);
   } catch (const rust::Error &e) {
     ASSERT(std::strcmp(e.what(), "rust error") == 0);
+    ASSERT(std::strcmp(e.debug(), "") == 0);
+    ASSERT(e.chain().empty());
   }
+  try {
+    r_fail_with_details();
+    ASSERT(false);
+  } catch (const rust::Error &e) {
+    rust::Error copy = e;
+    ASSERT(std::strcmp(copy.what(), "rust context") == 0);
+    ASSERT(std::strcmp(copy.debug(), "ContextError(Error)") == 0);
+    ASSERT(copy.chain().size() == 2);
+    ASSERT(copy.chain()[0] == "rust context");
+    ASSERT(copy.chain()[1] == "rust error");
+  }
+
+  ASSERT(r_sum<std::int32_t>(rust::Slice<const std::int32_t>{}) == 0);
+  std::int32_t ints[] = {2000, 20, 1};
+  ASSERT(r_sum<std::int32_t>({ints, 3}) == 2021);
+  double doubles[] = {2020.0, 0.5};
+  ASSERT(r_sum<double>({doubles, 2}) == 2020.5);
+  ASSERT(r_default_value<std::uint8_t>() == 0);
+  ASSERT(r_default_value<std::size_t>() == 0);
+
+  ASSERT(r_catch_panic(false) == 2020);
+  try {
+    r_catch_panic(true);
+    ASSERT(false);
+  } catch (const rust::Panic &e) {
+    ASSERT(std::strcmp(e.what(), "rust panic") == 0);
+  }
+  try {
+    r_catch_panic_or_fail(false);
+    ASSERT(false);
+  } catch (const rust::Error &e) {
+    ASSERT(std::strcmp(e.what(), "rust error") == 0);
+  }
+  try {
+    r_catch_panic_or_fail(true);
+    ASSERT(false);
+  } catch (const rust::Panic &e) {
+    ASSERT(std::strcmp(e.what(), "rust panic 2020") == 0);
+  }
+
+  auto countdown = r_countdown(5);
+  std::size_t countdown_sum = 0;
+  for (std::size_t n : *countdown) {
+    countdown_sum += n;
+  }
+  ASSERT(countdown_sum == 10);
+  auto words = r_words("rust iterator from c++");
+  std::string joined;
+  for (auto &word : *words) {
+    joined += std::string(word);
+  }
+  ASSERT(joined == "rustiteratorfromc++");
+  ASSERT(words->begin() == words->end());
+  auto countdown2 = r_countdown(5);
+  ASSERT(std::accumulate(countdown2->begin(), countdown2->end(),
+                         std::size_t(0)) == 10);
 
   auto r = r_return_box();
   ASSERT(r->get() == 2020);
@@ -855,6 +1169,11 @@ extern "C" const char *cxx_run_test() noexcept {
 
   ASSERT(std::string(rAliasedFunction(2020)) == "2020");
 
+  ASSERT(std::string(rOverloadedFunction(2020)) == "2020");
+  ASSERT(std::string(rOverloadedFunction(rust::Str("2020"))) == "2020");
+  ASSERT(std::string(r->rOverloadedMethod(1)) == "2021");
+  ASSERT(std::string(r->rOverloadedMethod(rust::Str("!"))) == "2020!");
+
   ASSERT(Shared{1} == Shared{1});
   ASSERT(Shared{1} != Shared{2});
 
@@ -924,6 +1243,19 @@ extern "C" const char *cxx_run_test() noexcept {
   rust::String bad_utf16_rstring = rust::String::lossy(bad_utf16_literal);
   ASSERT(bad_utf8_rstring == bad_utf16_rstring);
 
+  ASSERT(utf8_rstring.to_utf16() == utf16_literal);
+  ASSERT(rust::Str("crab \xF0\x9F\xA6\x80").to_utf16() == u"crab \U0001F980");
+
+#if __cplusplus >= 201703L
+  {
+    std::u16string_view utf16_view = u"crab \U0001F980";
+    rust::Slice<const char16_t> utf16_chars(utf16_view);
+    ASSERT(utf16_chars.size() == 7 && utf16_chars[5] == 0xD83E);
+    ASSERT(std::u16string_view(utf16_chars) == utf16_view);
+    ASSERT(std::u16string_view(utf16_chars).data() == utf16_view.data());
+  }
+#endif
+
   // Test Slice<T> explicit constructor from container
   {
     std::vector<int> cpp_vec{1, 2, 3};
@@ -996,6 +1328,16 @@ extern "C" const char *cxx_run_test() noexcept {
   ASSERT(vec1[0] == 3 && vec1[1] == 4);
   ASSERT(vec2[0] == 1 && vec2[1] == 2);
 
+#ifdef __cpp_lib_format
+  ASSERT(std::format("{}", rust::String("2020")) == "2020");
+  ASSERT(std::format("{:>6}", rust::Str("2020")) == "  2020");
+  ASSERT(std::format("{}", vec1) == "[3, 4]");
+  ASSERT(std::format("{:02}", rust::Slice<const int>(vec2.data(), 2)) ==
+         "[01, 02]");
+  ASSERT(std::format("{}", Shared{2020}) == "Shared { z: 2020 }");
+  ASSERT(std::format("{}", Enum::AVal) == "AVal");
+#endif // __cpp_lib_format
+
   // Test Vec<usize> and Vec<isize>. These are weird because on Linux and
   // Windows size_t is exactly the same C++ type as one of the sized integer
   // types (typically uint64_t, both of which are defined as unsigned long),
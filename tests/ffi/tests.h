@@ -1,6 +1,9 @@
 #pragma once
 #include "rust/cxx.h"
+#include <deque>
+#include <list>
 #include <memory>
+#include <set>
 #include <string>
 
 namespace A {
@@ -47,6 +50,18 @@ struct Unmovable {
 
 using Array = int[];
 
+struct Pooled {
+  size_t n;
+};
+
+struct PooledDeleter {
+  void operator()(Pooled *pooled) const noexcept;
+};
+
+struct Node : std::enable_shared_from_this<Node> {
+  size_t value;
+};
+
 struct R;
 struct Shared;
 struct SharedString;
@@ -105,11 +120,14 @@ struct Borrow {
 
 typedef char Buffer[12];
 
+extern const int32_t C_STATIC_NUMBER;
+
 size_t c_return_primitive();
 Shared c_return_shared();
 ::A::AShared c_return_ns_shared();
 ::A::B::ABShared c_return_nested_ns_shared();
 rust::Box<R> c_return_box();
+rust::Arc<R> c_return_arc(rust::Arc<R> r);
 std::unique_ptr<C> c_return_unique_ptr();
 std::shared_ptr<C> c_return_shared_ptr();
 std::unique_ptr<::H::H> c_return_ns_unique_ptr();
@@ -123,11 +141,32 @@ rust::Slice<uint8_t> c_return_mutsliceu8(rust::Slice<uint8_t> slice);
 rust::String c_return_rust_string();
 rust::String c_return_rust_string_lossy();
 std::unique_ptr<std::string> c_return_unique_ptr_string();
+std::unique_ptr<std::u16string> c_return_unique_ptr_u16string();
+std::unique_ptr<std::u32string> c_return_unique_ptr_u32string();
 std::unique_ptr<std::vector<uint8_t>> c_return_unique_ptr_vector_u8();
 std::unique_ptr<std::vector<double>> c_return_unique_ptr_vector_f64();
 std::unique_ptr<std::vector<std::string>> c_return_unique_ptr_vector_string();
 std::unique_ptr<std::vector<Shared>> c_return_unique_ptr_vector_shared();
+std::unique_ptr<std::vector<bool>> c_return_unique_ptr_vector_bool();
+std::unique_ptr<std::deque<Shared>> c_return_unique_ptr_deque_shared();
+std::unique_ptr<std::list<std::string>> c_return_unique_ptr_list_string();
+std::unique_ptr<std::set<int32_t>> c_return_unique_ptr_set_i32();
 std::unique_ptr<std::vector<C>> c_return_unique_ptr_vector_opaque();
+std::unique_ptr<std::vector<std::shared_ptr<C>>>
+c_return_unique_ptr_vector_shared_ptr();
+std::unique_ptr<std::vector<std::unique_ptr<std::string>>>
+c_return_unique_ptr_vector_unique_ptr_string();
+std::unique_ptr<std::vector<std::vector<float>>>
+c_return_unique_ptr_vector_vector_f32();
+std::unique_ptr<std::vector<std::vector<Shared>>>
+c_return_unique_ptr_vector_vector_shared();
+std::unique_ptr<std::vector<std::u32string>>
+c_return_unique_ptr_vector_u32string();
+std::unique_ptr<Pooled, PooledDeleter> c_return_unique_ptr_pooled();
+std::shared_ptr<Node> c_return_shared_ptr_node();
+std::unique_ptr<Node> c_return_unique_ptr_node();
+size_t c_get_node_value(const Node &node);
+size_t c_pooled_drop_count();
 const std::vector<uint8_t> &c_return_ref_vector(const C &c);
 std::vector<uint8_t> &c_return_mut_vector(C &c);
 rust::Vec<uint8_t> c_return_rust_vec_u8();
@@ -143,30 +182,41 @@ Enum c_return_enum(uint16_t n);
 std::unique_ptr<Borrow> c_return_borrow(const std::string &s);
 const C *c_return_const_ptr(size_t n);
 C *c_return_mut_ptr(size_t n);
+uint32_t *c_return_unique_ptr_array_u32(size_t len);
 
 void c_take_primitive(size_t n);
 void c_take_shared(Shared shared);
 void c_take_ns_shared(::A::AShared shared);
 void c_take_nested_ns_shared(::A::B::ABShared shared);
 void c_take_box(rust::Box<R> r);
+size_t c_take_arc(rust::Arc<R> r);
 void c_take_unique_ptr(std::unique_ptr<C> c);
+void c_take_unique_ptr_pooled(std::unique_ptr<Pooled, PooledDeleter> pooled);
+void c_take_shared_ptr_pooled(std::shared_ptr<Pooled> pooled);
 void c_take_ref_r(const R &r);
 void c_take_ref_c(const C &c);
 void c_take_ref_ns_c(const ::H::H &h);
 void c_take_str(rust::Str s);
 void c_take_slice_char(rust::Slice<const char> s);
+void c_take_slice_u16(rust::Slice<const uint16_t> s);
+void c_take_slice_char16(rust::Slice<const char16_t> s);
 void c_take_slice_shared(rust::Slice<const Shared> s);
 void c_take_slice_shared_sort(rust::Slice<Shared> s);
 void c_take_slice_r(rust::Slice<const R> s);
 void c_take_slice_r_sort(rust::Slice<R> s);
 void c_take_rust_string(rust::String s);
 void c_take_unique_ptr_string(std::unique_ptr<std::string> s);
+void c_take_ref_u16string(const std::u16string &s);
 void c_take_unique_ptr_vector_u8(std::unique_ptr<std::vector<uint8_t>> v);
 void c_take_unique_ptr_vector_f64(std::unique_ptr<std::vector<double>> v);
 void c_take_unique_ptr_vector_string(
     std::unique_ptr<std::vector<std::string>> v);
 void c_take_unique_ptr_vector_shared(std::unique_ptr<std::vector<Shared>> v);
 void c_take_ref_vector(const std::vector<uint8_t> &v);
+void c_take_ref_vector_bool(const std::vector<bool> &v);
+void c_take_ref_vector_shared_ptr(const std::vector<std::shared_ptr<C>> &v);
+void c_take_unique_ptr_deque_shared(std::unique_ptr<std::deque<Shared>> d);
+void c_take_ref_set_string(const std::set<std::string> &s);
 void c_take_rust_vec(rust::Vec<uint8_t> v);
 void c_take_rust_vec_index(rust::Vec<uint8_t> v);
 void c_take_rust_vec_shared(rust::Vec<Shared> v);
@@ -192,10 +242,13 @@ void c_take_ns_enum(::A::AEnum e);
 void c_take_nested_ns_enum(::A::B::ABEnum e);
 size_t c_take_const_ptr(const C *c);
 size_t c_take_mut_ptr(C *c);
+void c_take_unique_ptr_array_f32(float *data, size_t len);
+void c_take_unique_ptr_array_shared(Shared *data, size_t len);
 
 void c_try_return_void();
 size_t c_try_return_primitive();
 size_t c_fail_return_primitive();
+size_t c_fail_nested_exception();
 rust::Box<R> c_try_return_box();
 const rust::String &c_try_return_ref(const rust::String &);
 rust::Str c_try_return_str(rust::Str);
@@ -235,6 +288,21 @@ const std::vector<uint8_t> &c_lifetime_elision_fn(const C &c);
 rust::String cOverloadedFunction(int32_t x);
 rust::String cOverloadedFunction(rust::Str x);
 
+template <typename T>
+T c_template_sum(T a, T b) {
+  return a + b;
+}
+
+template <typename T>
+std::unique_ptr<T> c_template_new(size_t n) {
+  return std::unique_ptr<T>(new T(n));
+}
+
+using IntList = std::list<int32_t>;
+using StringSet = std::set<std::string>;
+std::unique_ptr<IntList> c_return_int_list();
+std::unique_ptr<StringSet> c_return_string_set();
+
 } // namespace tests
 
 namespace other {
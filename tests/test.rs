@@ -7,13 +7,18 @@
     clippy::unit_cmp
 )]
 
-use cxx::{CxxVector, SharedPtr, UniquePtr};
+use cxx::{
+    CxxDeque, CxxList, CxxSet, CxxString, CxxU16String, CxxVector, SharedPtr, UniquePtr,
+    let_cxx_string,
+};
 use cxx_test_suite::module::ffi2;
 use cxx_test_suite::{R, cast, ffi};
 use std::cell::Cell;
+use std::error::Error as _;
 use std::ffi::CStr;
 use std::panic::{self, RefUnwindSafe, UnwindSafe};
 use std::ptr;
+use std::sync::Arc;
 
 thread_local! {
     static CORRECT: Cell<bool> = const { Cell::new(false) };
@@ -100,6 +105,16 @@ fn test_c_return() {
     }
 }
 
+#[test]
+fn test_const_and_static() {
+    assert_eq!(64, ffi::MAX_ITEMS);
+    assert_eq!(-2020, ffi::NEGATIVE_OFFSET);
+    assert_eq!(0.5, ffi::SCALE);
+    assert!(ffi::FEATURE_ENABLED);
+    assert_eq!(2021, ffi::Shared::MAX_Z);
+    assert_eq!(2020, *ffi::C_STATIC_NUMBER);
+}
+
 #[test]
 fn test_c_try_return() {
     assert_eq!((), ffi::c_try_return_void().unwrap());
@@ -127,11 +142,15 @@ fn test_c_take() {
     check!(ffi::ns_c_take_ns_shared(ffi::AShared { z: 2020 }));
     check!(ffi::c_take_nested_ns_shared(ffi::ABShared { z: 2020 }));
     check!(ffi::c_take_box(Box::new(R(2020))));
+    assert_eq!(2020, ffi::c_take_arc(Arc::new(R(2020))));
     check!(ffi::c_take_ref_c(&unique_ptr));
     check!(ffi2::c_take_ref_ns_c(&unique_ptr_ns));
     check!(cxx_test_suite::module::ffi::c_take_unique_ptr(unique_ptr));
     check!(ffi::c_take_str("2020"));
     check!(ffi::c_take_slice_char(cast::unsigned_to_c_char(b"2020")));
+    let utf16: Vec<u16> = "crab \u{1f980}".encode_utf16().collect();
+    check!(ffi::c_take_slice_u16(&utf16));
+    check!(ffi::c_take_slice_char16(&utf16));
     check!(ffi::c_take_slice_shared(&[
         ffi::Shared { z: 2020 },
         ffi::Shared { z: 2021 },
@@ -157,6 +176,7 @@ fn test_c_take() {
     check!(ffi::c_take_unique_ptr_string(
         ffi::c_return_unique_ptr_string()
     ));
+    check!(ffi::c_take_unique_ptr_string(CxxString::new_unique("2020")));
     let mut vector = ffi::c_return_unique_ptr_vector_u8();
     assert_eq!(vector.pin_mut().pop(), Some(9));
     check!(ffi::c_take_unique_ptr_vector_u8(vector));
@@ -237,6 +257,23 @@ fn test_c_callback() {
     check!(ffi::c_take_callback_mut(callback_mut));
 }
 
+#[test]
+fn test_c_exception_source() {
+    let outer = ffi::c_fail_nested_exception().unwrap_err();
+    assert_eq!("outer", outer.what());
+    assert!(outer.type_name().unwrap().contains("runtime_error"));
+
+    let inner = outer.source().unwrap();
+    assert_eq!("inner", inner.to_string());
+    let inner = inner.downcast_ref::<cxx::Exception>().unwrap();
+    assert!(inner.type_name().unwrap().contains("out_of_range"));
+    assert!(inner.source().is_none());
+
+    let plain = ffi::c_fail_return_primitive().unwrap_err();
+    assert!(plain.type_name().unwrap().contains("logic_error"));
+    assert!(plain.source().is_none());
+}
+
 #[test]
 fn test_c_call_r() {
     fn cxx_run_test() {
@@ -298,6 +335,82 @@ fn test_shared_ptr_weak_ptr() {
     assert!(weak_ptr.upgrade().is_null());
 }
 
+#[test]
+fn test_arc() {
+    let r = Arc::new(R(2020));
+    assert_eq!(2020, ffi::c_take_arc(Arc::clone(&r)));
+    assert_eq!(1, Arc::strong_count(&r));
+
+    let r2 = ffi::c_return_arc(Arc::clone(&r));
+    assert!(Arc::ptr_eq(&r, &r2));
+    assert_eq!(2, Arc::strong_count(&r));
+    drop(r2);
+    assert_eq!(1, Arc::strong_count(&r));
+}
+
+#[test]
+fn test_shared_ptr_use_count() {
+    let shared_ptr = ffi::c_return_shared_ptr();
+    assert_eq!(1, shared_ptr.use_count());
+    let clone = shared_ptr.clone();
+    assert_eq!(2, shared_ptr.use_count());
+    assert!(SharedPtr::ptr_eq(&shared_ptr, &clone));
+    assert!(!SharedPtr::ptr_eq(&shared_ptr, &ffi::c_return_shared_ptr()));
+
+    let weak_ptr = SharedPtr::downgrade(&shared_ptr);
+    assert_eq!(2, weak_ptr.use_count());
+    assert!(!weak_ptr.expired());
+    drop(clone);
+    drop(shared_ptr);
+    assert_eq!(0, weak_ptr.use_count());
+    assert!(weak_ptr.expired());
+
+    assert_eq!(0, SharedPtr::<ffi::C>::null().use_count());
+}
+
+#[test]
+fn test_shared_from_this() {
+    let node = ffi::c_return_shared_ptr_node();
+    let shared = node.as_ref().unwrap().shared_from_this();
+    assert!(SharedPtr::ptr_eq(&node, &shared));
+    assert_eq!(2, node.use_count());
+    assert_eq!(2020, ffi::c_get_node_value(&shared));
+
+    let through_alias = node.shared_from_this();
+    assert_eq!(
+        2020,
+        cxx_test_suite::module::ffi::c_get_node_value_alias(&through_alias),
+    );
+    drop(through_alias);
+
+    let weak = shared.weak_from_this();
+    drop(node);
+    drop(shared);
+    assert!(weak.expired());
+
+    let unowned = ffi::c_return_unique_ptr_node();
+    assert!(unowned.shared_from_this().is_null());
+    assert!(unowned.weak_from_this().expired());
+}
+
+#[test]
+fn test_shared_ptr_alias() {
+    static VALUE: usize = 2020;
+
+    let shared_ptr = ffi::c_return_shared_ptr();
+    let weak_ptr = SharedPtr::downgrade(&shared_ptr);
+    let alias = shared_ptr.project(|_| &VALUE);
+    assert_eq!(2, shared_ptr.use_count());
+    assert_eq!(2, alias.use_count());
+    assert_eq!(ptr::addr_of!(VALUE), ptr::addr_of!(*alias));
+
+    drop(shared_ptr);
+    assert!(!weak_ptr.expired());
+    assert_eq!(2020, *alias);
+    drop(alias);
+    assert!(weak_ptr.expired());
+}
+
 #[test]
 fn test_unique_to_shared_ptr_string() {
     let unique = ffi::c_return_unique_ptr_string();
@@ -315,6 +428,47 @@ fn test_unique_to_shared_ptr_cpp_type() {
     assert_eq!(ptr::addr_of!(*shared), ptr);
 }
 
+#[test]
+fn test_unique_ptr_custom_deleter() {
+    let drop_count = ffi::c_pooled_drop_count();
+
+    drop(ffi::c_return_unique_ptr_pooled());
+    assert_eq!(ffi::c_pooled_drop_count(), drop_count + 1);
+
+    check!(ffi::c_take_unique_ptr_pooled(
+        ffi::c_return_unique_ptr_pooled()
+    ));
+    assert_eq!(ffi::c_pooled_drop_count(), drop_count + 2);
+
+    let shared = SharedPtr::from(ffi::c_return_unique_ptr_pooled());
+    check!(ffi::c_take_shared_ptr_pooled(shared.clone()));
+    assert_eq!(ffi::c_pooled_drop_count(), drop_count + 2);
+    drop(shared);
+    assert_eq!(ffi::c_pooled_drop_count(), drop_count + 3);
+
+    check!(cxx_test_suite::module::ffi::c_take_unique_ptr_pooled_alias(
+        ffi::c_return_unique_ptr_pooled()
+    ));
+    assert_eq!(ffi::c_pooled_drop_count(), drop_count + 4);
+}
+
+#[test]
+fn test_unique_ptr_array() {
+    let data = ffi::c_return_unique_ptr_array_u32(3);
+    let array = unsafe { UniquePtr::<[u32]>::from_raw_parts(data, 3) };
+    assert_eq!(*array, [2020, 2021, 2022]);
+
+    let mut array = UniquePtr::<[f32]>::new_slice(4);
+    array[3] = 2020.0;
+    let (data, len) = array.into_raw_parts();
+    check!(unsafe { ffi::c_take_unique_ptr_array_f32(data, len) });
+
+    let mut array = UniquePtr::<[ffi::Shared]>::new_slice(2);
+    array[1].z = 2020;
+    let (data, len) = array.into_raw_parts();
+    check!(unsafe { ffi::c_take_unique_ptr_array_shared(data, len) });
+}
+
 #[test]
 fn test_unique_to_shared_ptr_null() {
     let unique = UniquePtr::<ffi::C>::null();
@@ -348,6 +502,30 @@ fn test_vector_reserve_unmovable() {
     vector.pin_mut().reserve(10);
 }
 
+#[test]
+fn test_vector_truncate_unmovable() {
+    let mut vector = CxxVector::<ffi::Unmovable>::new();
+    vector.pin_mut().truncate(0);
+    vector
+        .pin_mut()
+        .swap(CxxVector::<ffi::Unmovable>::new().pin_mut());
+    assert!(vector.is_empty());
+}
+
+#[test]
+fn test_vector_mutation() {
+    let mut vector = ffi::c_return_unique_ptr_vector_shared();
+    vector.pin_mut().insert(1, ffi::Shared { z: 2020 });
+    vector.pin_mut().resize(4, ffi::Shared { z: 0 });
+    let zs: Vec<usize> = vector.iter().map(|shared| shared.z).collect();
+    assert_eq!(zs, [1010, 2020, 1011, 0]);
+    vector.pin_mut().retain(|shared| shared.z > 1010);
+    assert_eq!(vector.pin_mut().remove(0).z, 2020);
+    assert_eq!(vector.len(), 1);
+    vector.pin_mut().clear();
+    assert!(vector.is_empty());
+}
+
 #[test]
 fn test_c_ns_method_calls() {
     let unique_ptr = ffi2::ns_c_return_unique_ptr_ns();
@@ -399,6 +577,167 @@ fn test_rust_name_attribute() {
     assert_eq!("2020", unique_ptr.str_overloaded_method("2020"));
 }
 
+#[test]
+fn test_cxx_template_attribute() {
+    assert_eq!(2021, ffi::c_template_sum_i32(2020, 1));
+    assert_eq!(2020.5, ffi::c_template_sum_f64(2020.0, 0.5));
+    assert_eq!(2020, ffi::c_template_new(2020).get());
+}
+
+#[test]
+fn test_cxx_iterator() {
+    let list = ffi::c_return_int_list();
+    let mut items = Vec::new();
+    for n in &*list {
+        items.push(*n);
+    }
+    assert_eq!(items, [2020, 2021, 2022]);
+    assert_eq!(6063, list.into_iter().sum::<i32>());
+
+    let set = ffi::c_return_string_set();
+    let mut iter = set.into_iter();
+    assert_eq!(iter.next().unwrap(), "a");
+    assert_eq!(iter.next().unwrap(), "b");
+    assert_eq!(iter.next().unwrap(), "c");
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_cxx_vector_bool() {
+    let mut vector = ffi::c_return_unique_ptr_vector_bool();
+    assert_eq!(vector.len(), 3);
+    assert_eq!(vector.get(0), Some(true));
+    assert_eq!(vector.get(1), Some(false));
+    assert_eq!(vector.get(3), None);
+    vector.pin_mut().set(0, false);
+    vector.pin_mut().push(true);
+    assert_eq!(format!("{:?}", vector), "[false, false, true, true]");
+    check!(ffi::c_take_ref_vector_bool(&vector));
+    assert_eq!(vector.pin_mut().pop(), Some(true));
+    assert_eq!(vector.iter().filter(|&b| b).count(), 1);
+}
+
+#[test]
+fn test_cxx_vector_nested() {
+    let vector = ffi::c_return_unique_ptr_vector_shared_ptr();
+    assert_eq!(vector.len(), 3);
+    assert_eq!(vector.get(0).unwrap().get(), 2020);
+    assert!(vector.get(1).unwrap().is_null());
+    check!(ffi::c_take_ref_vector_shared_ptr(&vector));
+
+    let vector = ffi::c_return_unique_ptr_vector_unique_ptr_string();
+    assert_eq!(vector.get(0).unwrap().as_ref().unwrap(), "2020");
+    assert!(vector.get(1).unwrap().is_null());
+
+    let vector = ffi::c_return_unique_ptr_vector_vector_f32();
+    assert_eq!(vector.len(), 3);
+    assert_eq!(vector.get(0).unwrap().as_slice(), [1.5, 2.5]);
+    assert!(vector.get(1).unwrap().is_empty());
+    assert_eq!(format!("{:?}", vector), "[[1.5, 2.5], [], [3.5]]");
+
+    let vector = ffi::c_return_unique_ptr_vector_vector_shared();
+    let items: Vec<usize> = vector.iter().flatten().map(|shared| shared.z).collect();
+    assert_eq!(items, [1010, 1011]);
+}
+
+#[test]
+fn test_cxx_unicode_string() {
+    let string = ffi::c_return_unique_ptr_u16string();
+    assert_eq!(string.len(), 7);
+    assert_eq!(*string, *"crab \u{1f980}");
+    assert_eq!(string.try_to_string().unwrap(), "crab \u{1f980}");
+
+    let string = ffi::c_return_unique_ptr_u32string();
+    assert_eq!(string.as_slice(), [50, 48, 50, 48, 0xD800]);
+    assert!(string.try_to_string().is_err());
+    assert_eq!(string.to_string_lossy(), "2020\u{fffd}");
+    assert_eq!(format!("{:?}", string), "\"2020\\u{d800}\"");
+
+    let vector = ffi::c_return_unique_ptr_vector_u32string();
+    let strings: Vec<String> = vector.iter().map(ToString::to_string).collect();
+    assert_eq!(strings, ["2020", "\u{1f980}"]);
+
+    let mut string = CxxU16String::new();
+    string.pin_mut().push_str("crab ");
+    string.pin_mut().push('\u{1f980}');
+    string.pin_mut().push_slice(&[u16::from(b'!')]);
+    check!(ffi::c_take_ref_u16string(&string));
+}
+
+#[test]
+fn test_cxx_containers() {
+    let mut deque = ffi::c_return_unique_ptr_deque_shared();
+    assert_eq!(deque.len(), 2);
+    assert_eq!(deque.get(0).unwrap().z, 1010);
+    assert_eq!(deque.get(1).unwrap().z, 1011);
+    assert!(deque.get(2).is_none());
+    deque.pin_mut().push_front(ffi::Shared { z: 1009 });
+    deque.pin_mut().push_back(ffi::Shared { z: 1012 });
+    deque.pin_mut().push_back(ffi::Shared { z: 1013 });
+    assert_eq!(deque.pin_mut().pop_back().unwrap().z, 1013);
+    let items: Vec<usize> = deque.iter().map(|shared| shared.z).collect();
+    assert_eq!(items, [1009, 1010, 1011, 1012]);
+    assert_eq!(deque.pin_mut().pop_front().unwrap().z, 1009);
+    deque.pin_mut().push_front(ffi::Shared { z: 1009 });
+    deque.pin_mut().index_mut(1).unwrap().z = 0;
+    assert_eq!(deque.get(1).unwrap().z, 0);
+    deque.pin_mut().pop_back();
+    deque.pin_mut().insert(1, ffi::Shared { z: 2020 });
+    assert_eq!(deque.get(1).unwrap().z, 2020);
+    assert_eq!(deque.pin_mut().remove(1).unwrap().z, 2020);
+    assert!(deque.pin_mut().remove(3).is_none());
+    check!(ffi::c_take_unique_ptr_deque_shared(deque));
+
+    let list = ffi::c_return_unique_ptr_list_string();
+    assert_eq!(list.len(), 2);
+    let items: Vec<&str> = list.iter().map(|s| s.to_str().unwrap()).collect();
+    assert_eq!(items, ["2020", "2021"]);
+
+    let mut set = ffi::c_return_unique_ptr_set_i32();
+    assert_eq!(format!("{:?}", set), "{1, 2, 3}");
+    assert!(set.contains(&2));
+    assert!(!set.pin_mut().insert(&2));
+    assert!(set.pin_mut().insert(&0));
+    assert!(set.pin_mut().remove(&3));
+    assert!(!set.pin_mut().remove(&3));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+    set.pin_mut().clear();
+    assert!(set.is_empty());
+
+    let mut strings = CxxSet::<CxxString>::new();
+    let_cxx_string!(x = "x");
+    let_cxx_string!(y = "y");
+    assert!(strings.pin_mut().insert(&y));
+    assert!(strings.pin_mut().insert(&x));
+    assert!(!strings.pin_mut().insert(&x));
+    check!(ffi::c_take_ref_set_string(&strings));
+
+    let mut numbers = CxxList::<f64>::new();
+    numbers.pin_mut().push_back(1.5);
+    numbers.pin_mut().push_front(0.5);
+    assert_eq!(numbers.iter().sum::<f64>(), 2.0);
+    numbers.pin_mut().insert(1, 1.0);
+    assert_eq!(numbers.iter().copied().collect::<Vec<_>>(), [0.5, 1.0, 1.5]);
+    assert_eq!(numbers.pin_mut().remove(1), Some(1.0));
+    assert_eq!(numbers.pin_mut().remove(2), None);
+    assert_eq!(numbers.pin_mut().pop_front(), Some(0.5));
+    assert_eq!(numbers.pin_mut().pop_back(), Some(1.5));
+    assert_eq!(numbers.pin_mut().pop_back(), None);
+
+    let mut enums = CxxSet::<ffi::Enum>::new();
+    assert!(enums.pin_mut().insert(&ffi::Enum::LastVal));
+    assert!(enums.pin_mut().insert(&ffi::Enum::AVal));
+    assert_eq!(enums.iter().next(), Some(&ffi::Enum::AVal));
+
+    let mut bytes = CxxDeque::<u8>::new();
+    bytes.pin_mut().push_back(1);
+    bytes.pin_mut().push_front(0);
+    bytes.pin_mut().insert(2, 2);
+    assert_eq!(format!("{:?}", bytes), "[0, 1, 2]");
+    assert_eq!(bytes.pin_mut().remove(0), Some(0));
+}
+
 #[test]
 fn test_extern_trivial() {
     let mut d = ffi2::c_return_trivial();
@@ -0,0 +1,18 @@
+use std::cell::Cell;
+
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        type Counter;
+
+        fn new_counter() -> Arc<Counter>;
+    }
+}
+
+pub struct Counter(Cell<usize>);
+
+fn new_counter() -> std::sync::Arc<Counter> {
+    unimplemented!()
+}
+
+fn main() {}
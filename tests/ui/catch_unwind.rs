@@ -0,0 +1,9 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        #[catch_unwind]
+        fn f() -> usize;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[cxx::bridge]
+mod ffi {
+    enum Enum {
+        A,
+    }
+
+    #[Self = "Enum"]
+    const DEFAULT: u8 = 0;
+}
+
+fn main() {}
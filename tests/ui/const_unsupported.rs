@@ -0,0 +1,12 @@
+#[cxx::bridge]
+mod ffi {
+    const NAME: &str = "cxx";
+    const SUM: usize = 1 + 1;
+    const FLAG: bool = 1;
+
+    unsafe extern "C++" {
+        static NAMES: Vec<String>;
+    }
+}
+
+fn main() {}
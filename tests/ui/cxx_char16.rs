@@ -0,0 +1,9 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        fn f(#[cxx_char16] s: &[u8]);
+        fn g(#[cxx_char16] s: &str);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        type R;
+    }
+
+    unsafe extern "C++" {
+        type C;
+
+        fn deque_of_rust() -> UniquePtr<CxxDeque<R>>;
+        fn list_of_ptr() -> UniquePtr<CxxList<*mut C>>;
+        fn set_of_rust_string() -> UniquePtr<CxxSet<String>>;
+        fn shared_ptr_to_set() -> SharedPtr<CxxSet<i32>>;
+        fn deque_by_mut_ref(deque: &mut CxxDeque<u8>);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        fn f(#[cxx_default = "0"] x: i32);
+    }
+
+    extern "Rust" {
+        fn g(#[cxx_default = "0"] x: i32, y: i32);
+    }
+}
+
+fn g(_x: i32, _y: i32) {}
+
+fn main() {}
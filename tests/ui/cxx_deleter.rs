@@ -0,0 +1,16 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        #[cxx_deleter = " "]
+        type Empty;
+    }
+
+    extern "Rust" {
+        #[cxx_deleter = "Deleter"]
+        type Opaque;
+    }
+}
+
+struct Opaque;
+
+fn main() {}
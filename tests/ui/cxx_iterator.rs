@@ -0,0 +1,25 @@
+#[cxx::bridge]
+mod ffi {
+    struct Shared {
+        x: i32,
+    }
+
+    unsafe extern "C++" {
+        type Container;
+        type Other;
+    }
+
+    impl IntoIterator for &Shared {
+        type Item = &i32;
+    }
+
+    impl IntoIterator for &Container {
+        type Item = i32;
+    }
+
+    impl IntoIterator for &Other {
+        type Item = &mut i32;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        #[cxx_template(i32)]
+        fn f(x: i32);
+    }
+}
+
+fn f(_x: i32) {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        #[enable_shared_from_this(Node)]
+        type Node;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        #[enable_shared_from_this]
+        type Opaque;
+    }
+}
+
+struct Opaque;
+
+fn main() {}
@@ -0,0 +1,18 @@
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        #[error_details]
+        fn f() -> usize;
+    }
+
+    unsafe extern "C++" {
+        #[error_details]
+        fn g() -> Result<usize>;
+    }
+}
+
+fn f() -> usize {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        #[instantiate(i32)]
+        fn f<T>(x: T, #[cxx_default = "0"] y: i32);
+
+        #[instantiate(i32, Missing)]
+        fn g<T>(x: T);
+
+        #[instantiate(i32)]
+        fn h<T>();
+    }
+}
+
+fn f<T>(_x: T, _y: i32) {}
+
+fn g<T>(_x: T) {}
+
+fn h<T>() {}
+
+fn main() {}
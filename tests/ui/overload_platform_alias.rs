@@ -0,0 +1,16 @@
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        #[cxx_name = "f"]
+        fn f_usize(x: usize);
+        #[cxx_name = "f"]
+        fn f_u64(x: u64);
+
+        #[cxx_name = "g"]
+        fn g_i64(x: i64);
+        #[cxx_name = "g"]
+        fn g_isize(x: isize);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#[cxx::bridge]
+mod ffi {
+    extern "Rust" {
+        type T;
+
+        #[cxx_name = "f"]
+        fn f_a(x: i32);
+        #[cxx_name = "f"]
+        fn f_b(y: i32);
+
+        #[cxx_name = "get"]
+        fn get_a(self: &T) -> usize;
+        #[cxx_name = "get"]
+        fn get_b(self: &mut T) -> usize;
+        #[cxx_name = "get"]
+        fn get_c(self: &T) -> isize;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+#[cxx::bridge]
+mod ffi {
+    struct Shared {
+        x: i32,
+    }
+
+    extern "Rust" {
+        type Borrowed;
+    }
+
+    impl Iterator for Shared {
+        type Item = i32;
+    }
+
+    impl Iterator for Borrowed {
+        type Item = &'static str;
+    }
+}
+
+struct Borrowed;
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        static mut COUNTER: i32;
+    }
+}
+
+fn main() {}
@@ -1,4 +1,5 @@
 use cxx::{CxxString, UniquePtr};
+use std::ptr;
 
 #[test]
 #[should_panic = "called deref on a null UniquePtr<CxxString>"]
@@ -6,3 +7,31 @@ fn test_deref_null() {
     let unique_ptr = UniquePtr::<CxxString>::null();
     let _: &CxxString = &unique_ptr;
 }
+
+#[test]
+fn test_slice() {
+    let mut array = UniquePtr::<[f32]>::new_slice(4);
+    assert!(!array.is_null());
+    assert_eq!(array.len(), 4);
+    assert_eq!(*array, [0.0; 4]);
+    array[1] = 2020.0;
+    assert_eq!(array.iter().sum::<f32>(), 2020.0);
+
+    let array = UniquePtr::from_slice(&[2u8, 0, 2, 0]);
+    let (data, len) = array.into_raw_parts();
+    let array = unsafe { UniquePtr::<[u8]>::from_raw_parts(data, len) };
+    assert_eq!(format!("{:?}", array), "[2, 0, 2, 0]");
+
+    let empty = UniquePtr::<[u64]>::new_slice(0);
+    assert!(!empty.is_null());
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic = "called deref on a null UniquePtr<[u32]>"]
+fn test_deref_null_slice() {
+    let array = unsafe { UniquePtr::<[u32]>::from_raw_parts(ptr::null_mut(), 0) };
+    assert!(array.is_null());
+    assert!(array.as_ref().is_none());
+    let _: &[u32] = &array;
+}